@@ -47,4 +47,100 @@ pub enum EndpointError {
 
     #[msg("Invalid Destination Chain Id")]
     InvalidDestinationChain,
+
+    #[msg("Too many adapter instructions")]
+    TooManyInstructions,
+
+    #[msg("Adapter instruction payload is too long")]
+    PayloadTooLong,
+
+    #[msg("Adapter instruction index is out of bounds")]
+    InvalidInstructionIndex,
+
+    #[msg("Adapter instructions are malformed")]
+    InvalidAdapterInstructions,
+
+    #[msg("Adapter program does not match the registered adapter")]
+    InvalidAdapterProgram,
+
+    #[msg("Adapter returned an invalid delivery price quote")]
+    InvalidDeliveryPriceQuote,
+
+    #[msg("Delivery price overflow")]
+    DeliveryPriceOverflow,
+
+    #[msg("Outbox message has not expired")]
+    OutboxMessageNotExpired,
+
+    #[msg("Not enough enabled adapters attested to the message")]
+    ThresholdNotMet,
+
+    #[msg("Threshold exceeds the number of enabled receive adapters")]
+    InvalidThreshold,
+
+    #[msg("Message has not been executed")]
+    MessageNotExecuted,
+
+    #[msg("Endpoint is paused")]
+    EndpointPaused,
+
+    #[msg("Sending messages is paused")]
+    SendPaused,
+
+    #[msg("Receiving messages is paused")]
+    RecvPaused,
+
+    #[msg("Adapter is still enabled on a chain")]
+    AdapterStillEnabled,
+
+    #[msg("Adapter bitmap contains an adapter that is not registered")]
+    InvalidAdapterBitmap,
+
+    #[msg("Adapter info account does not match the adapter")]
+    InvalidAdapterInfo,
+
+    #[msg("Admin signers or threshold are invalid")]
+    InvalidAdminSigners,
+
+    #[msg("Config change must be proposed and executed after the config delay")]
+    ConfigChangeTimelocked,
+
+    #[msg("Config delay has not elapsed")]
+    ConfigDelayNotElapsed,
+
+    #[msg("Accounts do not match the config change")]
+    InvalidConfigChangeAccounts,
+
+    #[msg("Role is not granted")]
+    RoleNotGranted,
+
+    #[msg("Too many or duplicate peers")]
+    InvalidPeers,
+
+    #[msg("Source address is not a peer")]
+    UnknownPeer,
+
+    #[msg("Destination address is not a peer")]
+    UnknownDestination,
+
+    #[msg("Rate limit exceeded")]
+    RateLimitExceeded,
+
+    #[msg("Rate limit window must be non-zero")]
+    InvalidRateLimit,
+
+    #[msg("Message is queued by the inbound rate limit")]
+    MessageQueued,
+
+    #[msg("Message is not queued")]
+    MessageNotQueued,
+
+    #[msg("Queue delay has not elapsed")]
+    QueueDelayNotElapsed,
+
+    #[msg("Minimum attestation age has not elapsed")]
+    AttestationTooRecent,
+
+    #[msg("Adapter slot was reassigned after the message was sent")]
+    AdapterReassigned,
 }
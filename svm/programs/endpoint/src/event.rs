@@ -2,6 +2,8 @@ use anchor_lang::event;
 use anchor_lang::prelude::*;
 use universal_address::UniversalAddress;
 
+use crate::state::{ConfigChange, Role};
+
 /// Event emitted when a new integrator is registered
 #[event]
 pub struct IntegratorRegistered {
@@ -47,6 +49,28 @@ pub struct MessagePickedUp {
     pub remaining_adapters: u128,
 }
 
+/// Event emitted when a message is cancelled by its integrator before any adapter picked it up
+#[event]
+pub struct MessageCancelled {
+    pub src_addr: UniversalAddress,
+    pub sequence: u64,
+    pub dst_chain: u16,
+    pub dst_addr: UniversalAddress,
+    pub payload_hash: [u8; 32],
+}
+
+/// Event emitted when an expired outbox message is reclaimed
+/// `outstanding_adapters` is the bitmap of send adapters that never picked up the message
+#[event]
+pub struct MessageExpired {
+    pub src_addr: UniversalAddress,
+    pub sequence: u64,
+    pub dst_chain: u16,
+    pub dst_addr: UniversalAddress,
+    pub payload_hash: [u8; 32],
+    pub outstanding_adapters: u128,
+}
+
 /// Event emitted when a message is attested to by an adapter
 #[event]
 pub struct MessageAttestedTo {
@@ -132,3 +156,224 @@ pub struct RecvAdapterDisabledForChain {
 pub struct AdminDiscarded {
     pub integrator: Pubkey,
 }
+
+/// Event emitted when the outbox message timeout of an integrator is updated
+#[event]
+pub struct OutboxMessageTimeoutUpdated {
+    pub integrator: Pubkey,
+    pub timeout: u64,
+}
+
+/// Event emitted when the receive threshold of an integrator is updated for a specific chain
+#[event]
+pub struct RecvThresholdUpdated {
+    pub integrator: Pubkey,
+    pub chain: u16,
+    pub old_threshold: u8,
+    pub new_threshold: u8,
+}
+
+/// Event emitted when the attestation info of an executed message is closed
+#[event]
+pub struct AttestationInfoClosed {
+    pub message_hash: [u8; 32],
+    pub src_chain: u16,
+    pub src_addr: UniversalAddress,
+    pub sequence: u64,
+    pub dst_addr: UniversalAddress,
+}
+
+/// Event emitted when the endpoint config is initialized
+#[event]
+pub struct EndpointInitialized {
+    pub guardian: Pubkey,
+}
+
+/// Event emitted when the endpoint is paused or unpaused
+#[event]
+pub struct EndpointPausedUpdated {
+    pub paused: bool,
+}
+
+/// Event emitted when the pause flags of an integrator are updated
+#[event]
+pub struct IntegratorPausedUpdated {
+    pub integrator: Pubkey,
+    pub paused_send: bool,
+    pub paused_recv: bool,
+}
+
+/// Event emitted when the pause flags of an integrator are updated for a specific chain
+#[event]
+pub struct ChainPausedUpdated {
+    pub integrator: Pubkey,
+    pub chain: u16,
+    pub paused_send: bool,
+    pub paused_recv: bool,
+}
+
+/// Event emitted when an adapter is removed from an integrator
+#[event]
+pub struct AdapterRemoved {
+    pub integrator: Pubkey,
+    pub adapter: Pubkey,
+    pub index: u8,
+}
+
+/// Event emitted when the slot of an adapter is migrated to a new adapter program
+#[event]
+pub struct AdapterReplaced {
+    pub integrator: Pubkey,
+    pub old_adapter: Pubkey,
+    pub new_adapter: Pubkey,
+    pub index: u8,
+}
+
+/// Event emitted when the multisig admin of an integrator is updated
+#[event]
+pub struct AdminSignersUpdated {
+    pub integrator: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+/// Event emitted when the admin of an integrator proposes a config change
+#[event]
+pub struct ConfigChangeProposed {
+    pub integrator: Pubkey,
+    pub id: u64,
+    pub change: ConfigChange,
+    pub eta: u64,
+}
+
+/// Event emitted when a proposed config change is executed
+#[event]
+pub struct ConfigChangeExecuted {
+    pub integrator: Pubkey,
+    pub id: u64,
+    pub change: ConfigChange,
+}
+
+/// Event emitted when a proposed config change is cancelled
+#[event]
+pub struct ConfigChangeCancelled {
+    pub integrator: Pubkey,
+    pub id: u64,
+    pub change: ConfigChange,
+}
+
+/// Event emitted when the config delay of an integrator is updated
+#[event]
+pub struct ConfigDelayUpdated {
+    pub integrator: Pubkey,
+    pub delay: u64,
+}
+
+/// Event emitted when a role of an integrator is granted to an account
+#[event]
+pub struct RoleGranted {
+    pub integrator: Pubkey,
+    pub role: Role,
+    pub account: Pubkey,
+}
+
+/// Event emitted when a role of an integrator is revoked from an account
+#[event]
+pub struct RoleRevoked {
+    pub integrator: Pubkey,
+    pub role: Role,
+    pub account: Pubkey,
+}
+
+/// Event emitted when a pending admin transfer is cancelled
+#[event]
+pub struct AdminTransferCancelled {
+    pub integrator: Pubkey,
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+/// Event emitted when the peers of an integrator are updated for a specific chain
+#[event]
+pub struct ChainPeersUpdated {
+    pub integrator: Pubkey,
+    pub chain: u16,
+    pub peers: Vec<UniversalAddress>,
+}
+
+/// Event emitted when an integrator enables or disables the enforcement of destination peers
+#[event]
+pub struct EnforcePeersUpdated {
+    pub integrator: Pubkey,
+    pub enforce_peers: bool,
+}
+
+/// Event emitted when the outbound rate limit of an integrator is updated for a specific chain
+#[event]
+pub struct OutboundRateLimitUpdated {
+    pub integrator: Pubkey,
+    pub chain: u16,
+    pub limit: u64,
+    pub window: u64,
+}
+
+/// Event emitted when a message exceeding the inbound rate limit is queued
+#[event]
+pub struct MessageQueued {
+    pub message_hash: [u8; 32],
+    pub src_chain: u16,
+    pub src_addr: UniversalAddress,
+    pub sequence: u64,
+    pub dst_chain: u16,
+    pub dst_addr: UniversalAddress,
+    pub payload_hash: [u8; 32],
+    pub release_slot: u64,
+}
+
+/// Event emitted when a queued message is released
+#[event]
+pub struct MessageReleased {
+    pub message_hash: [u8; 32],
+    pub src_chain: u16,
+    pub src_addr: UniversalAddress,
+    pub sequence: u64,
+    pub dst_addr: UniversalAddress,
+}
+
+/// Event emitted when the inbound rate limit of an integrator is updated for a specific chain
+#[event]
+pub struct InboundRateLimitUpdated {
+    pub integrator: Pubkey,
+    pub chain: u16,
+    pub limit: u64,
+    pub window: u64,
+    pub queue_delay: u64,
+}
+
+/// Event emitted when the minimum attestation age of an integrator is updated for a specific chain
+#[event]
+pub struct MinAttestationAgeUpdated {
+    pub integrator: Pubkey,
+    pub chain: u16,
+    pub min_attestation_age: u64,
+}
+
+/// Event emitted when an adapter claims the delivery fees held in its fee vault
+#[event]
+pub struct AdapterFeesClaimed {
+    pub adapter: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+/// Event emitted when the slot at which a message met the receive threshold is updated
+/// `threshold_reached_slot` is `None` if the threshold is not met
+#[event]
+pub struct ThresholdReachedSlotUpdated {
+    pub message_hash: [u8; 32],
+    pub src_chain: u16,
+    pub src_addr: UniversalAddress,
+    pub sequence: u64,
+    pub dst_addr: UniversalAddress,
+    pub threshold_reached_slot: Option<u64>,
+}
@@ -1,6 +1,6 @@
 use crate::{
     event::AdapterAdded,
-    state::{AdapterInfo, IntegratorConfig},
+    state::{AdapterInfo, IntegratorConfig, Role},
 };
 use anchor_lang::prelude::*;
 
@@ -22,12 +22,13 @@ pub struct AddAdapter<'info> {
     pub payer: Signer<'info>,
 
     /// The admin registered on IntegratorConfig
+    /// Or the holder of the configurator role
     pub admin: Signer<'info>,
 
     /// The integrator config account
     /// This makes sure that the admin signing this ix is the one registered in the IntegratorConfig
     /// The new registered adapter will be pushed to the `adapter_infos` field in
-    /// this account
+    /// this account, or take the slot of a removed adapter
     /// `has_one` constraint checks if admin signer is the current admin of the config
     #[account(
         mut,
@@ -57,8 +58,15 @@ pub struct AddAdapter<'info> {
 }
 
 impl<'info> AddAdapter<'info> {
-    pub fn validate(&self) -> Result<()> {
-        self.integrator_config.check_admin(&self.admin)
+    pub fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        self.integrator_config.check_roles(
+            &[Role::Configurator],
+            &self.admin,
+            remaining_accounts,
+        )?;
+
+        // Adapters can only be added through `propose_config_change` while the config delay is set
+        self.integrator_config.check_no_config_delay()
     }
 }
 
@@ -66,7 +74,8 @@ impl<'info> AddAdapter<'info> {
 ///
 /// This function performs the following steps:
 /// 1. Checks if the maximum number of adapters has been reached.
-/// 2. Adds the new adapter to the list of registered adapters in IntegratorConfig
+/// 2. Adds the new adapter to the list of registered adapters in IntegratorConfig, reusing the
+///    slot of a removed adapter if there is one
 /// 3. Initializes the AdapterInfo account with the provided information.
 ///
 /// # Arguments
@@ -80,16 +89,17 @@ impl<'info> AddAdapter<'info> {
 ///
 /// Returns `Ok(())` if the adapter is successfully registered, or an error otherwise.
 ///
+/// While the config delay is set, adapters must be added through `propose_config_change` instead.
+///
 /// # Events
 ///
 /// Emits a `AdapterAdded` event
-#[access_control(AddAdapter::validate(&ctx.accounts))]
+#[access_control(AddAdapter::validate(&ctx.accounts, ctx.remaining_accounts))]
 pub fn add_adapter(ctx: Context<AddAdapter>, args: AddAdapterArgs) -> Result<()> {
-    let index = ctx.accounts.integrator_config.adapter_infos.len() as u8;
-
     // Add the new adapter to the list
     // The vector length check is in `add_adapter`
-    ctx.accounts
+    let index = ctx
+        .accounts
         .integrator_config
         .add_adapter(args.adapter_program_id)?;
 
@@ -99,6 +109,7 @@ pub fn add_adapter(ctx: Context<AddAdapter>, args: AddAdapterArgs) -> Result<()>
         index,
         integrator_program_id: args.integrator_program_id,
         adapter_program_id: args.adapter_program_id,
+        enabled_count: 0,
     });
 
     emit_cpi!(AdapterAdded {
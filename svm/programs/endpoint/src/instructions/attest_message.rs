@@ -1,11 +1,14 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use universal_address::UniversalAddress;
 
 use crate::{
     error::EndpointError,
     event::MessageAttestedTo,
-    state::{AdapterInfo, AttestationInfo, IntegratorChainConfig},
-    CHAIN_ID,
+    state::{
+        AdapterInfo, AttestationInfo, EndpointConfig, IntegratorChainConfig, IntegratorConfig,
+        ReplayBitmap,
+    },
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -47,6 +50,14 @@ pub struct AttestMessage<'info> {
     )]
     pub adapter_pda: Signer<'info>,
 
+    /// The integrator config account
+    /// This is used to clear the attestations of adapters whose slot was reassigned
+    #[account(
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
     /// The integrator chain config account
     #[account(
         seeds = [
@@ -59,10 +70,11 @@ pub struct AttestMessage<'info> {
     pub integrator_chain_config: Account<'info, IntegratorChainConfig>,
 
     /// The attestation info account
+    /// This is created by the first attestation, unless the message has already been executed
+    /// CHECK: The address is checked by the seeds constraint. The account is created or
+    /// deserialized in the instruction
     #[account(
-        init_if_needed,
-        payer = payer,
-        space = 8 + AttestationInfo::INIT_SPACE,
+        mut,
         seeds = [
             AttestationInfo::SEED_PREFIX,
             &AttestationInfo::compute_message_hash(
@@ -76,7 +88,31 @@ pub struct AttestMessage<'info> {
         ],
         bump
     )]
-    pub attestation_info: Account<'info, AttestationInfo>,
+    pub attestation_info: UncheckedAccount<'info>,
+
+    /// The replay bitmap account tracking the sequence of the message
+    /// CHECK: The address is checked by the seeds. The account doesn't exist until a message it
+    /// tracks is executed
+    #[account(
+        seeds = [
+            ReplayBitmap::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.src_chain.to_be_bytes().as_ref(),
+            args.src_addr.to_bytes().as_ref(),
+            ReplayBitmap::index_of(args.sequence).to_be_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub replay_bitmap: UncheckedAccount<'info>,
+
+    /// The endpoint config account
+    /// This is used to check that the endpoint is not paused
+    #[account(
+        seeds = [EndpointConfig::SEED_PREFIX],
+        bump = endpoint_config.bump,
+        constraint = !endpoint_config.paused @ EndpointError::EndpointPaused,
+    )]
+    pub endpoint_config: Account<'info, EndpointConfig>,
 
     pub system_program: Program<'info, System>,
 }
@@ -85,9 +121,17 @@ pub struct AttestMessage<'info> {
 ///
 /// This function allows an adapter to attest to a message. It performs the following steps:
 /// 1. Checks if the adapter is enabled for receiving messages from the source chain.
-/// 2. Initializes the attestation info account if it's newly created.
-/// 3. Checks if the adapter has already attested to this message.
-/// 4. Marks the adapter as having attested to the message.
+/// 2. Creates and initializes the attestation info account if it doesn't exist, unless the message
+///    has already been executed according to the replay bitmap, in which case the attestation is
+///    accepted without recording it.
+/// 3. Clears the attestations of the adapters whose slot was freed or migrated since they attested.
+/// 4. Checks if the adapter has already attested to this message.
+/// 5. Marks the adapter as having attested to the message.
+/// 6. Records the slot if the attestations meet the receive threshold for the first time.
+///
+/// Adapters can attest after the message was executed. The attestation info of an executed
+/// message is updated while it exists, but it is not re-created once it has been closed, so that
+/// late attestations do not lock rent in an account that can no longer be used.
 ///
 /// # Arguments
 ///
@@ -104,6 +148,9 @@ pub struct AttestMessage<'info> {
 /// # Errors
 ///
 /// This function will return an error if:
+/// * The endpoint is paused (EndpointError::EndpointPaused)
+/// * Either chain ID is 0 (EndpointError::InvalidChainId)
+/// * The destination chain is not this chain (EndpointError::InvalidDestinationChain)
 /// * The adapter is not enabled for receiving messages from the source chain
 /// * The adapter has already attested to this message
 ///
@@ -113,17 +160,14 @@ pub struct AttestMessage<'info> {
 ///
 /// # Events
 ///
-/// Emits a `MessageAttestedTo` event
+/// Emits a `MessageAttestedTo` event, unless the attestation info of the executed message has
+/// been closed
 pub fn attest_message(ctx: Context<AttestMessage>, args: AttestMessageArgs) -> Result<()> {
     // Validate that the destination chain is this program's chain
-    require!(
-        args.dst_chain == CHAIN_ID,
-        EndpointError::InvalidDestinationChain
-    );
+    AttestationInfo::validate_chains(args.src_chain, args.dst_chain)?;
 
     let adapter_info = &ctx.accounts.adapter_info;
     let integrator_chain_config = &ctx.accounts.integrator_chain_config;
-    let attestation_info = &mut ctx.accounts.attestation_info;
 
     // Check if the Adapter is an enabled receive Adapter for the Integrator and source chain
     require!(
@@ -134,11 +178,16 @@ pub fn attest_message(ctx: Context<AttestMessage>, args: AttestMessageArgs) -> R
         EndpointError::AdapterNotEnabled
     );
 
-    // If the attestation_info is newly created, initialize it
-    // It is fine to check for initialization using `src_chain == 0` as
-    // `IntegratorChainConfig` and `AttestationInfo` can never have chain_id that is 0
-    if attestation_info.src_chain == 0 {
-        attestation_info.set_inner(AttestationInfo::new(
+    let mut attestation_info = if ctx.accounts.attestation_info.owner == &crate::ID {
+        AttestationInfo::try_deserialize(
+            &mut &ctx.accounts.attestation_info.try_borrow_data()?[..],
+        )?
+    } else if ReplayBitmap::is_executed_in(&ctx.accounts.replay_bitmap, args.sequence)? {
+        // The attestation info of the executed message has been closed, and there is nothing
+        // left to attest to
+        return Ok(());
+    } else {
+        let mut attestation_info = AttestationInfo::new(
             ctx.bumps.attestation_info,
             args.src_chain,
             args.src_addr,
@@ -146,8 +195,16 @@ pub fn attest_message(ctx: Context<AttestMessage>, args: AttestMessageArgs) -> R
             args.dst_chain,
             UniversalAddress::from_pubkey(&args.integrator_program_id),
             args.payload_hash,
-        )?);
-    }
+        )?;
+        // Recorded so the rent can be refunded once the account is closed
+        attestation_info.payer = ctx.accounts.payer.key();
+        create_attestation_info_account(&ctx, &attestation_info)?;
+        attestation_info
+    };
+
+    // An adapter reusing the slot of a removed or replaced adapter does not inherit its
+    // attestations
+    attestation_info.clear_stale_attestations(&ctx.accounts.integrator_config)?;
 
     // Check if the Adapter has already attested
     require!(
@@ -163,6 +220,13 @@ pub fn attest_message(ctx: Context<AttestMessage>, args: AttestMessageArgs) -> R
         .attested_adapters
         .set(adapter_info.index, true)?;
 
+    // Record when the threshold is reached, from which the minimum attestation age of the chain is
+    // counted
+    attestation_info.update_threshold_reached_slot(integrator_chain_config, Clock::get()?.slot);
+
+    attestation_info
+        .try_serialize(&mut &mut ctx.accounts.attestation_info.try_borrow_mut_data()?[..])?;
+
     emit_cpi!(MessageAttestedTo {
         message_hash: attestation_info.message_hash,
         src_chain: args.src_chain,
@@ -177,3 +241,71 @@ pub fn attest_message(ctx: Context<AttestMessage>, args: AttestMessageArgs) -> R
 
     Ok(())
 }
+
+/// Creates the attestation info account at its PDA, paid by the payer
+/// The account may already hold lamports, in which case only the missing rent is transferred
+fn create_attestation_info_account(
+    ctx: &Context<AttestMessage>,
+    attestation_info: &AttestationInfo,
+) -> Result<()> {
+    let space = 8 + AttestationInfo::INIT_SPACE;
+    let rent = Rent::get()?.minimum_balance(space);
+    let account = ctx.accounts.attestation_info.to_account_info();
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        AttestationInfo::SEED_PREFIX,
+        &attestation_info.message_hash,
+        &[attestation_info.bump],
+    ]];
+
+    let lamports = account.lamports();
+    if lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                system_program::CreateAccount {
+                    from: payer,
+                    to: account,
+                },
+                signer_seeds,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    if rent > lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer,
+                    to: account.clone(),
+                },
+            ),
+            rent - lamports,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program,
+            system_program::Assign {
+                account_to_assign: account,
+            },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )
+}
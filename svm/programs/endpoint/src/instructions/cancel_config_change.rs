@@ -0,0 +1,101 @@
+use crate::{
+    event::ConfigChangeCancelled,
+    state::{EndpointConfig, IntegratorConfig, PendingConfigChange, Role},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    /// The admin registered on IntegratorConfig, the holder of the configurator or pauser role, or
+    /// the guardian of the endpoint
+    pub authority: Signer<'info>,
+
+    /// The endpoint config account, whose guardian can cancel any proposed change
+    #[account(
+        seeds = [EndpointConfig::SEED_PREFIX],
+        bump = endpoint_config.bump,
+    )]
+    pub endpoint_config: Account<'info, EndpointConfig>,
+
+    /// The integrator config account
+    #[account(
+        seeds = [
+            IntegratorConfig::SEED_PREFIX,
+            pending_config_change.integrator_program_id.as_ref(),
+        ],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The proposed change
+    /// This account is closed and its rent refunded to the account that paid for it
+    #[account(
+        mut,
+        seeds = [
+            PendingConfigChange::SEED_PREFIX,
+            pending_config_change.integrator_program_id.as_ref(),
+            pending_config_change.id.to_be_bytes().as_ref(),
+        ],
+        bump = pending_config_change.bump,
+        close = rent_recipient,
+    )]
+    pub pending_config_change: Account<'info, PendingConfigChange>,
+
+    /// The account that paid the rent of the pending config change account
+    /// CHECK: The address is checked against the payer recorded in the pending config change
+    #[account(mut, address = pending_config_change.payer)]
+    pub rent_recipient: UncheckedAccount<'info>,
+}
+
+impl<'info> CancelConfigChange<'info> {
+    pub fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        if self.authority.key() == self.endpoint_config.guardian {
+            return Ok(());
+        }
+
+        self.integrator_config.check_roles(
+            &[Role::Configurator, Role::Pauser],
+            &self.authority,
+            remaining_accounts,
+        )
+    }
+}
+
+/// Cancels a proposed config change of an integrator
+///
+/// This function performs the following steps:
+/// 1. Validates that the signer is the guardian of the endpoint, the configurator, the pauser, or
+///    the admin with no pending admin transfer.
+/// 2. Emits a ConfigChangeCancelled event.
+/// 3. Closes the PendingConfigChange account, refunding its rent to the account that paid for it.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The signer is neither the guardian, the configurator, the pauser nor the admin
+///   (EndpointError::CallerNotAuthorized)
+/// * There is a pending admin transfer and the signer is not the guardian
+///   (EndpointError::AdminTransferInProgress)
+/// * The rent recipient is not the payer of the pending config change
+///   (ErrorCode::ConstraintAddress)
+///
+/// # Events
+///
+/// Emits a `ConfigChangeCancelled` event
+#[access_control(CancelConfigChange::validate(&ctx.accounts, ctx.remaining_accounts))]
+pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+    let pending_config_change = &ctx.accounts.pending_config_change;
+
+    emit_cpi!(ConfigChangeCancelled {
+        integrator: pending_config_change.integrator_program_id,
+        id: pending_config_change.id,
+        change: pending_config_change.change.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use crate::{error::EndpointError, event::MessageCancelled, state::OutboxMessage};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CancelMessageArgs {
+    pub integrator_program_id: Pubkey,
+    pub integrator_program_pda_bump: u8,
+    /// The sequence of the message to cancel
+    pub sequence: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: CancelMessageArgs)]
+pub struct CancelMessage<'info> {
+    /// The PDA of the integrator program.
+    /// This makes sure that only the integrator program that sent the message can cancel it
+    #[account(
+        seeds = [b"endpoint_integrator"],
+        bump = args.integrator_program_pda_bump,
+        seeds::program = args.integrator_program_id
+    )]
+    pub integrator_program_pda: Signer<'info>,
+
+    /// The outbox message account to be cancelled
+    /// This account is closed and its lamports are returned to the refund recipient
+    #[account(
+        mut,
+        seeds = [
+            OutboxMessage::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.sequence.to_be_bytes().as_ref(),
+        ],
+        bump = outbox_message.bump,
+        has_one = refund_recipient,
+        close = refund_recipient,
+    )]
+    pub outbox_message: Account<'info, OutboxMessage>,
+
+    /// The account that will receive the rent and escrowed fees of the outbox message account
+    #[account(mut)]
+    /// CHECK: This is an account for receiving the rent refund, checked against the outbox message
+    pub refund_recipient: AccountInfo<'info>,
+}
+
+impl<'info> CancelMessage<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.outbox_message.is_unpicked(),
+            EndpointError::MessageAlreadyPickedUp
+        );
+        Ok(())
+    }
+}
+
+/// Cancels a message that has not been picked up by any adapter yet.
+///
+/// This function performs the following steps:
+/// 1. Checks that no adapter has picked up the message.
+/// 2. Emits a MessageCancelled event.
+/// 3. Closes the outbox message account, returning the rent and the escrowed delivery fees to the
+///    refund recipient.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the cancel_message instruction, containing:
+///   - `integrator_program_id`: The program ID of the integrator that sent the message
+///   - `integrator_program_pda_bump`: The bump for the integrator_program_pda derivation
+///   - `sequence`: The sequence of the message
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * An adapter has already picked up the message (EndpointError::MessageAlreadyPickedUp)
+///
+/// # Events
+///
+/// Emits a `MessageCancelled` event
+#[access_control(CancelMessage::validate(&ctx.accounts))]
+pub fn cancel_message(ctx: Context<CancelMessage>, _args: CancelMessageArgs) -> Result<()> {
+    let outbox_message = &ctx.accounts.outbox_message;
+
+    emit_cpi!(MessageCancelled {
+        src_addr: outbox_message.src_addr,
+        sequence: outbox_message.sequence,
+        dst_chain: outbox_message.dst_chain,
+        dst_addr: outbox_message.dst_addr,
+        payload_hash: outbox_message.payload_hash,
+    });
+
+    Ok(())
+}
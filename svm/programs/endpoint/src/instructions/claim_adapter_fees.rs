@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{event::AdapterFeesClaimed, state::AdapterFeeVault};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ClaimAdapterFeesArgs {
+    pub adapter_program_id: Pubkey,
+    pub adapter_pda_bump: u8,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: ClaimAdapterFeesArgs)]
+pub struct ClaimAdapterFees<'info> {
+    /// The adapter PDA account, used for signing
+    /// This ensures that only the adapter can claim its fees
+    #[account(
+        seeds = ["adapter_pda".as_bytes()],
+        bump = args.adapter_pda_bump,
+        seeds::program = args.adapter_program_id
+    )]
+    pub adapter_pda: Signer<'info>,
+
+    /// The fee vault of the adapter
+    #[account(
+        mut,
+        seeds = [AdapterFeeVault::SEED_PREFIX, args.adapter_program_id.as_ref()],
+        bump = adapter_fee_vault.bump,
+    )]
+    pub adapter_fee_vault: Account<'info, AdapterFeeVault>,
+
+    /// The account receiving the fees
+    /// CHECK: This is chosen by the adapter
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+/// Claims the delivery fees released to an adapter
+///
+/// This function performs the following steps:
+/// 1. Transfers every lamport of the fee vault above its rent exemption minimum to the recipient.
+/// 2. Emits an AdapterFeesClaimed event.
+///
+/// The recipient must be rent exempt after receiving the fees, or the transaction fails.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the instruction, containing:
+///   - `adapter_program_id`: The program ID of the adapter
+///   - `adapter_pda_bump`: The bump seed for the adapter's PDA
+///
+/// # Events
+///
+/// Emits an `AdapterFeesClaimed` event
+pub fn claim_adapter_fees(
+    ctx: Context<ClaimAdapterFees>,
+    args: ClaimAdapterFeesArgs,
+) -> Result<()> {
+    let vault = ctx.accounts.adapter_fee_vault.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(vault.data_len());
+    let amount = vault.lamports().saturating_sub(rent_exempt_minimum);
+
+    if amount > 0 {
+        vault.sub_lamports(amount)?;
+        ctx.accounts.recipient.add_lamports(amount)?;
+    }
+
+    emit_cpi!(AdapterFeesClaimed {
+        adapter: args.adapter_program_id,
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+    });
+
+    Ok(())
+}
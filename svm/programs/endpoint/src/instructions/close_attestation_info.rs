@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::EndpointError,
+    event::AttestationInfoClosed,
+    state::{AttestationInfo, ReplayBitmap},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseAttestationInfo<'info> {
+    /// The attestation info account of an executed message, or of a message whose sequence has
+    /// been executed with another payload
+    /// This account is closed and its rent refunded to the account that paid for it
+    #[account(
+        mut,
+        seeds = [AttestationInfo::SEED_PREFIX, &attestation_info.message_hash],
+        bump = attestation_info.bump,
+        close = rent_recipient,
+    )]
+    pub attestation_info: Account<'info, AttestationInfo>,
+
+    /// The account that paid the rent of the attestation info account
+    /// CHECK: The address is checked against the payer recorded in the attestation info
+    #[account(mut, address = attestation_info.payer)]
+    pub rent_recipient: UncheckedAccount<'info>,
+
+    /// The replay bitmap account tracking the sequence of the message
+    /// CHECK: The address is checked by the seeds. The account doesn't exist until a message it
+    /// tracks is executed
+    #[account(
+        seeds = [
+            ReplayBitmap::SEED_PREFIX,
+            attestation_info.dst_addr.to_pubkey().as_ref(),
+            attestation_info.src_chain.to_be_bytes().as_ref(),
+            attestation_info.src_addr.to_bytes().as_ref(),
+            ReplayBitmap::index_of(attestation_info.sequence).to_be_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub replay_bitmap: UncheckedAccount<'info>,
+}
+
+impl<'info> CloseAttestationInfo<'info> {
+    pub fn validate(&self) -> Result<()> {
+        // A message whose sequence has been executed with another payload can no longer be
+        // received either
+        require!(
+            self.attestation_info.executed
+                || ReplayBitmap::is_executed_in(
+                    &self.replay_bitmap,
+                    self.attestation_info.sequence
+                )?,
+            EndpointError::MessageNotExecuted
+        );
+
+        Ok(())
+    }
+}
+
+/// Closes the attestation info account of an executed message
+///
+/// This function performs the following steps:
+/// 1. Checks that the message has been executed, or that its sequence is marked as executed in the
+///    replay bitmap.
+/// 2. Emits an AttestationInfoClosed event.
+/// 3. Closes the attestation info account, refunding its rent to the account that paid for it.
+///
+/// This can be called by anyone. Replay protection is kept by the replay bitmap, in which
+/// `recv_message` and `exec_message` marked the message as executed.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * Neither the message nor its sequence has been executed (EndpointError::MessageNotExecuted)
+/// * The rent recipient is not the payer of the attestation info (ErrorCode::ConstraintAddress)
+///
+/// # Events
+///
+/// Emits an `AttestationInfoClosed` event
+#[access_control(CloseAttestationInfo::validate(&ctx.accounts))]
+pub fn close_attestation_info(ctx: Context<CloseAttestationInfo>) -> Result<()> {
+    let attestation_info = &ctx.accounts.attestation_info;
+
+    emit_cpi!(AttestationInfoClosed {
+        message_hash: attestation_info.message_hash,
+        src_chain: attestation_info.src_chain,
+        src_addr: attestation_info.src_addr,
+        sequence: attestation_info.sequence,
+        dst_addr: attestation_info.dst_addr,
+    });
+
+    Ok(())
+}
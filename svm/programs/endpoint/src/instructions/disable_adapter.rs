@@ -1,7 +1,7 @@
 use crate::error::EndpointError;
 use crate::event::{RecvAdapterDisabledForChain, SendAdapterDisabledForChain};
 use crate::instructions::common::AdapterInfoArgs;
-use crate::state::{AdapterInfo, IntegratorChainConfig, IntegratorConfig};
+use crate::state::{AdapterInfo, IntegratorChainConfig, IntegratorConfig, Role};
 use anchor_lang::prelude::*;
 
 #[event_cpi]
@@ -9,6 +9,7 @@ use anchor_lang::prelude::*;
 #[instruction(args: AdapterInfoArgs)]
 pub struct DisableAdapter<'info> {
     /// The admin account that has the authority to disable adapters
+    /// Or the holder of the configurator or pauser role
     pub admin: Signer<'info>,
 
     /// The integrator config account
@@ -36,7 +37,9 @@ pub struct DisableAdapter<'info> {
     /// The registered adapter account
     /// This makes sure that that the adapter is registered. Else, it will throw
     /// `AccountNotInitialized`
+    /// The number of chains the adapter is enabled on is updated in this account
     #[account(
+        mut,
         seeds = [
             AdapterInfo::SEED_PREFIX,
             args.integrator_program_id.as_ref(),
@@ -48,8 +51,12 @@ pub struct DisableAdapter<'info> {
 }
 
 impl<'info> DisableAdapter<'info> {
-    pub fn validate(&self) -> Result<()> {
-        self.integrator_config.check_admin(&self.admin)
+    pub fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        self.integrator_config.check_roles(
+            &[Role::Configurator, Role::Pauser],
+            &self.admin,
+            remaining_accounts,
+        )
     }
 }
 
@@ -67,9 +74,9 @@ impl<'info> DisableAdapter<'info> {
 /// # Events
 ///
 /// Emits a `RecvAdapterDisabledForChain` event
-#[access_control(DisableAdapter::validate(&ctx.accounts))]
+#[access_control(DisableAdapter::validate(&ctx.accounts, ctx.remaining_accounts))]
 pub fn disable_recv_adapter(ctx: Context<DisableAdapter>, args: AdapterInfoArgs) -> Result<()> {
-    let adapter_info = &ctx.accounts.adapter_info;
+    let adapter_info = &mut ctx.accounts.adapter_info;
     let integrator_chain_config = &mut ctx.accounts.integrator_chain_config;
 
     // Check if the adapter is already disabled
@@ -84,6 +91,7 @@ pub fn disable_recv_adapter(ctx: Context<DisableAdapter>, args: AdapterInfoArgs)
     integrator_chain_config
         .recv_adapter_bitmap
         .set(adapter_info.index, false)?;
+    adapter_info.enabled_count -= 1;
 
     emit_cpi!(RecvAdapterDisabledForChain {
         integrator: args.integrator_program_id,
@@ -108,9 +116,9 @@ pub fn disable_recv_adapter(ctx: Context<DisableAdapter>, args: AdapterInfoArgs)
 /// # Events
 ///
 /// Emits a `SendAdapterDisabledForChain` event
-#[access_control(DisableAdapter::validate(&ctx.accounts))]
+#[access_control(DisableAdapter::validate(&ctx.accounts, ctx.remaining_accounts))]
 pub fn disable_send_adapter(ctx: Context<DisableAdapter>, args: AdapterInfoArgs) -> Result<()> {
-    let adapter_info = &ctx.accounts.adapter_info;
+    let adapter_info = &mut ctx.accounts.adapter_info;
     let integrator_chain_config = &mut ctx.accounts.integrator_chain_config;
 
     // Check if the adapter is already disabled
@@ -125,6 +133,7 @@ pub fn disable_send_adapter(ctx: Context<DisableAdapter>, args: AdapterInfoArgs)
     integrator_chain_config
         .send_adapter_bitmap
         .set(adapter_info.index, false)?;
+    adapter_info.enabled_count -= 1;
 
     emit_cpi!(SendAdapterDisabledForChain {
         integrator: args.integrator_program_id,
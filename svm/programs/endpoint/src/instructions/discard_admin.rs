@@ -20,16 +20,17 @@ pub struct DiscardAdmin<'info> {
 }
 
 impl<'info> DiscardAdmin<'info> {
-    pub fn validate(&self) -> Result<()> {
-        self.integrator_config.check_admin(&self.admin)
+    pub fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        self.integrator_config
+            .check_admin(&self.admin, remaining_accounts)
     }
 }
 
 /// Discards the admin for an integrator configuration
 ///
 /// This function removes the admin from the IntegratorConfig account,
-/// effectively leaving the integrator without an admin. Only an admin is
-/// authorized to do that.
+/// effectively leaving the integrator without an admin. The configurator and
+/// pauser roles are revoked as well. Only an admin is authorized to do that.
 ///
 /// # Arguments
 ///
@@ -43,9 +44,12 @@ impl<'info> DiscardAdmin<'info> {
 /// # Events
 ///
 /// Emits an `AdminDiscarded` event with the integrator's public key.
-#[access_control(DiscardAdmin::validate(&ctx.accounts))]
+#[access_control(DiscardAdmin::validate(&ctx.accounts, ctx.remaining_accounts))]
 pub fn discard_admin(ctx: Context<DiscardAdmin>) -> Result<()> {
-    ctx.accounts.integrator_config.admin = None;
+    let integrator_config = &mut ctx.accounts.integrator_config;
+    integrator_config.admin = None;
+    integrator_config.configurator = None;
+    integrator_config.pauser = None;
 
     emit_cpi!(AdminDiscarded {
         integrator: ctx.accounts.integrator_config.integrator_program_id,
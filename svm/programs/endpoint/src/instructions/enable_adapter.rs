@@ -1,8 +1,7 @@
 use crate::error::EndpointError;
 use crate::event::{RecvAdapterEnabledForChain, SendAdapterEnabledForChain};
 use crate::instructions::common::AdapterInfoArgs;
-use crate::state::{AdapterInfo, IntegratorChainConfig, IntegratorConfig};
-use crate::utils::bitmap::Bitmap;
+use crate::state::{AdapterInfo, IntegratorChainConfig, IntegratorConfig, Role};
 use anchor_lang::prelude::*;
 
 #[event_cpi]
@@ -14,6 +13,7 @@ pub struct EnableAdapter<'info> {
     pub payer: Signer<'info>,
 
     /// The admin account that has the authority to set adapters
+    /// Or the holder of the configurator role
     pub admin: Signer<'info>,
 
     /// The integrator config account
@@ -44,7 +44,9 @@ pub struct EnableAdapter<'info> {
     /// The registered adapter account
     /// This makes sure that the adapter is registered. Else, it will throw
     /// `AccountNotInitialized`
+    /// The number of chains the adapter is enabled on is updated in this account
     #[account(
+        mut,
         seeds = [
             AdapterInfo::SEED_PREFIX,
             args.integrator_program_id.as_ref(),
@@ -59,8 +61,17 @@ pub struct EnableAdapter<'info> {
 }
 
 impl<'info> EnableAdapter<'info> {
-    pub fn validate(&self, args: &AdapterInfoArgs) -> Result<()> {
-        self.integrator_config.check_admin(&self.admin)?;
+    pub fn validate(
+        &self,
+        args: &AdapterInfoArgs,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        self.integrator_config.check_roles(
+            &[Role::Configurator],
+            &self.admin,
+            remaining_accounts,
+        )?;
+        self.integrator_config.check_no_config_delay()?;
 
         // Ensure chain_id is not zero
         require!(args.chain_id != 0, EndpointError::InvalidChainId);
@@ -92,25 +103,24 @@ impl<'info> EnableAdapter<'info> {
 /// # Errors
 ///
 /// This function will return an error if:
+/// * The config delay is set (EndpointError::ConfigChangeTimelocked)
 /// * The adapter is already enabled (EndpointError::AdapterAlreadyEnabled)
 ///
 /// # Events
 ///
 /// Emits a `RecvAdapterEnabledForChain` event
-#[access_control(EnableAdapter::validate(&ctx.accounts, &args))]
+#[access_control(EnableAdapter::validate(&ctx.accounts, &args, ctx.remaining_accounts))]
 pub fn enable_recv_adapter(ctx: Context<EnableAdapter>, args: AdapterInfoArgs) -> Result<()> {
-    let adapter_info = &ctx.accounts.adapter_info;
+    let adapter_info = &mut ctx.accounts.adapter_info;
     let integrator_chain_config = &mut ctx.accounts.integrator_chain_config;
 
     // If chain_id is 0, this is initial setup
     if integrator_chain_config.chain_id == 0 {
-        integrator_chain_config.set_inner(IntegratorChainConfig {
-            chain_id: args.chain_id,
-            bump: ctx.bumps.integrator_chain_config,
-            integrator_program_id: args.integrator_program_id,
-            send_adapter_bitmap: Bitmap::new(),
-            recv_adapter_bitmap: Bitmap::new(),
-        });
+        integrator_chain_config.set_inner(IntegratorChainConfig::new(
+            ctx.bumps.integrator_chain_config,
+            args.chain_id,
+            args.integrator_program_id,
+        ));
     }
 
     if integrator_chain_config
@@ -123,6 +133,7 @@ pub fn enable_recv_adapter(ctx: Context<EnableAdapter>, args: AdapterInfoArgs) -
     integrator_chain_config
         .recv_adapter_bitmap
         .set(adapter_info.index, true)?;
+    adapter_info.enabled_count += 1;
 
     emit_cpi!(RecvAdapterEnabledForChain {
         integrator: args.integrator_program_id,
@@ -156,25 +167,24 @@ pub fn enable_recv_adapter(ctx: Context<EnableAdapter>, args: AdapterInfoArgs) -
 /// # Errors
 ///
 /// This function will return an error if:
+/// * The config delay is set (EndpointError::ConfigChangeTimelocked)
 /// * The adapter is already enabled (EndpointError::AdapterAlreadyEnabled)
 ///
 /// # Events
 ///
 /// Emits a `SendAdapterEnabledForChain` event
-#[access_control(EnableAdapter::validate(&ctx.accounts, &args))]
+#[access_control(EnableAdapter::validate(&ctx.accounts, &args, ctx.remaining_accounts))]
 pub fn enable_send_adapter(ctx: Context<EnableAdapter>, args: AdapterInfoArgs) -> Result<()> {
-    let adapter_info = &ctx.accounts.adapter_info;
+    let adapter_info = &mut ctx.accounts.adapter_info;
     let integrator_chain_config = &mut ctx.accounts.integrator_chain_config;
 
     // If chain_id is 0, this is initial setup
     if integrator_chain_config.chain_id == 0 {
-        integrator_chain_config.set_inner(IntegratorChainConfig {
-            chain_id: args.chain_id,
-            bump: ctx.bumps.integrator_chain_config,
-            integrator_program_id: args.integrator_program_id,
-            send_adapter_bitmap: Bitmap::new(),
-            recv_adapter_bitmap: Bitmap::new(),
-        });
+        integrator_chain_config.set_inner(IntegratorChainConfig::new(
+            ctx.bumps.integrator_chain_config,
+            args.chain_id,
+            args.integrator_program_id,
+        ));
     }
 
     if integrator_chain_config
@@ -187,6 +197,7 @@ pub fn enable_send_adapter(ctx: Context<EnableAdapter>, args: AdapterInfoArgs) -
     integrator_chain_config
         .send_adapter_bitmap
         .set(adapter_info.index, true)?;
+    adapter_info.enabled_count += 1;
 
     emit_cpi!(SendAdapterEnabledForChain {
         integrator: args.integrator_program_id,
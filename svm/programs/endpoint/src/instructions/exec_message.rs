@@ -1,7 +1,13 @@
 use anchor_lang::prelude::*;
 use universal_address::UniversalAddress;
 
-use crate::{error::EndpointError, event::MessageExecuted, state::AttestationInfo};
+use crate::{
+    error::EndpointError,
+    event::MessageExecuted,
+    state::{
+        AttestationInfo, EndpointConfig, IntegratorChainConfig, IntegratorConfig, ReplayBitmap,
+    },
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct ExecMessageArgs {
@@ -30,6 +36,31 @@ pub struct ExecMessage<'info> {
     )]
     pub integrator_program_pda: Signer<'info>,
 
+    /// The integrator config account
+    /// This is used to check that receiving is not paused for the integrator
+    #[account(
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+        constraint = !integrator_config.paused_recv @ EndpointError::RecvPaused,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The integrator chain config account
+    /// This is used to check that receiving from the source chain is not paused. It is not
+    /// initialized if no adapter was ever enabled for the source chain, in which case the chain is
+    /// not paused
+    /// CHECK: The address is checked by the seeds constraint and the data is deserialized in the
+    /// instruction if the account is initialized
+    #[account(
+        seeds = [
+            IntegratorChainConfig::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.src_chain.to_be_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub integrator_chain_config: UncheckedAccount<'info>,
+
     /// The attestation info account
     /// This account is initialized if it doesn't exist
     #[account(
@@ -51,16 +82,46 @@ pub struct ExecMessage<'info> {
     )]
     pub attestation_info: Account<'info, AttestationInfo>,
 
+    /// The replay bitmap account tracking the sequence of the message
+    /// This account is initialized if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ReplayBitmap::INIT_SPACE,
+        seeds = [
+            ReplayBitmap::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.src_chain.to_be_bytes().as_ref(),
+            args.src_addr.to_bytes().as_ref(),
+            ReplayBitmap::index_of(args.sequence).to_be_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub replay_bitmap: Account<'info, ReplayBitmap>,
+
+    /// The endpoint config account
+    /// This is used to check that the endpoint is not paused
+    #[account(
+        seeds = [EndpointConfig::SEED_PREFIX],
+        bump = endpoint_config.bump,
+        constraint = !endpoint_config.paused @ EndpointError::EndpointPaused,
+    )]
+    pub endpoint_config: Account<'info, EndpointConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
 /// Executes a message in the endpoint program
 ///
 /// This function is responsible for marking a message as executed. It performs the following steps:
-/// 1. Checks if the message has already been executed.
-/// 2. Initializes the attestation info if it's newly created.
-/// 3. Marks the message as executed.
-/// 4. Emits a MessageExecuted event.
+/// 1. Checks that the message is destined to this chain.
+/// 2. Checks that receiving from the source chain is not paused and that the source address is
+///    a peer, if peers are set for the chain.
+/// 3. Initializes the replay bitmap if it's newly created.
+/// 4. Checks if the message has already been executed.
+/// 5. Initializes the attestation info if it's newly created.
+/// 6. Marks the message as executed in the attestation info and the replay bitmap.
+/// 7. Emits a MessageExecuted event.
 ///
 /// # Arguments
 ///
@@ -81,17 +142,60 @@ pub struct ExecMessage<'info> {
 /// # Errors
 ///
 /// This function will return an error if:
-/// * The message has already been executed (EndpointError::AlreadyExecuted)
+/// * The endpoint is paused (EndpointError::EndpointPaused)
+/// * Receiving is paused for the integrator or the source chain (EndpointError::RecvPaused)
+/// * The source address is not a peer of the source chain (EndpointError::UnknownPeer)
+/// * Either chain ID is 0 (EndpointError::InvalidChainId)
+/// * The destination chain is not this chain (EndpointError::InvalidDestinationChain)
+/// * The message has already been executed, including when its attestation info was closed
+///   (EndpointError::AlreadyExecuted)
 ///
 /// # Events
 ///
 /// Emits a `MessageExecuted` event
 pub fn exec_message(ctx: Context<ExecMessage>, args: ExecMessageArgs) -> Result<()> {
+    // Validate that the destination chain is this program's chain
+    AttestationInfo::validate_chains(args.src_chain, args.dst_chain)?;
+
+    // Check that receiving from the source chain is not paused and that the sender is a peer
+    if let Some(integrator_chain_config) =
+        IntegratorChainConfig::try_load(&ctx.accounts.integrator_chain_config)?
+    {
+        require!(
+            !integrator_chain_config.paused_recv,
+            EndpointError::RecvPaused
+        );
+        require!(
+            integrator_chain_config.is_peer(&args.src_addr),
+            EndpointError::UnknownPeer
+        );
+    }
+
     let attestation_info = &mut ctx.accounts.attestation_info;
 
     // Check if the message has already been executed
     require!(!attestation_info.executed, EndpointError::AlreadyExecuted);
 
+    let replay_bitmap = &mut ctx.accounts.replay_bitmap;
+
+    // If the replay_bitmap is newly created, initialize it
+    // It is fine to check using src_chain == 0 as chain 0 is not a valid source chain
+    if replay_bitmap.src_chain == 0 {
+        replay_bitmap.set_inner(ReplayBitmap::new(
+            ctx.bumps.replay_bitmap,
+            args.integrator_program_id,
+            args.src_chain,
+            args.src_addr,
+            args.sequence,
+        ));
+    }
+
+    // The replay bitmap keeps the executed flag once the attestation info has been closed
+    require!(
+        !replay_bitmap.is_executed(args.sequence),
+        EndpointError::AlreadyExecuted
+    );
+
     // If the attestation_info is newly created, initialize it
     if attestation_info.src_chain == 0 {
         attestation_info.set_inner(AttestationInfo::new(
@@ -103,10 +207,13 @@ pub fn exec_message(ctx: Context<ExecMessage>, args: ExecMessageArgs) -> Result<
             UniversalAddress::from_pubkey(&args.integrator_program_id),
             args.payload_hash,
         )?);
+        // Recorded so the rent can be refunded once the account is closed
+        attestation_info.payer = ctx.accounts.payer.key();
     }
 
     // Mark the message as executed
     attestation_info.executed = true;
+    replay_bitmap.set_executed(args.sequence);
 
     emit_cpi!(MessageExecuted {
         message_hash: attestation_info.message_hash,
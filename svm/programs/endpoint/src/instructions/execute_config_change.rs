@@ -0,0 +1,488 @@
+use crate::{
+    error::EndpointError,
+    event::{
+        AdapterAdded, AdapterReplaced, ChainPeersUpdated, ConfigChangeExecuted, ConfigDelayUpdated,
+        InboundRateLimitUpdated, MinAttestationAgeUpdated, OutboundRateLimitUpdated,
+        RecvAdapterDisabledForChain, RecvAdapterEnabledForChain, RecvThresholdUpdated,
+        SendAdapterDisabledForChain, SendAdapterEnabledForChain,
+    },
+    instructions::set_chain_adapters::update_chain_adapters,
+    state::{
+        AdapterInfo, ConfigChange, IntegratorChainConfig, IntegratorConfig, PendingConfigChange,
+    },
+    utils::bitmap::Bitmap,
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteConfigChange<'info> {
+    /// The account that pays for the accounts initialized by the change
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The integrator config account
+    #[account(
+        mut,
+        seeds = [
+            IntegratorConfig::SEED_PREFIX,
+            pending_config_change.integrator_program_id.as_ref(),
+        ],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The proposed change
+    /// This account is closed and its rent refunded to the account that paid for it
+    #[account(
+        mut,
+        seeds = [
+            PendingConfigChange::SEED_PREFIX,
+            pending_config_change.integrator_program_id.as_ref(),
+            pending_config_change.id.to_be_bytes().as_ref(),
+        ],
+        bump = pending_config_change.bump,
+        close = rent_recipient,
+    )]
+    pub pending_config_change: Account<'info, PendingConfigChange>,
+
+    /// The account that paid the rent of the pending config change account
+    /// CHECK: The address is checked against the payer recorded in the pending config change
+    #[account(mut, address = pending_config_change.payer)]
+    pub rent_recipient: UncheckedAccount<'info>,
+
+    /// The account to store information about the added adapter
+    /// Only passed to add or replace an adapter. The `init` constraint checks that the adapter has
+    /// not been added
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AdapterInfo::INIT_SPACE,
+        seeds = [
+            AdapterInfo::SEED_PREFIX,
+            pending_config_change.integrator_program_id.as_ref(),
+            pending_config_change.change.new_adapter_program_id().unwrap_or_default().as_ref(),
+        ],
+        bump
+    )]
+    pub new_adapter_info: Option<Account<'info, AdapterInfo>>,
+
+    /// The registered adapter account
+    /// Only passed to enable, disable or replace an adapter. The account of a replaced adapter is
+    /// closed and its rent refunded to the payer
+    #[account(
+        mut,
+        seeds = [
+            AdapterInfo::SEED_PREFIX,
+            pending_config_change.integrator_program_id.as_ref(),
+            pending_config_change.change.adapter_program_id().unwrap_or_default().as_ref(),
+        ],
+        bump = adapter_info.bump,
+    )]
+    pub adapter_info: Option<Account<'info, AdapterInfo>>,
+
+    /// The integrator chain config account
+    /// Only passed for changes that apply to a chain. This account will be initialized if it
+    /// doesn't exist
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + IntegratorChainConfig::INIT_SPACE,
+        seeds = [
+            IntegratorChainConfig::SEED_PREFIX,
+            pending_config_change.integrator_program_id.as_ref(),
+            pending_config_change.change.chain_id().unwrap_or_default().to_be_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub integrator_chain_config: Option<Account<'info, IntegratorChainConfig>>,
+
+    /// The system program
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExecuteConfigChange<'info> {
+    pub fn validate(&self) -> Result<()> {
+        // Discarding the admin makes the config immutable, including through the changes
+        // proposed before
+        require!(
+            self.integrator_config.admin.is_some(),
+            EndpointError::CallerNotAuthorized
+        );
+
+        require!(
+            Clock::get()?.slot >= self.pending_config_change.eta,
+            EndpointError::ConfigDelayNotElapsed
+        );
+
+        // Exactly the accounts the change applies to must be passed
+        let change = &self.pending_config_change.change;
+        require!(
+            self.new_adapter_info.is_some() == change.new_adapter_program_id().is_some()
+                && self.adapter_info.is_some() == change.adapter_program_id().is_some()
+                && self.integrator_chain_config.is_some() == change.chain_id().is_some(),
+            EndpointError::InvalidConfigChangeAccounts
+        );
+
+        Ok(())
+    }
+}
+
+/// Enables or disables an adapter in an adapter bitmap of a chain, updating the number of chains
+/// the adapter is enabled on
+fn set_adapter_enabled(
+    bitmap: &mut Bitmap,
+    adapter_info: &mut AdapterInfo,
+    enabled: bool,
+) -> Result<()> {
+    if bitmap.get(adapter_info.index)? == enabled {
+        return Err(if enabled {
+            EndpointError::AdapterAlreadyEnabled
+        } else {
+            EndpointError::AdapterAlreadyDisabled
+        }
+        .into());
+    }
+
+    bitmap.set(adapter_info.index, enabled)?;
+    if enabled {
+        adapter_info.enabled_count += 1;
+    } else {
+        adapter_info.enabled_count -= 1;
+    }
+
+    Ok(())
+}
+
+/// Executes a proposed config change of an integrator once the config delay has elapsed
+///
+/// This function performs the following steps:
+/// 1. Checks that the admin of the integrator has not been discarded.
+/// 2. Checks that the slot from which the change can be executed has been reached.
+/// 3. Checks that exactly the accounts the change applies to are passed.
+/// 4. Initializes the IntegratorChainConfig if the change applies to a chain and it's not already
+///    set up.
+/// 5. Applies the change, with the same checks and events as the instruction it delays.
+/// 6. Emits a ConfigChangeExecuted event.
+/// 7. Closes the PendingConfigChange account, refunding its rent to the account that paid for it.
+///
+/// This can be called by anyone. Adding an adapter requires `new_adapter_info`, replacing an
+/// adapter requires `adapter_info` and `new_adapter_info`, enabling or disabling an adapter
+/// requires `adapter_info` and `integrator_chain_config`, and setting the adapters, a receive
+/// threshold, a minimum attestation age, the peers or a rate limit of a chain requires
+/// `integrator_chain_config`. Setting the adapters of a chain also requires the AdapterInfo
+/// account of every changed adapter as a writable remaining account, ordered by adapter index. Changes proposed before the admin was discarded can no longer be
+/// executed, and only the guardian can cancel them.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The admin of the integrator has been discarded (EndpointError::CallerNotAuthorized)
+/// * The config delay has not elapsed (EndpointError::ConfigDelayNotElapsed)
+/// * The accounts do not match the change (EndpointError::InvalidConfigChangeAccounts)
+/// * The rent recipient is not the payer of the pending config change
+///   (ErrorCode::ConstraintAddress)
+/// * The change itself fails, e.g. the adapter is already enabled
+///   (EndpointError::AdapterAlreadyEnabled) or the threshold is too high
+///   (EndpointError::InvalidThreshold) or there are too many peers (EndpointError::InvalidPeers)
+///   or the AdapterInfo account of a changed adapter is missing (EndpointError::InvalidAdapterInfo)
+///   or the rate limit has a zero window (EndpointError::InvalidRateLimit)
+///
+/// # Events
+///
+/// Emits the event of the applied change and a `ConfigChangeExecuted` event
+#[access_control(ExecuteConfigChange::validate(&ctx.accounts))]
+pub fn execute_config_change<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteConfigChange<'info>>,
+) -> Result<()> {
+    let integrator_program_id = ctx.accounts.pending_config_change.integrator_program_id;
+    let id = ctx.accounts.pending_config_change.id;
+    let change = ctx.accounts.pending_config_change.change.clone();
+
+    if let Some(integrator_chain_config) = ctx.accounts.integrator_chain_config.as_mut() {
+        // If chain_id is 0, this is initial setup
+        if integrator_chain_config.chain_id == 0 {
+            integrator_chain_config.set_inner(IntegratorChainConfig::new(
+                ctx.bumps.integrator_chain_config.unwrap_or_default(),
+                change.chain_id().unwrap_or_default(),
+                integrator_program_id,
+            ));
+        }
+    }
+
+    // The presence of the accounts used by each change is checked in `validate`
+    let integrator_config = &mut ctx.accounts.integrator_config;
+    let new_adapter_info = &mut ctx.accounts.new_adapter_info;
+    let adapter_info = &mut ctx.accounts.adapter_info;
+    let integrator_chain_config = &mut ctx.accounts.integrator_chain_config;
+
+    match change.clone() {
+        ConfigChange::AddAdapter { adapter_program_id } => {
+            let index = integrator_config.add_adapter(adapter_program_id)?;
+            new_adapter_info.as_mut().unwrap().set_inner(AdapterInfo {
+                bump: ctx.bumps.new_adapter_info.unwrap_or_default(),
+                index,
+                integrator_program_id,
+                adapter_program_id,
+                enabled_count: 0,
+            });
+
+            emit_cpi!(AdapterAdded {
+                integrator: integrator_program_id,
+                adapter: adapter_program_id,
+                adapters_num: index,
+            });
+        }
+        ConfigChange::EnableSendAdapter {
+            chain_id,
+            adapter_program_id,
+        } => {
+            set_adapter_enabled(
+                &mut integrator_chain_config
+                    .as_mut()
+                    .unwrap()
+                    .send_adapter_bitmap,
+                adapter_info.as_mut().unwrap(),
+                true,
+            )?;
+
+            emit_cpi!(SendAdapterEnabledForChain {
+                integrator: integrator_program_id,
+                chain: chain_id,
+                adapter: adapter_program_id,
+            });
+        }
+        ConfigChange::EnableRecvAdapter {
+            chain_id,
+            adapter_program_id,
+        } => {
+            set_adapter_enabled(
+                &mut integrator_chain_config
+                    .as_mut()
+                    .unwrap()
+                    .recv_adapter_bitmap,
+                adapter_info.as_mut().unwrap(),
+                true,
+            )?;
+
+            emit_cpi!(RecvAdapterEnabledForChain {
+                integrator: integrator_program_id,
+                chain: chain_id,
+                adapter: adapter_program_id,
+            });
+        }
+        ConfigChange::DisableSendAdapter {
+            chain_id,
+            adapter_program_id,
+        } => {
+            set_adapter_enabled(
+                &mut integrator_chain_config
+                    .as_mut()
+                    .unwrap()
+                    .send_adapter_bitmap,
+                adapter_info.as_mut().unwrap(),
+                false,
+            )?;
+
+            emit_cpi!(SendAdapterDisabledForChain {
+                integrator: integrator_program_id,
+                chain: chain_id,
+                adapter: adapter_program_id,
+            });
+        }
+        ConfigChange::DisableRecvAdapter {
+            chain_id,
+            adapter_program_id,
+        } => {
+            set_adapter_enabled(
+                &mut integrator_chain_config
+                    .as_mut()
+                    .unwrap()
+                    .recv_adapter_bitmap,
+                adapter_info.as_mut().unwrap(),
+                false,
+            )?;
+
+            emit_cpi!(RecvAdapterDisabledForChain {
+                integrator: integrator_program_id,
+                chain: chain_id,
+                adapter: adapter_program_id,
+            });
+        }
+        ConfigChange::SetRecvThreshold {
+            chain_id,
+            threshold,
+        } => {
+            let integrator_chain_config = integrator_chain_config.as_mut().unwrap();
+
+            // A threshold above the number of enabled receive adapters could never be met
+            require!(
+                usize::from(threshold) <= integrator_chain_config.recv_adapter_bitmap.len(),
+                EndpointError::InvalidThreshold
+            );
+
+            let old_threshold = integrator_chain_config.recv_threshold;
+            integrator_chain_config.recv_threshold = threshold;
+
+            emit_cpi!(RecvThresholdUpdated {
+                integrator: integrator_program_id,
+                chain: chain_id,
+                old_threshold,
+                new_threshold: threshold,
+            });
+        }
+        ConfigChange::SetConfigDelay { delay } => {
+            integrator_config.config_delay = delay;
+
+            emit_cpi!(ConfigDelayUpdated {
+                integrator: integrator_program_id,
+                delay,
+            });
+        }
+        ConfigChange::SetMinAttestationAge {
+            chain_id,
+            min_attestation_age,
+        } => {
+            integrator_chain_config
+                .as_mut()
+                .unwrap()
+                .min_attestation_age = min_attestation_age;
+
+            emit_cpi!(MinAttestationAgeUpdated {
+                integrator: integrator_program_id,
+                chain: chain_id,
+                min_attestation_age,
+            });
+        }
+        ConfigChange::SetChainPeers { chain_id, peers } => {
+            IntegratorChainConfig::validate_peers(&peers)?;
+            integrator_chain_config.as_mut().unwrap().peers = peers.clone();
+
+            emit_cpi!(ChainPeersUpdated {
+                integrator: integrator_program_id,
+                chain: chain_id,
+                peers,
+            });
+        }
+        ConfigChange::ReplaceAdapter {
+            adapter_program_id,
+            new_adapter_program_id,
+        } => {
+            let old_adapter_info = adapter_info.as_mut().unwrap();
+            let index = old_adapter_info.index;
+            let enabled_count = old_adapter_info.enabled_count;
+
+            integrator_config.replace_adapter(index, new_adapter_program_id)?;
+            new_adapter_info.as_mut().unwrap().set_inner(AdapterInfo {
+                bump: ctx.bumps.new_adapter_info.unwrap_or_default(),
+                index,
+                integrator_program_id,
+                adapter_program_id: new_adapter_program_id,
+                enabled_count,
+            });
+            old_adapter_info.close(ctx.accounts.payer.to_account_info())?;
+
+            emit_cpi!(AdapterReplaced {
+                integrator: integrator_program_id,
+                old_adapter: adapter_program_id,
+                new_adapter: new_adapter_program_id,
+                index,
+            });
+        }
+        ConfigChange::SetChainAdapters {
+            chain_id,
+            send_adapter_bitmap,
+            recv_adapter_bitmap,
+        } => {
+            let changes = update_chain_adapters(
+                integrator_config,
+                integrator_chain_config.as_mut().unwrap(),
+                send_adapter_bitmap,
+                recv_adapter_bitmap,
+                ctx.remaining_accounts,
+            )?;
+
+            for change in changes {
+                match change.send {
+                    Some(true) => emit_cpi!(SendAdapterEnabledForChain {
+                        integrator: integrator_program_id,
+                        chain: chain_id,
+                        adapter: change.adapter_program_id,
+                    }),
+                    Some(false) => emit_cpi!(SendAdapterDisabledForChain {
+                        integrator: integrator_program_id,
+                        chain: chain_id,
+                        adapter: change.adapter_program_id,
+                    }),
+                    None => {}
+                }
+
+                match change.recv {
+                    Some(true) => emit_cpi!(RecvAdapterEnabledForChain {
+                        integrator: integrator_program_id,
+                        chain: chain_id,
+                        adapter: change.adapter_program_id,
+                    }),
+                    Some(false) => emit_cpi!(RecvAdapterDisabledForChain {
+                        integrator: integrator_program_id,
+                        chain: chain_id,
+                        adapter: change.adapter_program_id,
+                    }),
+                    None => {}
+                }
+            }
+        }
+        ConfigChange::SetOutboundRateLimit {
+            chain_id,
+            limit,
+            window,
+        } => {
+            integrator_chain_config
+                .as_mut()
+                .unwrap()
+                .outbound_rate_limit
+                .set_limit(limit, window, Clock::get()?.slot)?;
+
+            emit_cpi!(OutboundRateLimitUpdated {
+                integrator: integrator_program_id,
+                chain: chain_id,
+                limit,
+                window,
+            });
+        }
+        ConfigChange::SetInboundRateLimit {
+            chain_id,
+            limit,
+            window,
+            queue_delay,
+        } => {
+            let integrator_chain_config = integrator_chain_config.as_mut().unwrap();
+            integrator_chain_config.inbound_rate_limit.set_limit(
+                limit,
+                window,
+                Clock::get()?.slot,
+            )?;
+            integrator_chain_config.inbound_queue_delay = queue_delay;
+
+            emit_cpi!(InboundRateLimitUpdated {
+                integrator: integrator_program_id,
+                chain: chain_id,
+                limit,
+                window,
+                queue_delay,
+            });
+        }
+    }
+
+    emit_cpi!(ConfigChangeExecuted {
+        integrator: integrator_program_id,
+        id,
+        change,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use universal_address::UniversalAddress;
+
+use crate::state::{AttestationInfo, IntegratorChainConfig, IntegratorConfig, ReplayBitmap};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct GetMessageStatusArgs {
+    pub src_chain: u16,
+    pub src_addr: UniversalAddress,
+    pub sequence: u64,
+    pub dst_chain: u16,
+    pub integrator_program_id: Pubkey,
+    pub payload_hash: [u8; 32],
+}
+
+/// The status of a message, as returned by `get_message_status`
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct MessageStatus {
+    /// The bitmap of receive adapters enabled for the source chain
+    pub enabled_bitmap: u128,
+
+    /// The bitmap of adapters that attested to the message
+    /// Attestations of removed or replaced adapters are excluded
+    pub attested_bitmap: u128,
+
+    /// Whether the message has been executed
+    pub executed: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(args: GetMessageStatusArgs)]
+pub struct GetMessageStatus<'info> {
+    /// The integrator config account
+    /// This is required to exclude the attestations of adapters whose slot was reassigned
+    #[account(
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The integrator chain config account
+    /// This is required to read the enabled_bitmap from
+    #[account(
+        seeds = [
+            IntegratorChainConfig::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.src_chain.to_be_bytes().as_ref()
+        ],
+        bump = integrator_chain_config.bump,
+    )]
+    pub integrator_chain_config: Account<'info, IntegratorChainConfig>,
+
+    /// The attestation info account
+    /// This is not initialized until the message is attested to or executed, in which case the
+    /// message has no attestations and has not been executed
+    /// CHECK: The address is checked by the seeds constraint and the data is deserialized in the
+    /// instruction if the account is initialized
+    #[account(
+        seeds = [
+            AttestationInfo::SEED_PREFIX,
+            &AttestationInfo::compute_message_hash(
+                args.src_chain,
+                args.src_addr,
+                args.sequence,
+                args.dst_chain,
+                UniversalAddress::from_pubkey(&args.integrator_program_id),
+                args.payload_hash
+            )
+        ],
+        bump,
+    )]
+    pub attestation_info: UncheckedAccount<'info>,
+
+    /// The replay bitmap account tracking the sequence of the message
+    /// This is initialized by `recv_message` or `exec_message` once a message it tracks has been
+    /// executed, so it is not initialized for a sequence that was never executed
+    /// CHECK: The address is checked by the seeds constraint and the data is deserialized in the
+    /// instruction if the account is initialized
+    #[account(
+        seeds = [
+            ReplayBitmap::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.src_chain.to_be_bytes().as_ref(),
+            args.src_addr.to_bytes().as_ref(),
+            ReplayBitmap::index_of(args.sequence).to_be_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub replay_bitmap: UncheckedAccount<'info>,
+}
+
+/// Returns the status of a message
+///
+/// This function performs the following steps:
+/// 1. Reads the enabled receive adapters for the source chain.
+/// 2. Reads the attestations and the executed flag of the message, if it has an `AttestationInfo`,
+///    excluding the attestations of adapters whose slot was freed or migrated since.
+/// 3. Reads the executed flag from the `ReplayBitmap`, in case the attestation info has been closed.
+/// 4. Returns them as a `MessageStatus`.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved.
+/// * `args` - The arguments for the instruction, including:
+///   * `src_chain`: The source chain ID.
+///   * `src_addr`: The source address as a UniversalAddress.
+///   * `sequence`: The sequence number of the message.
+///   * `dst_chain`: The destination chain ID.
+///   * `integrator_program_id`: The public key of the integrator program.
+///   * `payload_hash`: The hash of the message payload.
+///
+/// # Returns
+///
+/// Returns the `MessageStatus` of the message, also available to callers as return data.
+/// A message that has not been attested to nor executed has an empty attested bitmap and is not
+/// executed. The attestations of a message whose attestation info has been closed are no longer
+/// available, so only the executed flag is reported for it.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * No adapter was ever enabled for the source chain (ErrorCode::AccountNotInitialized)
+/// * The attestation info or replay bitmap account is initialized but cannot be deserialized
+pub fn get_message_status(
+    ctx: Context<GetMessageStatus>,
+    args: GetMessageStatusArgs,
+) -> Result<MessageStatus> {
+    let attestation_info = &ctx.accounts.attestation_info;
+
+    // The account may hold lamports without being initialized, so the owner is checked rather
+    // than the balance
+    let (attested_bitmap, executed) = if attestation_info.owner == &crate::ID {
+        let attestation_info =
+            AttestationInfo::try_deserialize(&mut &attestation_info.try_borrow_data()?[..])?;
+        (
+            attestation_info
+                .current_attestations(&ctx.accounts.integrator_config)?
+                .as_value(),
+            attestation_info.executed,
+        )
+    } else {
+        (0, false)
+    };
+
+    // The attestation info of an executed message may have been closed, so the replay bitmap is
+    // checked either way
+    let executed =
+        executed || ReplayBitmap::is_executed_in(&ctx.accounts.replay_bitmap, args.sequence)?;
+
+    Ok(MessageStatus {
+        enabled_bitmap: ctx
+            .accounts
+            .integrator_chain_config
+            .recv_adapter_bitmap
+            .as_value(),
+        attested_bitmap,
+        executed,
+    })
+}
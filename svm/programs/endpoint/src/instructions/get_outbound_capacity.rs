@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::state::IntegratorChainConfig;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct GetOutboundCapacityArgs {
+    pub integrator_program_id: Pubkey,
+    pub dst_chain: u16,
+}
+
+#[derive(Accounts)]
+#[instruction(args: GetOutboundCapacityArgs)]
+pub struct GetOutboundCapacity<'info> {
+    /// The integrator chain config account
+    /// This is required to read the outbound rate limit from
+    #[account(
+        seeds = [
+            IntegratorChainConfig::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.dst_chain.to_be_bytes().as_ref()
+        ],
+        bump = integrator_chain_config.bump,
+    )]
+    pub integrator_chain_config: Account<'info, IntegratorChainConfig>,
+}
+
+/// Returns the number of messages that can currently be sent to a chain
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved.
+/// * `args` - The arguments for the instruction, including:
+///   * `integrator_program_id`: The public key of the integrator program.
+///   * `dst_chain`: The destination chain ID.
+///
+/// # Returns
+///
+/// Returns the remaining capacity of the outbound rate limit at the current slot, also available
+/// to callers as return data. `u64::MAX` is returned if the rate limit is disabled.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * No adapter was ever enabled for the destination chain (ErrorCode::AccountNotInitialized)
+pub fn get_outbound_capacity(
+    ctx: Context<GetOutboundCapacity>,
+    _args: GetOutboundCapacityArgs,
+) -> Result<u64> {
+    Ok(ctx
+        .accounts
+        .integrator_chain_config
+        .outbound_rate_limit
+        .capacity_at(Clock::get()?.slot))
+}
@@ -0,0 +1,91 @@
+use crate::event::{RoleGranted, RoleRevoked};
+use crate::state::{IntegratorConfig, Role};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct GrantRoleArgs {
+    /// The integrator_program for the integrator_config
+    pub integrator_program_id: Pubkey,
+
+    /// The role to grant
+    pub role: Role,
+
+    /// The account the role is granted to
+    pub account: Pubkey,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: GrantRoleArgs)]
+pub struct GrantRole<'info> {
+    /// The current admin of the IntegratorConfig account
+    pub admin: Signer<'info>,
+
+    /// The IntegratorConfig account being updated
+    #[account(
+        mut,
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+}
+
+impl<'info> GrantRole<'info> {
+    pub fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        self.integrator_config
+            .check_admin(&self.admin, remaining_accounts)
+    }
+}
+
+/// Grants a role of an integrator to an account
+///
+/// This function performs the following steps:
+/// 1. Validates that the admin is the signer and that there's no pending admin transfer.
+/// 2. Emits a RoleRevoked event if the role is held by another account.
+/// 3. Sets the holder of the role in the IntegratorConfig account.
+/// 4. Emits a RoleGranted event.
+///
+/// Each role is held by a single account, so granting a role transfers it from its previous
+/// holder.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the grant_role instruction, containing:
+///   - `integrator_program_id`: The public key of the integrator program
+///   - `role`: The role to grant
+///   - `account`: The account the role is granted to
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is a pending admin transfer (EndpointError::AdminTransferInProgress)
+/// * The admin is not the signer (EndpointError::CallerNotAuthorized)
+///
+/// # Events
+///
+/// Emits a `RoleGranted` event, preceded by a `RoleRevoked` event for the previous holder
+#[access_control(GrantRole::validate(&ctx.accounts, ctx.remaining_accounts))]
+pub fn grant_role(ctx: Context<GrantRole>, args: GrantRoleArgs) -> Result<()> {
+    let integrator_config = &mut ctx.accounts.integrator_config;
+
+    if let Some(old_account) = integrator_config.role(args.role) {
+        if old_account != args.account {
+            emit_cpi!(RoleRevoked {
+                integrator: args.integrator_program_id,
+                role: args.role,
+                account: old_account,
+            });
+        }
+    }
+
+    integrator_config.set_role(args.role, Some(args.account));
+
+    emit_cpi!(RoleGranted {
+        integrator: args.integrator_program_id,
+        role: args.role,
+        account: args.account,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::state::AdapterFeeVault;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitAdapterFeeVaultArgs {
+    /// The program ID of the adapter the vault holds the fees of
+    pub adapter_program_id: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(args: InitAdapterFeeVaultArgs)]
+pub struct InitAdapterFeeVault<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The fee vault of the adapter
+    /// `init` constraint checks that the vault has not been initialized
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AdapterFeeVault::INIT_SPACE,
+        seeds = [AdapterFeeVault::SEED_PREFIX, args.adapter_program_id.as_ref()],
+        bump
+    )]
+    pub adapter_fee_vault: Account<'info, AdapterFeeVault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Initializes the fee vault of an adapter program
+///
+/// This can be called by anyone, and must be called once before the adapter picks up its first
+/// message. The payer funds the rent of the vault, which keeps it rent exempt whatever the fees
+/// credited to it.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the instruction, containing:
+///   - `adapter_program_id`: The program ID of the adapter
+pub fn init_adapter_fee_vault(
+    ctx: Context<InitAdapterFeeVault>,
+    args: InitAdapterFeeVaultArgs,
+) -> Result<()> {
+    ctx.accounts.adapter_fee_vault.set_inner(AdapterFeeVault {
+        bump: ctx.bumps.adapter_fee_vault,
+        adapter_program_id: args.adapter_program_id,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+use crate::{error::EndpointError, event::EndpointInitialized, state::EndpointConfig};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeArgs {
+    /// The guardian allowed to pause and unpause the endpoint
+    pub guardian: Pubkey,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The upgrade authority of the endpoint program
+    /// This makes sure that the endpoint config cannot be initialized by anyone else
+    pub upgrade_authority: Signer<'info>,
+
+    /// The program data account of the endpoint program, holding its upgrade authority
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key())
+            @ EndpointError::CallerNotAuthorized,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// The EndpointConfig account being initialized
+    /// `init` constraint checks that the endpoint is not already initialized
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + EndpointConfig::INIT_SPACE,
+        seeds = [EndpointConfig::SEED_PREFIX],
+        bump
+    )]
+    pub endpoint_config: Account<'info, EndpointConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Initializes the program-wide endpoint config
+///
+/// This function performs the following steps:
+/// 1. Checks that the signer is the upgrade authority of the endpoint program.
+/// 2. Initializes the EndpointConfig account with the guardian, unpaused.
+/// 3. Emits an EndpointInitialized event.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the initialize instruction, containing:
+///   - `guardian`: The guardian allowed to pause and unpause the endpoint
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The signer is not the upgrade authority of the endpoint program (EndpointError::CallerNotAuthorized)
+/// * The endpoint config is already initialized
+///
+/// # Events
+///
+/// Emits an `EndpointInitialized` event
+pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
+    ctx.accounts.endpoint_config.set_inner(EndpointConfig {
+        bump: ctx.bumps.endpoint_config,
+        guardian: args.guardian,
+        paused: false,
+    });
+
+    emit_cpi!(EndpointInitialized {
+        guardian: args.guardian,
+    });
+
+    Ok(())
+}
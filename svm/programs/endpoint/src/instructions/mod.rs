@@ -1,27 +1,89 @@
 pub mod add_adapter;
 pub mod attest_message;
+pub mod cancel_config_change;
+pub mod cancel_message;
+pub mod claim_adapter_fees;
+pub mod close_attestation_info;
 pub mod common;
 pub mod disable_adapter;
 pub mod discard_admin;
 pub mod enable_adapter;
 pub mod exec_message;
+pub mod execute_config_change;
+pub mod get_message_status;
+pub mod get_outbound_capacity;
+pub mod grant_role;
+pub mod init_adapter_fee_vault;
+pub mod initialize;
 pub mod pick_up_message;
+pub mod propose_config_change;
+pub mod quote_delivery_price;
+pub mod reclaim_outbox_message;
 pub mod recv_message;
 pub mod register;
+pub mod release_queued_message;
+pub mod remove_adapter;
+pub mod replace_adapter;
+pub mod revoke_role;
 pub mod send_message;
+pub mod set_admin_signers;
+pub mod set_chain_adapters;
+pub mod set_chain_paused;
+pub mod set_chain_peers;
+pub mod set_config_delay;
+pub mod set_enforce_peers;
+pub mod set_inbound_rate_limit;
+pub mod set_integrator_paused;
+pub mod set_min_attestation_age;
+pub mod set_outbound_rate_limit;
+pub mod set_outbox_message_timeout;
+pub mod set_paused;
+pub mod set_recv_threshold;
 pub mod transfer_admin;
 pub mod update_admin;
+pub mod update_threshold_reached_slot;
 
 pub use add_adapter::*;
 pub use attest_message::*;
+pub use cancel_config_change::*;
+pub use cancel_message::*;
+pub use claim_adapter_fees::*;
+pub use close_attestation_info::*;
 pub use common::*;
 pub use disable_adapter::*;
 pub use discard_admin::*;
 pub use enable_adapter::*;
 pub use exec_message::*;
+pub use execute_config_change::*;
+pub use get_message_status::*;
+pub use get_outbound_capacity::*;
+pub use grant_role::*;
+pub use init_adapter_fee_vault::*;
+pub use initialize::*;
 pub use pick_up_message::*;
+pub use propose_config_change::*;
+pub use quote_delivery_price::*;
+pub use reclaim_outbox_message::*;
 pub use recv_message::*;
 pub use register::*;
+pub use release_queued_message::*;
+pub use remove_adapter::*;
+pub use replace_adapter::*;
+pub use revoke_role::*;
 pub use send_message::*;
+pub use set_admin_signers::*;
+pub use set_chain_adapters::*;
+pub use set_chain_paused::*;
+pub use set_chain_peers::*;
+pub use set_config_delay::*;
+pub use set_enforce_peers::*;
+pub use set_inbound_rate_limit::*;
+pub use set_integrator_paused::*;
+pub use set_min_attestation_age::*;
+pub use set_outbound_rate_limit::*;
+pub use set_outbox_message_timeout::*;
+pub use set_paused::*;
+pub use set_recv_threshold::*;
 pub use transfer_admin::*;
 pub use update_admin::*;
+pub use update_threshold_reached_slot::*;
@@ -1,7 +1,8 @@
 use crate::{
     error::EndpointError,
     event::MessagePickedUp,
-    state::{AdapterInfo, OutboxMessage},
+    state::{AdapterFeeVault, AdapterInfo, EndpointConfig, IntegratorConfig, OutboxMessage},
+    utils::adapter_instructions,
 };
 use anchor_lang::prelude::*;
 
@@ -19,6 +20,12 @@ pub struct PickUpMessage<'info> {
     /// This account is mutable so we can update the `outstanding_adapters` state
     #[account(
         mut,
+        seeds = [
+            OutboxMessage::SEED_PREFIX,
+            outbox_message.src_addr.to_pubkey().as_ref(),
+            outbox_message.sequence.to_be_bytes().as_ref(),
+        ],
+        bump = outbox_message.bump,
         has_one = refund_recipient
     )]
     pub outbox_message: Account<'info, OutboxMessage>,
@@ -35,6 +42,17 @@ pub struct PickUpMessage<'info> {
     )]
     pub adapter_info: Account<'info, AdapterInfo>,
 
+    /// The integrator config account
+    /// This is used to detect adapter slots reassigned since the message was sent
+    #[account(
+        seeds = [
+            IntegratorConfig::SEED_PREFIX,
+            outbox_message.src_addr.to_pubkey().as_ref(),
+        ],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
     /// The adapter PDA account, used for signing
     /// This ensures that only the authorized adapter can pick up the message
     #[account(
@@ -44,11 +62,29 @@ pub struct PickUpMessage<'info> {
     )]
     pub adapter_pda: Signer<'info>,
 
+    /// The fee vault of the adapter
+    /// The escrowed delivery fee of the adapter is released to this account
+    #[account(
+        mut,
+        seeds = [AdapterFeeVault::SEED_PREFIX, args.adapter_program_id.as_ref()],
+        bump = adapter_fee_vault.bump,
+    )]
+    pub adapter_fee_vault: Account<'info, AdapterFeeVault>,
+
     /// The account that will receive the rent from closing the outbox message account
     #[account(mut)]
     /// CHECK: This is an account for receiving the rent refund
     pub refund_recipient: AccountInfo<'info>,
 
+    /// The endpoint config account
+    /// This is used to check that the endpoint is not paused
+    #[account(
+        seeds = [EndpointConfig::SEED_PREFIX],
+        bump = endpoint_config.bump,
+        constraint = !endpoint_config.paused @ EndpointError::EndpointPaused,
+    )]
+    pub endpoint_config: Account<'info, EndpointConfig>,
+
     /// The system program account
     pub system_program: Program<'info, System>,
 }
@@ -56,10 +92,19 @@ pub struct PickUpMessage<'info> {
 /// Instruction for picking up a message from the outbox.
 ///
 /// This function performs the following steps:
-/// 1. Checks if the message is available for pick up by this adapter.
-/// 2. Marks the message as picked up by updating the `outstanding_adapters` bitmap.
-/// 3. Emits a MessagePickedUp event.
-/// 4. Closes the outbox message account if all adapters have picked up the message.
+/// 1. Checks if the message is available for pick up by this adapter, and that the slot of the
+///    adapter was not reassigned since the message was sent.
+/// 2. Clears the outstanding adapters whose slot was reassigned since the message was sent, as
+///    they will never pick it up.
+/// 3. Marks the message as picked up by updating the `outstanding_adapters` bitmap.
+/// 4. Releases the escrowed delivery fee of the adapter to its fee vault.
+/// 5. Emits a MessagePickedUp event.
+/// 6. Closes the outbox message account if all adapters have picked up the message, returning
+///    the rent and any unclaimed fees to the refund recipient.
+/// 7. Returns the instruction payload provided by the integrator for this adapter.
+///
+/// The fee vault of the adapter must have been initialized with `init_adapter_fee_vault`. The
+/// adapter claims the released fees with `claim_adapter_fees`.
 ///
 /// # Arguments
 ///
@@ -69,21 +114,25 @@ pub struct PickUpMessage<'info> {
 ///
 /// # Returns
 ///
-/// Returns `Ok(())` if the message is successfully picked up, or an error otherwise.
+/// Returns the adapter instruction payload (empty if none was provided) if the message is
+/// successfully picked up, or an error otherwise.
 ///
 /// * `ctx` - The context of the instruction, containing the accounts involved.
 ///
 /// # Errors
 ///
 /// This function will return an error if:
+/// * The endpoint is paused (EndpointError::EndpointPaused)
 /// * The message has already been picked up by this adapter (EndpointError::MessageAlreadyPickedUp).
+/// * The slot of the adapter was freed or migrated after the message was sent, in which case the
+///   message belongs to the previous adapter of the slot (EndpointError::AdapterReassigned).
 /// * There's an issue updating the `outstanding_adapters` bitmap.
 /// * There's an issue closing the outbox message account when all adapters have picked up the message.
 ///
 /// # Events
 ///
 /// Emits a `MessagePickedUp` event
-pub fn pick_up_message(ctx: Context<PickUpMessage>, args: PickUpMessageArgs) -> Result<()> {
+pub fn pick_up_message(ctx: Context<PickUpMessage>, args: PickUpMessageArgs) -> Result<Vec<u8>> {
     let outbox_message = &mut ctx.accounts.outbox_message;
     let adapter_info = &ctx.accounts.adapter_info;
 
@@ -99,11 +148,34 @@ pub fn pick_up_message(ctx: Context<PickUpMessage>, args: PickUpMessageArgs) ->
         EndpointError::MessageAlreadyPickedUp
     );
 
+    // The pending pick ups and fees of a removed or replaced adapter are not taken over by the
+    // adapter reusing its slot
+    let reassigned = ctx
+        .accounts
+        .integrator_config
+        .reassigned_since(outbox_message.adapter_epoch)?;
+    require!(
+        !reassigned.get(adapter_index)?,
+        EndpointError::AdapterReassigned
+    );
+    outbox_message.clear_reassigned_adapters(reassigned);
+
     // Mark the message as picked up by this adapter
     outbox_message
         .outstanding_adapters
         .set(adapter_index, false)?;
 
+    let instruction =
+        adapter_instructions::payload_for(&outbox_message.adapter_instructions, adapter_index);
+
+    // Release the escrowed delivery fee to the fee vault of the adapter
+    // The vault is rent exempt, so it can receive fees of any amount
+    let fee = outbox_message.adapter_fee(adapter_index);
+    if fee > 0 {
+        outbox_message.sub_lamports(fee)?;
+        ctx.accounts.adapter_fee_vault.add_lamports(fee)?;
+    }
+
     emit_cpi!(MessagePickedUp {
         src_addr: outbox_message.src_addr,
         sequence: outbox_message.sequence,
@@ -121,5 +193,5 @@ pub fn pick_up_message(ctx: Context<PickUpMessage>, args: PickUpMessageArgs) ->
             .close(ctx.accounts.refund_recipient.to_account_info())?
     }
 
-    Ok(())
+    Ok(instruction)
 }
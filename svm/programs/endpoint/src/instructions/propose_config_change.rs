@@ -0,0 +1,137 @@
+use crate::{
+    error::EndpointError,
+    event::ConfigChangeProposed,
+    state::{ConfigChange, IntegratorConfig, PendingConfigChange},
+};
+use anchor_lang::prelude::*;
+
+/// Arguments for the propose_config_change instruction
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProposeConfigChangeArgs {
+    /// The Pubkey of the integrator program
+    pub integrator_program_id: Pubkey,
+
+    /// The proposed change
+    pub change: ConfigChange,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: ProposeConfigChangeArgs)]
+pub struct ProposeConfigChange<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The admin registered on IntegratorConfig
+    /// Or the holder of the configurator role
+    pub admin: Signer<'info>,
+
+    /// The integrator config account
+    /// The identifier of the next proposed change is incremented in this account
+    #[account(
+        mut,
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The account storing the proposed change until it is executed or cancelled
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PendingConfigChange::INIT_SPACE,
+        seeds = [
+            PendingConfigChange::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            integrator_config.next_config_change_id.to_be_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub pending_config_change: Account<'info, PendingConfigChange>,
+
+    /// The system program
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeConfigChange<'info> {
+    pub fn validate(
+        &self,
+        args: &ProposeConfigChangeArgs,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        self.integrator_config
+            .check_roles(args.change.roles(), &self.admin, remaining_accounts)?;
+
+        // Ensure chain_id is not zero
+        require!(
+            args.change.chain_id() != Some(0),
+            EndpointError::InvalidChainId
+        );
+
+        Ok(())
+    }
+}
+
+/// Proposes a config change of an integrator, which anyone can execute once the config delay has
+/// elapsed
+///
+/// This function performs the following steps:
+/// 1. Validates that the configurator is the signer, or that the admin is the signer and that
+///    there's no pending admin transfer. Only the admin can propose a `SetConfigDelay` change.
+/// 2. Initializes the PendingConfigChange account with the change and the slot from which it can
+///    be executed.
+/// 3. Increments the identifier of the next proposed change in IntegratorConfig.
+/// 4. Emits a ConfigChangeProposed event.
+///
+/// The change is only validated against the configuration when it is executed.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the propose_config_change instruction, containing:
+///   - `integrator_program_id`: The public key of the integrator program
+///   - `change`: The proposed change
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is a pending admin transfer (EndpointError::AdminTransferInProgress)
+/// * Neither the configurator nor the admin is the signer, or the configurator proposes a
+///   `SetConfigDelay` change (EndpointError::CallerNotAuthorized)
+/// * The change applies to chain ID 0 (EndpointError::InvalidChainId)
+///
+/// # Events
+///
+/// Emits a `ConfigChangeProposed` event
+#[access_control(ProposeConfigChange::validate(&ctx.accounts, &args, ctx.remaining_accounts))]
+pub fn propose_config_change(
+    ctx: Context<ProposeConfigChange>,
+    args: ProposeConfigChangeArgs,
+) -> Result<()> {
+    let integrator_config = &mut ctx.accounts.integrator_config;
+    let id = integrator_config.next_config_change_id;
+    let eta = Clock::get()?
+        .slot
+        .saturating_add(integrator_config.config_delay);
+    integrator_config.next_config_change_id += 1;
+
+    ctx.accounts
+        .pending_config_change
+        .set_inner(PendingConfigChange {
+            bump: ctx.bumps.pending_config_change,
+            integrator_program_id: args.integrator_program_id,
+            id,
+            change: args.change.clone(),
+            eta,
+            payer: ctx.accounts.payer.key(),
+        });
+
+    emit_cpi!(ConfigChangeProposed {
+        integrator: args.integrator_program_id,
+        id,
+        change: args.change,
+        eta,
+    });
+
+    Ok(())
+}
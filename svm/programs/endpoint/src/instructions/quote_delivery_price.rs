@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::EndpointError,
+    state::{IntegratorChainConfig, IntegratorConfig},
+    utils::{adapter_instructions, adapter_interface},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct QuoteDeliveryPriceArgs {
+    pub integrator_program_id: Pubkey,
+    pub dst_chain: u16,
+    pub adapter_instructions: Vec<u8>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: QuoteDeliveryPriceArgs)]
+pub struct QuoteDeliveryPrice<'info> {
+    /// The integrator config account
+    /// This is required to map the send adapter bitmap to adapter program ids
+    #[account(
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The integrator chain config account
+    /// This is required to read the send_adapter_bitmap from
+    #[account(
+        seeds = [
+            IntegratorChainConfig::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.dst_chain.to_be_bytes().as_ref()
+        ],
+        bump = integrator_chain_config.bump,
+    )]
+    pub integrator_chain_config: Account<'info, IntegratorChainConfig>,
+}
+
+/// Quotes the total delivery price of a message to the destination chain
+///
+/// This function performs the following steps:
+/// 1. Parses the adapter instructions against the registered adapters.
+/// 2. Invokes `quote_delivery_price` on every send-enabled adapter for the destination chain.
+/// 3. Sums up the individual delivery prices.
+///
+/// The program accounts of the send-enabled adapters must be passed as remaining accounts,
+/// ordered by adapter index.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved.
+/// * `args` - The arguments for the instruction, including:
+///   * `integrator_program_id`: The program ID of the integrator.
+///   * `dst_chain`: The destination chain ID.
+///   * `adapter_instructions`: The encoded adapter instructions.
+///
+/// # Returns
+///
+/// Returns the total delivery price in lamports, also available to callers as return data.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The adapter instructions are invalid (EndpointError::InvalidAdapterInstructions,
+///   EndpointError::InvalidInstructionIndex).
+/// * A remaining account is missing or is not the expected adapter program
+///   (EndpointError::InvalidAdapterProgram).
+/// * An adapter does not return a valid quote (EndpointError::InvalidDeliveryPriceQuote).
+/// * The total overflows (EndpointError::DeliveryPriceOverflow).
+pub fn quote_delivery_price<'info>(
+    ctx: Context<'_, '_, 'info, 'info, QuoteDeliveryPrice<'info>>,
+    args: QuoteDeliveryPriceArgs,
+) -> Result<u64> {
+    let integrator_config = &ctx.accounts.integrator_config;
+
+    let adapter_instructions = adapter_instructions::parse_instructions(
+        &args.adapter_instructions,
+        integrator_config.adapter_infos.len(),
+    )?;
+
+    let quotes = adapter_interface::quote_send_adapters(
+        integrator_config,
+        &ctx.accounts.integrator_chain_config,
+        ctx.remaining_accounts,
+        &adapter_instructions,
+    )?;
+
+    quotes.iter().try_fold(0u64, |total, (_, price)| {
+        total
+            .checked_add(*price)
+            .ok_or(EndpointError::DeliveryPriceOverflow.into())
+    })
+}
@@ -0,0 +1,90 @@
+use crate::{
+    error::EndpointError,
+    event::MessageExpired,
+    state::{IntegratorConfig, OutboxMessage},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReclaimOutboxMessage<'info> {
+    /// The IntegratorConfig account of the sending integrator
+    /// This account holds the outbox message timeout
+    #[account(
+        seeds = [
+            IntegratorConfig::SEED_PREFIX,
+            outbox_message.src_addr.to_pubkey().as_ref(),
+        ],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The expired outbox message account
+    /// This account is closed and its lamports are returned to the refund recipient
+    #[account(
+        mut,
+        seeds = [
+            OutboxMessage::SEED_PREFIX,
+            outbox_message.src_addr.to_pubkey().as_ref(),
+            outbox_message.sequence.to_be_bytes().as_ref(),
+        ],
+        bump = outbox_message.bump,
+        has_one = refund_recipient,
+        close = refund_recipient,
+    )]
+    pub outbox_message: Account<'info, OutboxMessage>,
+
+    /// The account that will receive the rent and unclaimed fees of the outbox message account
+    #[account(mut)]
+    /// CHECK: This is an account for receiving the rent refund, checked against the outbox message
+    pub refund_recipient: AccountInfo<'info>,
+}
+
+impl<'info> ReclaimOutboxMessage<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.outbox_message.is_expired(
+                self.integrator_config.outbox_message_timeout,
+                Clock::get()?.slot
+            ),
+            EndpointError::OutboxMessageNotExpired
+        );
+        Ok(())
+    }
+}
+
+/// Reclaims an outbox message that has not been picked up by every send adapter in time.
+///
+/// This instruction is permissionless. It performs the following steps:
+/// 1. Checks that the outbox message timeout of the integrator has elapsed since the message was sent.
+/// 2. Emits a MessageExpired event with the adapters that never picked up the message.
+/// 3. Closes the outbox message account, returning the rent and any unclaimed fees to the
+///    refund recipient.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The integrator has no outbox message timeout or it has not elapsed yet (EndpointError::OutboxMessageNotExpired)
+///
+/// # Events
+///
+/// Emits a `MessageExpired` event
+#[access_control(ReclaimOutboxMessage::validate(&ctx.accounts))]
+pub fn reclaim_outbox_message(ctx: Context<ReclaimOutboxMessage>) -> Result<()> {
+    let outbox_message = &ctx.accounts.outbox_message;
+
+    emit_cpi!(MessageExpired {
+        src_addr: outbox_message.src_addr,
+        sequence: outbox_message.sequence,
+        dst_chain: outbox_message.dst_chain,
+        dst_addr: outbox_message.dst_addr,
+        payload_hash: outbox_message.payload_hash,
+        outstanding_adapters: outbox_message.outstanding_adapters.as_value(),
+    });
+
+    Ok(())
+}
@@ -3,8 +3,11 @@ use universal_address::UniversalAddress;
 
 use crate::{
     error::EndpointError,
-    event::MessageReceived,
-    state::{AttestationInfo, IntegratorChainConfig},
+    event::{MessageQueued, MessageReceived},
+    state::{
+        AttestationInfo, EndpointConfig, IntegratorChainConfig, IntegratorConfig, QueueStatus,
+        ReplayBitmap,
+    },
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -16,6 +19,20 @@ pub struct RecvMessageArgs {
     pub dst_chain: u16,
     pub integrator_program_id: Pubkey,
     pub payload_hash: [u8; 32],
+    /// Whether a message exceeding the inbound rate limit is queued and `RecvStatus::Queued`
+    /// returned, instead of failing with `RateLimitExceeded`
+    /// Only integrators that check the returned status should opt in
+    pub queue_if_rate_limited: bool,
+}
+
+/// The outcome of `recv_message`
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum RecvStatus {
+    /// The message has been received and marked as executed
+    Received,
+
+    /// The message exceeded the inbound rate limit and has been queued until `release_slot`
+    Queued { release_slot: u64 },
 }
 
 #[event_cpi]
@@ -34,15 +51,29 @@ pub struct RecvMessage<'info> {
     )]
     pub integrator_program_pda: Signer<'info>,
 
+    /// The integrator config account
+    /// This is used to check that receiving is not paused for the integrator
+    #[account(
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+        constraint = !integrator_config.paused_recv @ EndpointError::RecvPaused,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
     /// The integrator chain config account
-    /// This is required to read the enabled_bitmap from
+    /// This is required to read the enabled_bitmap and the receive threshold from, to check
+    /// that receiving from the source chain is not paused and that the sender is a peer, and to
+    /// consume its inbound rate limit
     #[account(
+        mut,
         seeds = [
             IntegratorChainConfig::SEED_PREFIX,
             args.integrator_program_id.as_ref(),
             args.src_chain.to_be_bytes().as_ref()
         ],
         bump = integrator_chain_config.bump,
+        constraint = !integrator_chain_config.paused_recv @ EndpointError::RecvPaused,
+        constraint = integrator_chain_config.is_peer(&args.src_addr) @ EndpointError::UnknownPeer,
     )]
     pub integrator_chain_config: Account<'info, IntegratorChainConfig>,
 
@@ -65,15 +96,52 @@ pub struct RecvMessage<'info> {
     )]
     pub attestation_info: Account<'info, AttestationInfo>,
 
+    /// The replay bitmap account tracking the sequence of the message
+    /// This account is initialized if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ReplayBitmap::INIT_SPACE,
+        seeds = [
+            ReplayBitmap::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.src_chain.to_be_bytes().as_ref(),
+            args.src_addr.to_bytes().as_ref(),
+            ReplayBitmap::index_of(args.sequence).to_be_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub replay_bitmap: Account<'info, ReplayBitmap>,
+
+    /// The endpoint config account
+    /// This is used to check that the endpoint is not paused
+    #[account(
+        seeds = [EndpointConfig::SEED_PREFIX],
+        bump = endpoint_config.bump,
+        constraint = !endpoint_config.paused @ EndpointError::EndpointPaused,
+    )]
+    pub endpoint_config: Account<'info, EndpointConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
 /// Receives a message that has been attested to in the endpoint program
 ///
 /// This function performs the following steps:
-/// 1. Checks if the message has already been executed.
-/// 2. Marks the message as executed.
-/// 3. Emits a MessageReceived event.
+/// 1. Checks that the message is destined to this chain.
+/// 2. Initializes the replay bitmap if it's newly created.
+/// 3. Checks if the message has already been executed.
+/// 4. Clears the attestations of the adapters whose slot was freed or migrated since they attested,
+///    and checks that the number of enabled receive adapters that attested meets the threshold.
+/// 5. Records the slot at which the threshold was reached if it was not recorded yet, and checks
+///    that the chain's minimum attestation age has elapsed since.
+/// 6. Checks that the message is not queued, and consumes one message from the inbound rate limit
+///    unless the message has been released.
+/// 7. If the inbound rate limit is exhausted, fails unless the integrator opted in to queueing,
+///    in which case it queues the message for the chain's queue delay and emits a MessageQueued
+///    event.
+/// 8. Otherwise, marks the message as executed in the attestation info and the replay bitmap and
+///    emits a MessageReceived event.
 ///
 /// # Arguments
 ///
@@ -86,19 +154,39 @@ pub struct RecvMessage<'info> {
 ///   * `dst_chain`: The destination chain ID.
 ///   * `integrator_program_id`: The public key of the integrator program.
 ///   * `payload_hash`: The hash of the message payload.
+///   * `queue_if_rate_limited`: Whether to queue the message if it exceeds the inbound rate limit.
 ///
 /// # Returns
 ///
-/// Returns `Ok(())` if the message is successfully received, or an error if it fails
+/// Returns `RecvStatus::Received` if the message is received. Only if `queue_if_rate_limited` is
+/// set, returns `RecvStatus::Queued` if the message has been queued by the inbound rate limit, in
+/// which case the integrator must not process it yet. The status is also available to callers as
+/// return data.
+///
+/// Queueing is opt-in so that an integrator which ignores the returned status never processes a
+/// message that was not received. Without it, a message exceeding the inbound rate limit fails
+/// with `RateLimitExceeded` and can be received once the rate limit has refilled.
 ///
 /// # Errors
 ///
 /// This function will return an error if:
-/// * The message has already been executed (EndpointError::AlreadyExecuted)
+/// * The endpoint is paused (EndpointError::EndpointPaused)
+/// * Receiving is paused for the integrator or the source chain (EndpointError::RecvPaused)
+/// * The source address is not a peer of the source chain (EndpointError::UnknownPeer)
+/// * Either chain ID is 0 (EndpointError::InvalidChainId)
+/// * The destination chain is not this chain (EndpointError::InvalidDestinationChain)
+/// * The message has already been executed, including when its attestation info was closed and
+///   the message attested to again (EndpointError::AlreadyExecuted)
+/// * Fewer enabled receive adapters attested than the chain's threshold (EndpointError::ThresholdNotMet)
+/// * The chain's minimum attestation age has not elapsed since the threshold was reached
+///   (EndpointError::AttestationTooRecent)
+/// * The message is queued and has not been released (EndpointError::MessageQueued)
+/// * The inbound rate limit is exhausted and `queue_if_rate_limited` is not set
+///   (EndpointError::RateLimitExceeded)
 ///
 /// # Events
 ///
-/// Emits a `MessageReceived` event
+/// Emits a `MessageReceived` event, or a `MessageQueued` event if the message is queued
 ///
 /// # Notes
 ///
@@ -106,17 +194,91 @@ pub struct RecvMessage<'info> {
 /// it means the `AttestationInfo` is already initialized. In other words,
 /// either `attest_message` or `exec_message` has been invoked previously.
 /// In the case of `exec_message`, `AlreadyExecuted` will be thrown.
-pub fn recv_message(ctx: Context<RecvMessage>, _args: RecvMessageArgs) -> Result<()> {
+///
+/// A slot recorded or reset here is reverted along with the failing instruction, so a message
+/// whose threshold is met by a change of the chain's adapters or threshold rather than by an
+/// attestation needs `update_threshold_reached_slot` to start its minimum attestation age.
+///
+/// While the chain has a minimum attestation age, a receive threshold of 0 still requires one
+/// attestation from an enabled receive adapter, so that disabling the attesting adapter before
+/// the age has elapsed stops the message.
+pub fn recv_message(ctx: Context<RecvMessage>, args: RecvMessageArgs) -> Result<RecvStatus> {
+    // Validate that the destination chain is this program's chain
+    AttestationInfo::validate_chains(args.src_chain, args.dst_chain)?;
+
     let attestation_info = &mut ctx.accounts.attestation_info;
 
     // Check if the message has already been executed
     require!(!attestation_info.executed, EndpointError::AlreadyExecuted);
 
-    // There is no need to check for the src_chain and dst_chain validity since they
-    // are check during the init of attestation_info in either `exec_message` or `attest_message`
+    let replay_bitmap = &mut ctx.accounts.replay_bitmap;
+
+    // If the replay_bitmap is newly created, initialize it
+    // It is fine to check using src_chain == 0 as chain 0 is not a valid source chain
+    if replay_bitmap.src_chain == 0 {
+        replay_bitmap.set_inner(ReplayBitmap::new(
+            ctx.bumps.replay_bitmap,
+            args.integrator_program_id,
+            args.src_chain,
+            args.src_addr,
+            args.sequence,
+        ));
+    }
+
+    // The replay bitmap keeps the executed flag once the attestation info has been closed
+    require!(
+        !replay_bitmap.is_executed(args.sequence),
+        EndpointError::AlreadyExecuted
+    );
+
+    // Attestations of removed or replaced adapters do not count towards the threshold, even once
+    // their slot is reused
+    attestation_info.clear_stale_attestations(&ctx.accounts.integrator_config)?;
+
+    // Only attestations from currently enabled receive adapters count towards the threshold
+    // The threshold may have been met or lost since the last attestation through a change of the
+    // chain's adapters or threshold, so the slot at which it was reached is updated first
+    let integrator_chain_config = &mut ctx.accounts.integrator_chain_config;
+    let slot = Clock::get()?.slot;
+    require!(
+        attestation_info.update_threshold_reached_slot(integrator_chain_config, slot),
+        EndpointError::ThresholdNotMet
+    );
+
+    // Leave time to disable a compromised adapter before its attestations are consumed
+    attestation_info.check_attestation_age(integrator_chain_config.min_attestation_age, slot)?;
+
+    // A queued message must be released before it can be received, and a released message has
+    // already been counted against the rate limit
+    match attestation_info.queue_status {
+        QueueStatus::Queued { .. } => return err!(EndpointError::MessageQueued),
+        QueueStatus::Released => {}
+        QueueStatus::NotQueued => {
+            if !integrator_chain_config.inbound_rate_limit.try_consume(slot) {
+                require!(args.queue_if_rate_limited, EndpointError::RateLimitExceeded);
+
+                let release_slot = slot.saturating_add(integrator_chain_config.inbound_queue_delay);
+                attestation_info.queue_status = QueueStatus::Queued { release_slot };
+
+                emit_cpi!(MessageQueued {
+                    message_hash: attestation_info.message_hash,
+                    src_chain: attestation_info.src_chain,
+                    src_addr: attestation_info.src_addr,
+                    sequence: attestation_info.sequence,
+                    dst_chain: attestation_info.dst_chain,
+                    dst_addr: attestation_info.dst_addr,
+                    payload_hash: attestation_info.payload_hash,
+                    release_slot,
+                });
+
+                return Ok(RecvStatus::Queued { release_slot });
+            }
+        }
+    }
 
     // Mark the message as executed
     attestation_info.executed = true;
+    replay_bitmap.set_executed(args.sequence);
 
     emit_cpi!(MessageReceived {
         message_hash: attestation_info.message_hash,
@@ -126,14 +288,9 @@ pub fn recv_message(ctx: Context<RecvMessage>, _args: RecvMessageArgs) -> Result
         dst_chain: attestation_info.dst_chain,
         dst_addr: attestation_info.dst_addr,
         payload_hash: attestation_info.payload_hash,
-        enabled_bitmap: ctx
-            .accounts
-            .integrator_chain_config
-            .recv_adapter_bitmap
-            .as_value(),
+        enabled_bitmap: integrator_chain_config.recv_adapter_bitmap.as_value(),
         attested_bitmap: attestation_info.attested_adapters.as_value(),
     });
 
-    // Return the enabled receive Adapters for that chain along with the attestations
-    Ok(())
+    Ok(RecvStatus::Received)
 }
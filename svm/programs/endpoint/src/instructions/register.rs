@@ -97,9 +97,21 @@ pub fn register(ctx: Context<Register>, args: RegisterArgs) -> Result<()> {
     ctx.accounts.integrator_config.set_inner(IntegratorConfig {
         bump: ctx.bumps.integrator_config,
         admin: Some(args.admin),
+        admin_signers: Vec::new(),
+        admin_threshold: 0,
         pending_admin: None,
+        configurator: None,
+        pauser: None,
+        outbox_message_timeout: 0,
+        paused_send: false,
+        paused_recv: false,
+        enforce_peers: false,
+        config_delay: 0,
+        next_config_change_id: 0,
         integrator_program_id: args.integrator_program_id,
         adapter_infos: Vec::new(),
+        adapter_epoch: 0,
+        adapter_epochs: Vec::new(),
     });
 
     // Initialize the SequenceTracker account with default values
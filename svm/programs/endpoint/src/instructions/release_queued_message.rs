@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::EndpointError,
+    event::MessageReleased,
+    state::{AttestationInfo, QueueStatus},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReleaseQueuedMessage<'info> {
+    /// The attestation info account of a message queued by the inbound rate limit
+    #[account(
+        mut,
+        seeds = [AttestationInfo::SEED_PREFIX, &attestation_info.message_hash],
+        bump = attestation_info.bump,
+    )]
+    pub attestation_info: Account<'info, AttestationInfo>,
+}
+
+impl<'info> ReleaseQueuedMessage<'info> {
+    pub fn validate(&self) -> Result<()> {
+        match self.attestation_info.queue_status {
+            QueueStatus::Queued { release_slot } => {
+                require!(
+                    Clock::get()?.slot >= release_slot,
+                    EndpointError::QueueDelayNotElapsed
+                );
+                Ok(())
+            }
+            _ => err!(EndpointError::MessageNotQueued),
+        }
+    }
+}
+
+/// Releases a message queued by the inbound rate limit of its source chain
+///
+/// This function performs the following steps:
+/// 1. Checks that the message is queued and that its queue delay has elapsed.
+/// 2. Marks the message as released in the attestation info.
+/// 3. Emits a MessageReleased event.
+///
+/// This can be called by anyone. The integrator then receives the message with `recv_message`,
+/// which no longer applies the inbound rate limit to it but still checks the threshold, the
+/// pause flags and the peers.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The message is not queued (EndpointError::MessageNotQueued)
+/// * The queue delay has not elapsed (EndpointError::QueueDelayNotElapsed)
+///
+/// # Events
+///
+/// Emits a `MessageReleased` event
+#[access_control(ReleaseQueuedMessage::validate(&ctx.accounts))]
+pub fn release_queued_message(ctx: Context<ReleaseQueuedMessage>) -> Result<()> {
+    let attestation_info = &mut ctx.accounts.attestation_info;
+    attestation_info.queue_status = QueueStatus::Released;
+
+    emit_cpi!(MessageReleased {
+        message_hash: attestation_info.message_hash,
+        src_chain: attestation_info.src_chain,
+        src_addr: attestation_info.src_addr,
+        sequence: attestation_info.sequence,
+        dst_addr: attestation_info.dst_addr,
+    });
+
+    Ok(())
+}
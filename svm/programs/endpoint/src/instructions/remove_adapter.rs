@@ -0,0 +1,117 @@
+use crate::{
+    error::EndpointError,
+    event::AdapterRemoved,
+    state::{AdapterInfo, IntegratorConfig, Role},
+};
+use anchor_lang::prelude::*;
+
+/// Arguments for the remove_adapter instruction
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RemoveAdapterArgs {
+    /// The Pubkey of the integrator program
+    pub integrator_program_id: Pubkey,
+
+    /// The Pubkey of the adapter to be removed
+    pub adapter_program_id: Pubkey,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: RemoveAdapterArgs)]
+pub struct RemoveAdapter<'info> {
+    /// The admin registered on IntegratorConfig
+    /// Or the holder of the configurator role
+    pub admin: Signer<'info>,
+
+    /// The integrator config account
+    /// The slot of the removed adapter in the `adapter_infos` field of this account is freed
+    #[account(
+        mut,
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The registered adapter account
+    /// This account is closed and its rent refunded to the rent recipient
+    #[account(
+        mut,
+        seeds = [
+            AdapterInfo::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.adapter_program_id.as_ref(),
+        ],
+        bump = adapter_info.bump,
+        close = rent_recipient,
+    )]
+    pub adapter_info: Account<'info, AdapterInfo>,
+
+    /// The account receiving the rent of the adapter info account
+    /// CHECK: This is chosen by the admin
+    #[account(mut)]
+    pub rent_recipient: UncheckedAccount<'info>,
+}
+
+impl<'info> RemoveAdapter<'info> {
+    pub fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        self.integrator_config.check_roles(
+            &[Role::Configurator],
+            &self.admin,
+            remaining_accounts,
+        )?;
+
+        require!(
+            self.adapter_info.enabled_count == 0,
+            EndpointError::AdapterStillEnabled
+        );
+
+        Ok(())
+    }
+}
+
+/// Removes a registered adapter from an integrator.
+///
+/// This function performs the following steps:
+/// 1. Validates that the configurator is the signer, or that the admin is the signer and that
+///    there's no pending admin transfer.
+/// 2. Checks that the adapter is disabled on every chain.
+/// 3. Frees the slot of the adapter in IntegratorConfig so that `add_adapter` can reuse it, and
+///    ends the adapter epoch of the slot.
+/// 4. Emits an AdapterRemoved event.
+/// 5. Closes the AdapterInfo account, refunding its rent to the rent recipient.
+///
+/// The slot is marked as reassigned at a new adapter epoch. The attestations the adapter recorded
+/// for messages that have not been received yet stop counting towards the threshold, and the
+/// adapter that reuses the slot can neither inherit them nor pick up the messages sent before the
+/// removal. The fees escrowed for the adapter are returned to the refund recipient of the messages.
+///
+/// # Arguments
+///
+/// * `ctx` - The context for the instruction, containing the accounts.
+/// * `args` - The arguments for removing an adapter, including:
+///     * `integrator_program_id`: The Pubkey of the integrator program.
+///     * `adapter_program_id`: The Pubkey of the adapter to be removed.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is a pending admin transfer (EndpointError::AdminTransferInProgress)
+/// * Neither the configurator nor the admin is the signer (EndpointError::CallerNotAuthorized)
+/// * The adapter is still enabled on a chain (EndpointError::AdapterStillEnabled)
+///
+/// # Events
+///
+/// Emits an `AdapterRemoved` event
+#[access_control(RemoveAdapter::validate(&ctx.accounts, ctx.remaining_accounts))]
+pub fn remove_adapter(ctx: Context<RemoveAdapter>, args: RemoveAdapterArgs) -> Result<()> {
+    let index = ctx.accounts.adapter_info.index;
+    ctx.accounts.integrator_config.remove_adapter(index);
+
+    emit_cpi!(AdapterRemoved {
+        integrator: args.integrator_program_id,
+        adapter: args.adapter_program_id,
+        index,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+use crate::{
+    event::AdapterReplaced,
+    state::{AdapterInfo, IntegratorConfig, Role},
+};
+use anchor_lang::prelude::*;
+
+/// Arguments for the replace_adapter instruction
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ReplaceAdapterArgs {
+    /// The Pubkey of the integrator program
+    pub integrator_program_id: Pubkey,
+
+    /// The Pubkey of the adapter to be replaced
+    pub adapter_program_id: Pubkey,
+
+    /// The Pubkey of the adapter taking over the slot
+    pub new_adapter_program_id: Pubkey,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: ReplaceAdapterArgs)]
+pub struct ReplaceAdapter<'info> {
+    /// Payer pays for the init of `new_adapter_info` and receives the rent of `adapter_info`
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The admin registered on IntegratorConfig
+    /// Or the holder of the configurator role
+    pub admin: Signer<'info>,
+
+    /// The integrator config account
+    /// The slot of the replaced adapter in the `adapter_infos` field of this account is migrated
+    /// to the new adapter
+    #[account(
+        mut,
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The registered adapter account of the replaced adapter
+    /// This account is closed and its rent refunded to the payer
+    #[account(
+        mut,
+        seeds = [
+            AdapterInfo::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.adapter_program_id.as_ref(),
+        ],
+        bump = adapter_info.bump,
+        close = payer,
+    )]
+    pub adapter_info: Account<'info, AdapterInfo>,
+
+    /// The account to store information about the new adapter
+    /// The `init` constraint checks that the new adapter has not been added. If it is,
+    /// `AccountAlreadyInUse` error will be thrown
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AdapterInfo::INIT_SPACE,
+        seeds = [
+            AdapterInfo::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.new_adapter_program_id.as_ref(),
+        ],
+        bump
+    )]
+    pub new_adapter_info: Account<'info, AdapterInfo>,
+
+    /// The system program
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReplaceAdapter<'info> {
+    pub fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        self.integrator_config.check_roles(
+            &[Role::Configurator],
+            &self.admin,
+            remaining_accounts,
+        )?;
+
+        // The adapter can only be replaced through `propose_config_change` while the config delay
+        // is set
+        self.integrator_config.check_no_config_delay()
+    }
+}
+
+/// Replaces a registered adapter of an integrator with a new adapter program.
+///
+/// This function performs the following steps:
+/// 1. Validates that the configurator is the signer, or that the admin is the signer and that
+///    there's no pending admin transfer.
+/// 2. Migrates the slot of the replaced adapter in IntegratorConfig to the new adapter, ending the
+///    adapter epoch of the slot.
+/// 3. Initializes the AdapterInfo account of the new adapter with the index and enabled chain
+///    count of the replaced adapter.
+/// 4. Emits an AdapterReplaced event.
+/// 5. Closes the AdapterInfo account of the replaced adapter, refunding its rent to the payer.
+///
+/// The chain bitmaps are left untouched, so the new adapter is enabled on the same chains as the
+/// replaced adapter. The slot is marked as reassigned at a new adapter epoch, so the attestations
+/// recorded by the replaced adapter stop counting towards the threshold, and the new adapter can
+/// attest to these messages itself. The new adapter cannot pick up the messages the replaced
+/// adapter had not picked up yet, whose fees are returned to their refund recipient.
+///
+/// # Arguments
+///
+/// * `ctx` - The context for the instruction, containing the accounts.
+/// * `args` - The arguments for replacing an adapter, including:
+///     * `integrator_program_id`: The Pubkey of the integrator program.
+///     * `adapter_program_id`: The Pubkey of the adapter to be replaced.
+///     * `new_adapter_program_id`: The Pubkey of the adapter taking over the slot.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is a pending admin transfer (EndpointError::AdminTransferInProgress)
+/// * Neither the configurator nor the admin is the signer (EndpointError::CallerNotAuthorized)
+/// * The config delay is set (EndpointError::ConfigChangeTimelocked). The replacement must then
+///   be proposed as a `ReplaceAdapter` config change
+/// * The new adapter is the default pubkey (EndpointError::InvalidAdapterProgram)
+///
+/// # Events
+///
+/// Emits an `AdapterReplaced` event
+#[access_control(ReplaceAdapter::validate(&ctx.accounts, ctx.remaining_accounts))]
+pub fn replace_adapter(ctx: Context<ReplaceAdapter>, args: ReplaceAdapterArgs) -> Result<()> {
+    let adapter_info = &ctx.accounts.adapter_info;
+    let index = adapter_info.index;
+    let enabled_count = adapter_info.enabled_count;
+
+    ctx.accounts
+        .integrator_config
+        .replace_adapter(index, args.new_adapter_program_id)?;
+
+    ctx.accounts.new_adapter_info.set_inner(AdapterInfo {
+        bump: ctx.bumps.new_adapter_info,
+        index,
+        integrator_program_id: args.integrator_program_id,
+        adapter_program_id: args.new_adapter_program_id,
+        enabled_count,
+    });
+
+    emit_cpi!(AdapterReplaced {
+        integrator: args.integrator_program_id,
+        old_adapter: args.adapter_program_id,
+        new_adapter: args.new_adapter_program_id,
+        index,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use crate::error::EndpointError;
+use crate::event::RoleRevoked;
+use crate::state::{IntegratorConfig, Role};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RevokeRoleArgs {
+    /// The integrator_program for the integrator_config
+    pub integrator_program_id: Pubkey,
+
+    /// The role to revoke
+    pub role: Role,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: RevokeRoleArgs)]
+pub struct RevokeRole<'info> {
+    /// The current admin of the IntegratorConfig account
+    pub admin: Signer<'info>,
+
+    /// The IntegratorConfig account being updated
+    #[account(
+        mut,
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+}
+
+impl<'info> RevokeRole<'info> {
+    pub fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        self.integrator_config
+            .check_admin(&self.admin, remaining_accounts)
+    }
+}
+
+/// Revokes a role of an integrator from its holder
+///
+/// This function performs the following steps:
+/// 1. Validates that the admin is the signer and that there's no pending admin transfer.
+/// 2. Checks that the role is granted.
+/// 3. Clears the holder of the role in the IntegratorConfig account.
+/// 4. Emits a RoleRevoked event.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the revoke_role instruction, containing:
+///   - `integrator_program_id`: The public key of the integrator program
+///   - `role`: The role to revoke
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is a pending admin transfer (EndpointError::AdminTransferInProgress)
+/// * The admin is not the signer (EndpointError::CallerNotAuthorized)
+/// * The role is not granted (EndpointError::RoleNotGranted)
+///
+/// # Events
+///
+/// Emits a `RoleRevoked` event
+#[access_control(RevokeRole::validate(&ctx.accounts, ctx.remaining_accounts))]
+pub fn revoke_role(ctx: Context<RevokeRole>, args: RevokeRoleArgs) -> Result<()> {
+    let integrator_config = &mut ctx.accounts.integrator_config;
+    let account = integrator_config
+        .role(args.role)
+        .ok_or(EndpointError::RoleNotGranted)?;
+
+    integrator_config.set_role(args.role, None);
+
+    emit_cpi!(RoleRevoked {
+        integrator: args.integrator_program_id,
+        role: args.role,
+        account,
+    });
+
+    Ok(())
+}
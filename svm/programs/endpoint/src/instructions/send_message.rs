@@ -1,10 +1,15 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use universal_address::UniversalAddress;
 
 use crate::{
     error::EndpointError,
     event::MessageSent,
-    state::{IntegratorChainConfig, OutboxMessage, SequenceTracker},
+    state::{
+        AdapterFee, EndpointConfig, IntegratorChainConfig, IntegratorConfig, OutboxMessage,
+        SequenceTracker,
+    },
+    utils::{adapter_instructions, adapter_interface},
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -14,13 +19,16 @@ pub struct SendMessageArgs {
     pub dst_chain: u16,
     pub dst_addr: UniversalAddress,
     pub payload_hash: [u8; 32],
+    /// Encoded adapter instructions, see `utils::adapter_instructions`
+    /// May be empty if none of the adapters need instructions
+    pub adapter_instructions: Vec<u8>,
 }
 
 #[event_cpi]
 #[derive(Accounts)]
 #[instruction(args: SendMessageArgs)]
 pub struct SendMessage<'info> {
-    // Payer pays for the init of `outbox_message`
+    // Payer pays for the init of `outbox_message` and the delivery fees of the send adapters
     #[account(mut)]
     pub payer: Signer<'info>,
 
@@ -33,13 +41,33 @@ pub struct SendMessage<'info> {
     )]
     pub integrator_program_pda: Signer<'info>,
 
+    /// The integrator config account
+    /// This is required to check the adapter instruction indices against the registered adapters
+    /// and that sending is not paused for the integrator
     #[account(
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+        constraint = !integrator_config.paused_send @ EndpointError::SendPaused,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The integrator chain config account
+    /// This is required to read the enabled send adapters from, to check that sending to the
+    /// destination chain is not paused and that the destination is a peer if peers are enforced,
+    /// and to consume its outbound rate limit
+    #[account(
+        mut,
         seeds = [
             IntegratorChainConfig::SEED_PREFIX,
             args.integrator_program_id.as_ref(),
             args.dst_chain.to_be_bytes().as_ref()
         ],
         bump = integrator_chain_config.bump,
+        constraint = !integrator_chain_config.paused_send @ EndpointError::SendPaused,
+        constraint = integrator_chain_config.is_destination(
+            &args.dst_addr,
+            integrator_config.enforce_peers
+        ) @ EndpointError::UnknownDestination,
     )]
     pub integrator_chain_config: Account<'info, IntegratorChainConfig>,
 
@@ -53,13 +81,34 @@ pub struct SendMessage<'info> {
     )]
     pub sequence_tracker: Account<'info, SequenceTracker>,
 
+    /// The outbox message account
+    /// This is derived from the integrator program ID and the current sequence so adapters can
+    /// locate pending messages from sequence numbers alone
     #[account(
         init,
         payer = payer,
-        space = 8 + OutboxMessage::INIT_SPACE,
+        seeds = [
+            OutboxMessage::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            sequence_tracker.sequence.to_be_bytes().as_ref(),
+        ],
+        bump,
+        space = OutboxMessage::space(
+            &args.adapter_instructions,
+            integrator_chain_config.send_adapter_bitmap.len()
+        ),
     )]
     pub outbox_message: Account<'info, OutboxMessage>,
 
+    /// The endpoint config account
+    /// This is used to check that the endpoint is not paused
+    #[account(
+        seeds = [EndpointConfig::SEED_PREFIX],
+        bump = endpoint_config.bump,
+        constraint = !endpoint_config.paused @ EndpointError::EndpointPaused,
+    )]
+    pub endpoint_config: Account<'info, EndpointConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -67,9 +116,15 @@ pub struct SendMessage<'info> {
 ///
 /// This function performs the following steps:
 /// 1. Checks if there are any enabled send adapters for the destination chain.
-/// 2. Initializes a new `OutboxMessage` account with the provided information.
-/// 3. Increments the sequence number in the `SequenceTracker` account.
-/// 4. Emits a `MessageSent` event with details about the sent message.
+/// 2. Consumes one message from the outbound rate limit of the destination chain.
+/// 3. Parses the adapter instructions against the registered adapters.
+/// 4. Quotes the delivery price of every send adapter and escrows the fees in the outbox message.
+/// 5. Initializes a new `OutboxMessage` account with the provided information.
+/// 6. Increments the sequence number in the `SequenceTracker` account.
+/// 7. Emits a `MessageSent` event with details about the sent message.
+///
+/// The program accounts of the send-enabled adapters must be passed as remaining accounts,
+/// ordered by adapter index.
 ///
 /// # Arguments
 ///
@@ -80,21 +135,36 @@ pub struct SendMessage<'info> {
 ///   * `dst_chain`: The destination chain ID.
 ///   * `dst_addr`: The destination address as a UniversalAddress.
 ///   * `payload_hash`: The hash of the message payload.
+///   * `adapter_instructions`: The encoded adapter instructions.
 ///
 /// # Errors
 ///
 /// This function will return an error if:
+/// * The endpoint is paused (EndpointError::EndpointPaused)
+/// * Sending is paused for the integrator or the destination chain (EndpointError::SendPaused)
+/// * Peers are enforced and the destination address is not a peer of the destination chain
+///   (EndpointError::UnknownDestination)
 /// * There are no enabled send adapters for the destination chain (EndpointError::AdapterNotEnabled).
+/// * The outbound rate limit of the destination chain is exhausted (EndpointError::RateLimitExceeded).
+/// * The adapter instructions are malformed (EndpointError::InvalidAdapterInstructions).
+/// * An adapter instruction index is not a registered adapter (EndpointError::InvalidInstructionIndex).
+/// * A send adapter program is missing or invalid (EndpointError::InvalidAdapterProgram).
+/// * A send adapter does not return a valid quote (EndpointError::InvalidDeliveryPriceQuote).
 ///
 /// # Side Effects
 ///
 /// * Initializes a new `OutboxMessage` account.
+/// * Transfers the delivery fees from the payer to the `OutboxMessage` account.
 /// * Increments the sequence number in the `SequenceTracker` account.
+/// * Consumes one message from the outbound rate limit in the `IntegratorChainConfig` account.
 ///
 /// # Events
 ///
 /// Emits a `MessageSent` event
-pub fn send_message(ctx: Context<SendMessage>, args: SendMessageArgs) -> Result<()> {
+pub fn send_message<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SendMessage<'info>>,
+    args: SendMessageArgs,
+) -> Result<()> {
     // Check if there are any enabled send adapters for destination chain
     require!(
         !ctx.accounts
@@ -104,20 +174,72 @@ pub fn send_message(ctx: Context<SendMessage>, args: SendMessageArgs) -> Result<
         EndpointError::AdapterNotEnabled
     );
 
+    let slot = Clock::get()?.slot;
+    ctx.accounts
+        .integrator_chain_config
+        .outbound_rate_limit
+        .consume(slot)?;
+
+    // Parse the adapter instructions so we can pass the appropriate one to each adapter
+    let adapter_instructions = adapter_instructions::parse_instructions(
+        &args.adapter_instructions,
+        ctx.accounts.integrator_config.adapter_infos.len(),
+    )?;
+
+    // Quote every send adapter and escrow the fees until they pick up the message
+    let adapter_fees: Vec<AdapterFee> = adapter_interface::quote_send_adapters(
+        &ctx.accounts.integrator_config,
+        &ctx.accounts.integrator_chain_config,
+        ctx.remaining_accounts,
+        &adapter_instructions,
+    )?
+    .into_iter()
+    .map(|(index, amount)| AdapterFee { index, amount })
+    .collect();
+
+    let total_fee = adapter_fees.iter().try_fold(0u64, |total, fee| {
+        total
+            .checked_add(fee.amount)
+            .ok_or(EndpointError::DeliveryPriceOverflow)
+    })?;
+
+    if total_fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.outbox_message.to_account_info(),
+                },
+            ),
+            total_fee,
+        )?;
+    }
+
+    // The outbox message is seeded with the sequence before it is incremented, which is also the
+    // sequence reported in the event
+    let sequence = ctx.accounts.sequence_tracker.next_sequence();
+
     // Create and initialize the outbox message
     ctx.accounts.outbox_message.set_inner(OutboxMessage {
+        bump: ctx.bumps.outbox_message,
         src_addr: UniversalAddress::from(args.integrator_program_id),
-        sequence: ctx.accounts.sequence_tracker.next_sequence(),
+        sequence,
         dst_chain: args.dst_chain,
         dst_addr: args.dst_addr,
         payload_hash: args.payload_hash,
+        send_adapters: ctx.accounts.integrator_chain_config.send_adapter_bitmap,
         outstanding_adapters: ctx.accounts.integrator_chain_config.send_adapter_bitmap,
         refund_recipient: ctx.accounts.payer.key(),
+        created_at: slot,
+        adapter_epoch: ctx.accounts.integrator_config.adapter_epoch,
+        adapter_instructions,
+        adapter_fees,
     });
 
     emit_cpi!(MessageSent {
         sender: UniversalAddress::from(args.integrator_program_id),
-        sequence: ctx.accounts.sequence_tracker.sequence,
+        sequence,
         recipient: args.dst_addr,
         recipient_chain: args.dst_chain,
         payload_digest: args.payload_hash,
@@ -0,0 +1,94 @@
+use crate::event::AdminSignersUpdated;
+use crate::state::IntegratorConfig;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetAdminSignersArgs {
+    /// The integrator_program for the integrator_config
+    pub integrator_program_id: Pubkey,
+
+    /// The signer set of the multisig admin, empty to switch back to the single admin key
+    pub signers: Vec<Pubkey>,
+
+    /// The number of signers that must sign admin instructions, 0 to switch back to the single
+    /// admin key
+    pub threshold: u8,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: SetAdminSignersArgs)]
+pub struct SetAdminSigners<'info> {
+    /// The current admin of the IntegratorConfig account, or one of its admin signers
+    pub admin: Signer<'info>,
+
+    /// The IntegratorConfig account being updated
+    #[account(
+        mut,
+        seeds = [
+            IntegratorConfig::SEED_PREFIX,
+            args.integrator_program_id.key().as_ref(),
+        ],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+}
+
+impl<'info> SetAdminSigners<'info> {
+    pub fn validate(
+        &self,
+        args: &SetAdminSignersArgs,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        self.integrator_config
+            .check_admin(&self.admin, remaining_accounts)?;
+
+        IntegratorConfig::validate_admin_signers(&args.signers, args.threshold)
+    }
+}
+
+/// Sets the multisig admin of an IntegratorConfig account.
+///
+/// This function performs the following steps:
+/// 1. Validates that the admin approves the update and that there's no pending admin transfer.
+/// 2. Validates the signer set and threshold.
+/// 3. Updates the admin signers and threshold in the IntegratorConfig account.
+/// 4. Emits an AdminSignersUpdated event.
+///
+/// Once the threshold is non-zero, every admin instruction must be signed by at least `threshold`
+/// of the signers, passed as the `admin` account and as signer remaining accounts. The single
+/// admin key has no authority until the threshold is set back to 0.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the set_admin_signers instruction, containing:
+///   - `integrator_program_id`: The public key of the integrator program
+///   - `signers`: The signer set of the multisig admin
+///   - `threshold`: The number of signers that must sign admin instructions
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is a pending admin transfer (EndpointError::AdminTransferInProgress)
+/// * The admin does not approve the update (EndpointError::CallerNotAuthorized)
+/// * There are more than 10 signers, duplicate signers, or the threshold is 0 with signers or
+///   exceeds the number of signers (EndpointError::InvalidAdminSigners)
+///
+/// # Events
+///
+/// Emits an `AdminSignersUpdated` event
+#[access_control(SetAdminSigners::validate(&ctx.accounts, &args, ctx.remaining_accounts))]
+pub fn set_admin_signers(ctx: Context<SetAdminSigners>, args: SetAdminSignersArgs) -> Result<()> {
+    let integrator_config = &mut ctx.accounts.integrator_config;
+    integrator_config.admin_signers = args.signers.clone();
+    integrator_config.admin_threshold = args.threshold;
+
+    emit_cpi!(AdminSignersUpdated {
+        integrator: args.integrator_program_id,
+        signers: args.signers,
+        threshold: args.threshold,
+    });
+
+    Ok(())
+}
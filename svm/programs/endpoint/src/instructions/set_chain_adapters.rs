@@ -0,0 +1,275 @@
+use crate::error::EndpointError;
+use crate::event::{
+    RecvAdapterDisabledForChain, RecvAdapterEnabledForChain, SendAdapterDisabledForChain,
+    SendAdapterEnabledForChain,
+};
+use crate::state::{AdapterInfo, IntegratorChainConfig, IntegratorConfig, Role};
+use crate::utils::bitmap::Bitmap;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetChainAdaptersArgs {
+    /// The integrator_program for the integrator_config
+    pub integrator_program_id: Pubkey,
+
+    /// The chain the adapters are set for
+    pub chain_id: u16,
+
+    /// The bitmap of the adapters to enable for sending, indexed like `adapter_infos`
+    pub send_adapter_bitmap: Bitmap,
+
+    /// The bitmap of the adapters to enable for receiving, indexed like `adapter_infos`
+    pub recv_adapter_bitmap: Bitmap,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: SetChainAdaptersArgs)]
+pub struct SetChainAdapters<'info> {
+    /// The account that pays for the transaction
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The admin account that has the authority to set adapters
+    /// Or the holder of the configurator role
+    pub admin: Signer<'info>,
+
+    /// The integrator config account
+    /// This is used to check that the signer is the admin of the config and that the bitmaps only
+    /// contain registered adapters
+    #[account(
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The integrator chain config account
+    /// This account will be initialized if it doesn't exist, and its bitmaps will be overwritten
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + IntegratorChainConfig::INIT_SPACE,
+        seeds = [
+            IntegratorChainConfig::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.chain_id.to_be_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub integrator_chain_config: Account<'info, IntegratorChainConfig>,
+
+    /// The System Program
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetChainAdapters<'info> {
+    pub fn validate(
+        &self,
+        args: &SetChainAdaptersArgs,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        self.integrator_config.check_roles(
+            &[Role::Configurator],
+            &self.admin,
+            remaining_accounts,
+        )?;
+
+        // Ensure chain_id is not zero
+        require!(args.chain_id != 0, EndpointError::InvalidChainId);
+
+        // Adapters can only be enabled through `propose_config_change` while the config delay is
+        // set. Disabling adapters stays immediate
+        let enabled = (args.send_adapter_bitmap.as_value()
+            & !self.integrator_chain_config.send_adapter_bitmap.as_value())
+            | (args.recv_adapter_bitmap.as_value()
+                & !self.integrator_chain_config.recv_adapter_bitmap.as_value());
+        if enabled != 0 {
+            self.integrator_config.check_no_config_delay()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A change of the status of an adapter on a chain made by `update_chain_adapters`
+pub(crate) struct ChainAdapterChange {
+    /// The adapter whose status changed
+    pub adapter_program_id: Pubkey,
+
+    /// The new send status of the adapter, if it changed
+    pub send: Option<bool>,
+
+    /// The new receive status of the adapter, if it changed
+    pub recv: Option<bool>,
+}
+
+/// Overwrites the send and receive adapter bitmaps of a chain and updates the number of chains
+/// each changed adapter is enabled on
+///
+/// The AdapterInfo accounts of the changed adapters are read from `adapter_infos`, ordered by
+/// adapter index. Shared with `execute_config_change`, which applies delayed
+/// `SetChainAdapters` changes.
+pub(crate) fn update_chain_adapters<'info>(
+    integrator_config: &IntegratorConfig,
+    integrator_chain_config: &mut IntegratorChainConfig,
+    send_adapter_bitmap: Bitmap,
+    recv_adapter_bitmap: Bitmap,
+    adapter_infos: &'info [AccountInfo<'info>],
+) -> Result<Vec<ChainAdapterChange>> {
+    // Only registered adapters can be enabled
+    let registered = integrator_config.registered_bitmap()?.as_value();
+    require!(
+        (send_adapter_bitmap.as_value() | recv_adapter_bitmap.as_value()) & !registered == 0,
+        EndpointError::InvalidAdapterBitmap
+    );
+
+    let old_send_adapter_bitmap = integrator_chain_config.send_adapter_bitmap;
+    let old_recv_adapter_bitmap = integrator_chain_config.recv_adapter_bitmap;
+    integrator_chain_config.send_adapter_bitmap = send_adapter_bitmap;
+    integrator_chain_config.recv_adapter_bitmap = recv_adapter_bitmap;
+
+    let mut adapter_infos = adapter_infos.iter();
+    let mut changes = Vec::new();
+
+    for (index, adapter_program_id) in integrator_config.adapter_infos.iter().enumerate() {
+        let index = index as u8;
+        let (old_send, new_send) = (
+            old_send_adapter_bitmap.get(index)?,
+            send_adapter_bitmap.get(index)?,
+        );
+        let (old_recv, new_recv) = (
+            old_recv_adapter_bitmap.get(index)?,
+            recv_adapter_bitmap.get(index)?,
+        );
+        if old_send == new_send && old_recv == new_recv {
+            continue;
+        }
+
+        // Update the number of chains the adapter is enabled on
+        let adapter_info = adapter_infos
+            .next()
+            .ok_or(EndpointError::InvalidAdapterInfo)?;
+        let mut adapter_info = Account::<AdapterInfo>::try_from(adapter_info)?;
+        require!(
+            adapter_info.integrator_program_id == integrator_chain_config.integrator_program_id
+                && adapter_info.index == index,
+            EndpointError::InvalidAdapterInfo
+        );
+        adapter_info.enabled_count =
+            adapter_info.enabled_count + u32::from(new_send) + u32::from(new_recv)
+                - u32::from(old_send)
+                - u32::from(old_recv);
+        adapter_info.exit(&crate::ID)?;
+
+        changes.push(ChainAdapterChange {
+            adapter_program_id: *adapter_program_id,
+            send: (old_send != new_send).then_some(new_send),
+            recv: (old_recv != new_recv).then_some(new_recv),
+        });
+    }
+
+    Ok(changes)
+}
+
+/// Sets the send and receive adapters of an integrator for a specific chain in one instruction
+///
+/// This function performs the following steps:
+/// 1. Validates that the configurator is the signer, or that the admin is the signer and that
+///    there's no pending admin transfer.
+/// 2. Checks that the bitmaps only contain registered adapters.
+/// 3. Initializes the IntegratorChainConfig if it's not already set up.
+/// 4. Overwrites the send and receive adapter bitmaps of the chain.
+/// 5. Updates the number of chains each changed adapter is enabled on.
+/// 6. Emits an enable or disable event for every adapter whose status changed.
+///
+/// The AdapterInfo account of every adapter whose send or receive status changes must be passed
+/// as a writable remaining account, ordered by adapter index. With a multisig admin, the
+/// additional admin signers are passed after them.
+///
+/// Disabling receive adapters does not lower the receive threshold, so the admin must lower it
+/// first if needed.
+///
+/// While the config delay is set, the instruction fails if it enables any adapter, as enabling
+/// adapters must be proposed as a `SetChainAdapters` config change. Changes that only disable
+/// adapters take effect immediately.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the set_chain_adapters instruction, containing:
+///   - `integrator_program_id`: The public key of the integrator program
+///   - `chain_id`: The chain the adapters are set for
+///   - `send_adapter_bitmap`: The adapters to enable for sending
+///   - `recv_adapter_bitmap`: The adapters to enable for receiving
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is a pending admin transfer (EndpointError::AdminTransferInProgress)
+/// * Neither the configurator nor the admin is the signer (EndpointError::CallerNotAuthorized)
+/// * The config delay is set and an adapter is enabled (EndpointError::ConfigChangeTimelocked)
+/// * The chain ID is 0 (EndpointError::InvalidChainId)
+/// * A bitmap contains an adapter that is not registered (EndpointError::InvalidAdapterBitmap)
+/// * The AdapterInfo account of a changed adapter is missing or invalid
+///   (EndpointError::InvalidAdapterInfo)
+///
+/// # Events
+///
+/// Emits a `SendAdapterEnabledForChain`, `SendAdapterDisabledForChain`,
+/// `RecvAdapterEnabledForChain` or `RecvAdapterDisabledForChain` event for every change
+#[access_control(SetChainAdapters::validate(&ctx.accounts, &args, ctx.remaining_accounts))]
+pub fn set_chain_adapters<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SetChainAdapters<'info>>,
+    args: SetChainAdaptersArgs,
+) -> Result<()> {
+    let integrator_chain_config = &mut ctx.accounts.integrator_chain_config;
+
+    // If chain_id is 0, this is initial setup
+    if integrator_chain_config.chain_id == 0 {
+        integrator_chain_config.set_inner(IntegratorChainConfig::new(
+            ctx.bumps.integrator_chain_config,
+            args.chain_id,
+            args.integrator_program_id,
+        ));
+    }
+
+    let changes = update_chain_adapters(
+        &ctx.accounts.integrator_config,
+        integrator_chain_config,
+        args.send_adapter_bitmap,
+        args.recv_adapter_bitmap,
+        ctx.remaining_accounts,
+    )?;
+
+    for change in changes {
+        match change.send {
+            Some(true) => emit_cpi!(SendAdapterEnabledForChain {
+                integrator: args.integrator_program_id,
+                chain: args.chain_id,
+                adapter: change.adapter_program_id,
+            }),
+            Some(false) => emit_cpi!(SendAdapterDisabledForChain {
+                integrator: args.integrator_program_id,
+                chain: args.chain_id,
+                adapter: change.adapter_program_id,
+            }),
+            None => {}
+        }
+
+        match change.recv {
+            Some(true) => emit_cpi!(RecvAdapterEnabledForChain {
+                integrator: args.integrator_program_id,
+                chain: args.chain_id,
+                adapter: change.adapter_program_id,
+            }),
+            Some(false) => emit_cpi!(RecvAdapterDisabledForChain {
+                integrator: args.integrator_program_id,
+                chain: args.chain_id,
+                adapter: change.adapter_program_id,
+            }),
+            None => {}
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use crate::event::ChainPausedUpdated;
+use crate::state::{IntegratorChainConfig, IntegratorConfig, Role};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetChainPausedArgs {
+    /// The integrator_program for the integrator_config
+    pub integrator_program_id: Pubkey,
+
+    /// The chain the pause flags apply to
+    pub chain_id: u16,
+
+    /// Whether sending messages to the chain is paused
+    pub paused_send: bool,
+
+    /// Whether receiving and executing messages from the chain is paused
+    pub paused_recv: bool,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: SetChainPausedArgs)]
+pub struct SetChainPaused<'info> {
+    /// The admin account that has the authority to pause the chain
+    /// Or the holder of the pauser role, which can only pause
+    pub admin: Signer<'info>,
+
+    /// The integrator config account
+    /// This is used to check that the signer is the admin of the config
+    #[account(
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The integrator chain config account
+    /// Adapters must have been enabled for the chain beforehand, else this will throw
+    /// `AccountNotInitialized`
+    #[account(
+        mut,
+        seeds = [
+            IntegratorChainConfig::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.chain_id.to_be_bytes().as_ref(),
+        ],
+        bump = integrator_chain_config.bump,
+    )]
+    pub integrator_chain_config: Account<'info, IntegratorChainConfig>,
+}
+
+impl<'info> SetChainPaused<'info> {
+    pub fn validate(
+        &self,
+        args: &SetChainPausedArgs,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        // The pauser can only pause
+        let unpauses = (self.integrator_chain_config.paused_send && !args.paused_send)
+            || (self.integrator_chain_config.paused_recv && !args.paused_recv);
+        let roles: &[Role] = if unpauses { &[] } else { &[Role::Pauser] };
+
+        self.integrator_config
+            .check_roles(roles, &self.admin, remaining_accounts)
+    }
+}
+
+/// Pauses or unpauses sending and receiving messages for an integrator on a specific chain
+///
+/// This function performs the following steps:
+/// 1. Validates that the pauser is the signer and only pauses, or that the admin is the signer
+///    and that there's no pending admin transfer.
+/// 2. Updates the pause flags in the IntegratorChainConfig account.
+/// 3. Emits a ChainPausedUpdated event.
+///
+/// The enabled adapters and the receive threshold of the chain are left untouched, so
+/// unpausing restores the previous behavior.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the set_chain_paused instruction, containing:
+///   - `integrator_program_id`: The public key of the integrator program
+///   - `chain_id`: The chain the pause flags apply to
+///   - `paused_send`: Whether sending messages to the chain is paused
+///   - `paused_recv`: Whether receiving and executing messages from the chain is paused
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is a pending admin transfer (EndpointError::AdminTransferInProgress)
+/// * Neither the admin nor, when only pausing, the pauser is the signer
+///   (EndpointError::CallerNotAuthorized)
+///
+/// # Events
+///
+/// Emits a `ChainPausedUpdated` event
+#[access_control(SetChainPaused::validate(&ctx.accounts, &args, ctx.remaining_accounts))]
+pub fn set_chain_paused(ctx: Context<SetChainPaused>, args: SetChainPausedArgs) -> Result<()> {
+    let integrator_chain_config = &mut ctx.accounts.integrator_chain_config;
+    integrator_chain_config.paused_send = args.paused_send;
+    integrator_chain_config.paused_recv = args.paused_recv;
+
+    emit_cpi!(ChainPausedUpdated {
+        integrator: args.integrator_program_id,
+        chain: args.chain_id,
+        paused_send: args.paused_send,
+        paused_recv: args.paused_recv,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use crate::event::ChainPeersUpdated;
+use crate::state::{IntegratorChainConfig, IntegratorConfig, Role};
+use anchor_lang::prelude::*;
+use universal_address::UniversalAddress;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetChainPeersArgs {
+    /// The integrator_program for the integrator_config
+    pub integrator_program_id: Pubkey,
+
+    /// The source chain the peers apply to
+    pub chain_id: u16,
+
+    /// The addresses allowed to send messages from the chain, empty to allow any, and the
+    /// destinations allowed when peers are enforced
+    pub peers: Vec<UniversalAddress>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: SetChainPeersArgs)]
+pub struct SetChainPeers<'info> {
+    /// The admin account that has the authority to set the peers
+    /// Or the holder of the configurator role
+    pub admin: Signer<'info>,
+
+    /// The integrator config account
+    /// This is used to check that the signer is the admin of the config
+    #[account(
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The integrator chain config account
+    /// Adapters must have been enabled for the chain beforehand, else this will throw
+    /// `AccountNotInitialized`
+    #[account(
+        mut,
+        seeds = [
+            IntegratorChainConfig::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.chain_id.to_be_bytes().as_ref(),
+        ],
+        bump = integrator_chain_config.bump,
+    )]
+    pub integrator_chain_config: Account<'info, IntegratorChainConfig>,
+}
+
+impl<'info> SetChainPeers<'info> {
+    pub fn validate(
+        &self,
+        args: &SetChainPeersArgs,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        self.integrator_config.check_roles(
+            &[Role::Configurator],
+            &self.admin,
+            remaining_accounts,
+        )?;
+
+        IntegratorChainConfig::validate_peers(&args.peers)?;
+
+        // Allowing new addresses must wait for the config delay
+        if self
+            .integrator_chain_config
+            .loosens_peers(&args.peers, self.integrator_config.enforce_peers)
+        {
+            self.integrator_config.check_no_config_delay()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Sets the peers of an integrator for a specific chain
+///
+/// This function performs the following steps:
+/// 1. Validates that the configurator is the signer, or that the admin is the signer and that
+///    there's no pending admin transfer.
+/// 2. Checks that there are at most `MAX_PEERS` peers and no duplicates.
+/// 3. Checks that no address is newly allowed while the config delay is set.
+/// 4. Replaces the peers in the IntegratorChainConfig account.
+/// 5. Emits a ChainPeersUpdated event.
+///
+/// Once peers are set, `recv_message` and `exec_message` reject messages from any other source
+/// address on the chain. Setting an empty list allows any source address again. When the
+/// integrator enforces peers, `send_message` also rejects any other destination address.
+///
+/// While the config delay is set, changes that allow an address that is currently rejected, as a
+/// source or as an enforced destination, must be proposed. Removing peers takes effect immediately
+/// as long as at least one peer remains.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the set_chain_peers instruction, containing:
+///   - `integrator_program_id`: The public key of the integrator program
+///   - `chain_id`: The source chain the peers apply to
+///   - `peers`: The allowed source addresses, empty to allow any
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is a pending admin transfer (EndpointError::AdminTransferInProgress)
+/// * Neither the configurator nor the admin is the signer (EndpointError::CallerNotAuthorized)
+/// * There are more than `MAX_PEERS` peers or duplicate peers (EndpointError::InvalidPeers)
+/// * The config delay is set and the change allows a new address
+///   (EndpointError::ConfigChangeTimelocked)
+///
+/// # Events
+///
+/// Emits a `ChainPeersUpdated` event
+#[access_control(SetChainPeers::validate(&ctx.accounts, &args, ctx.remaining_accounts))]
+pub fn set_chain_peers(ctx: Context<SetChainPeers>, args: SetChainPeersArgs) -> Result<()> {
+    ctx.accounts.integrator_chain_config.peers = args.peers.clone();
+
+    emit_cpi!(ChainPeersUpdated {
+        integrator: args.integrator_program_id,
+        chain: args.chain_id,
+        peers: args.peers,
+    });
+
+    Ok(())
+}
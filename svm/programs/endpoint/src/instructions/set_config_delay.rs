@@ -0,0 +1,88 @@
+use crate::error::EndpointError;
+use crate::event::ConfigDelayUpdated;
+use crate::state::IntegratorConfig;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetConfigDelayArgs {
+    /// The integrator_program for the integrator_config
+    pub integrator_program_id: Pubkey,
+
+    /// The number of slots between the proposal and the execution of a config change
+    pub delay: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: SetConfigDelayArgs)]
+pub struct SetConfigDelay<'info> {
+    /// The current admin of the IntegratorConfig account
+    pub admin: Signer<'info>,
+
+    /// The IntegratorConfig account being updated
+    #[account(
+        mut,
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+}
+
+impl<'info> SetConfigDelay<'info> {
+    pub fn validate(
+        &self,
+        args: &SetConfigDelayArgs,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        self.integrator_config
+            .check_admin(&self.admin, remaining_accounts)?;
+
+        // Lowering the delay must itself wait for the current delay
+        require!(
+            args.delay >= self.integrator_config.config_delay,
+            EndpointError::ConfigChangeTimelocked
+        );
+
+        Ok(())
+    }
+}
+
+/// Increases the config delay of an integrator
+///
+/// This function performs the following steps:
+/// 1. Validates that the admin is the signer and that there's no pending admin transfer.
+/// 2. Checks that the delay is not lowered.
+/// 3. Updates the config delay in the IntegratorConfig account.
+/// 4. Emits a ConfigDelayUpdated event.
+///
+/// The delay applies to changes proposed afterwards. Lowering it must be proposed with
+/// `propose_config_change`.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the set_config_delay instruction, containing:
+///   - `integrator_program_id`: The public key of the integrator program
+///   - `delay`: The delay in slots
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is a pending admin transfer (EndpointError::AdminTransferInProgress)
+/// * The admin is not the signer (EndpointError::CallerNotAuthorized)
+/// * The delay is lower than the current delay (EndpointError::ConfigChangeTimelocked)
+///
+/// # Events
+///
+/// Emits a `ConfigDelayUpdated` event
+#[access_control(SetConfigDelay::validate(&ctx.accounts, &args, ctx.remaining_accounts))]
+pub fn set_config_delay(ctx: Context<SetConfigDelay>, args: SetConfigDelayArgs) -> Result<()> {
+    ctx.accounts.integrator_config.config_delay = args.delay;
+
+    emit_cpi!(ConfigDelayUpdated {
+        integrator: args.integrator_program_id,
+        delay: args.delay,
+    });
+
+    Ok(())
+}
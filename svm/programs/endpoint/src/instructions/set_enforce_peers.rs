@@ -0,0 +1,78 @@
+use crate::event::EnforcePeersUpdated;
+use crate::state::{IntegratorConfig, Role};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetEnforcePeersArgs {
+    /// The integrator_program for the integrator_config
+    pub integrator_program_id: Pubkey,
+
+    /// Whether `send_message` only accepts destination addresses that are peers
+    pub enforce_peers: bool,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: SetEnforcePeersArgs)]
+pub struct SetEnforcePeers<'info> {
+    /// The current admin of the IntegratorConfig account
+    /// Or the holder of the configurator role
+    pub admin: Signer<'info>,
+
+    /// The IntegratorConfig account being updated
+    #[account(
+        mut,
+        seeds = [
+            IntegratorConfig::SEED_PREFIX,
+            args.integrator_program_id.key().as_ref(),
+        ],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+}
+
+impl<'info> SetEnforcePeers<'info> {
+    pub fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        self.integrator_config
+            .check_roles(&[Role::Configurator], &self.admin, remaining_accounts)
+    }
+}
+
+/// Enables or disables the enforcement of destination peers for an integrator
+///
+/// This function performs the following steps:
+/// 1. Validates that the configurator is the signer, or that the admin is the signer and that
+///    there's no pending admin transfer.
+/// 2. Updates the enforce_peers flag in the IntegratorConfig account.
+/// 3. Emits an EnforcePeersUpdated event.
+///
+/// While enforced, `send_message` only accepts destination addresses that are peers of the
+/// destination chain, so a chain without peers cannot be sent to.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the set_enforce_peers instruction, containing:
+///   - `integrator_program_id`: The public key of the integrator program
+///   - `enforce_peers`: Whether `send_message` only accepts peers as destinations
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is a pending admin transfer (EndpointError::AdminTransferInProgress)
+/// * Neither the configurator nor the admin is the signer (EndpointError::CallerNotAuthorized)
+///
+/// # Events
+///
+/// Emits an `EnforcePeersUpdated` event
+#[access_control(SetEnforcePeers::validate(&ctx.accounts, ctx.remaining_accounts))]
+pub fn set_enforce_peers(ctx: Context<SetEnforcePeers>, args: SetEnforcePeersArgs) -> Result<()> {
+    ctx.accounts.integrator_config.enforce_peers = args.enforce_peers;
+
+    emit_cpi!(EnforcePeersUpdated {
+        integrator: args.integrator_program_id,
+        enforce_peers: args.enforce_peers,
+    });
+
+    Ok(())
+}
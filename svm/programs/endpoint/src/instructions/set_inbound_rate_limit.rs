@@ -0,0 +1,142 @@
+use crate::event::InboundRateLimitUpdated;
+use crate::state::{IntegratorChainConfig, IntegratorConfig, Role};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetInboundRateLimitArgs {
+    /// The integrator_program for the integrator_config
+    pub integrator_program_id: Pubkey,
+
+    /// The source chain the rate limit applies to
+    pub chain_id: u16,
+
+    /// The maximum number of messages per window, 0 to disable the rate limit
+    pub limit: u64,
+
+    /// The number of slots over which the limit refills
+    pub window: u64,
+
+    /// The number of slots after which a queued message can be released
+    pub queue_delay: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: SetInboundRateLimitArgs)]
+pub struct SetInboundRateLimit<'info> {
+    /// The admin account that has the authority to set the rate limit
+    /// Or the holder of the configurator role
+    pub admin: Signer<'info>,
+
+    /// The integrator config account
+    /// This is used to check that the signer is the admin of the config
+    #[account(
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The integrator chain config account
+    /// Adapters must have been enabled for the chain beforehand, else this will throw
+    /// `AccountNotInitialized`
+    #[account(
+        mut,
+        seeds = [
+            IntegratorChainConfig::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.chain_id.to_be_bytes().as_ref(),
+        ],
+        bump = integrator_chain_config.bump,
+    )]
+    pub integrator_chain_config: Account<'info, IntegratorChainConfig>,
+}
+
+impl<'info> SetInboundRateLimit<'info> {
+    pub fn validate(
+        &self,
+        args: &SetInboundRateLimitArgs,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        self.integrator_config.check_roles(
+            &[Role::Configurator],
+            &self.admin,
+            remaining_accounts,
+        )?;
+
+        // Raising or disabling the rate limit, or releasing queued messages sooner, must wait for
+        // the config delay
+        let integrator_chain_config = &self.integrator_chain_config;
+        let rate_limit = &integrator_chain_config.inbound_rate_limit;
+        if rate_limit.is_loosened_by(args.limit, args.window)
+            || (rate_limit.is_enabled()
+                && args.queue_delay < integrator_chain_config.inbound_queue_delay)
+        {
+            self.integrator_config.check_no_config_delay()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Sets the inbound rate limit of an integrator for a specific chain
+///
+/// This function performs the following steps:
+/// 1. Validates that the configurator is the signer, or that the admin is the signer and that
+///    there's no pending admin transfer.
+/// 2. Checks that the rate limit is not loosened while the config delay is set.
+/// 3. Updates the inbound rate limit and queue delay in the IntegratorChainConfig account.
+/// 4. Emits an InboundRateLimitUpdated event.
+///
+/// The available capacity moves by the change of the limit, so messages already received in the
+/// current window still count against the new limit. Enabling the rate limit starts with a full
+/// capacity. `recv_message` queues the messages exceeding the limit, and the queue delay only
+/// applies to messages queued afterwards.
+///
+/// While the config delay is set, disabling the rate limit, raising the limit, shortening the
+/// window or lowering the queue delay of an enabled rate limit must be proposed as a
+/// `SetInboundRateLimit` config change. Tightening the rate limit takes effect immediately.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the set_inbound_rate_limit instruction, containing:
+///   - `integrator_program_id`: The public key of the integrator program
+///   - `chain_id`: The source chain the rate limit applies to
+///   - `limit`: The maximum number of messages per window, 0 to disable the rate limit
+///   - `window`: The number of slots over which the limit refills
+///   - `queue_delay`: The number of slots after which a queued message can be released
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is a pending admin transfer (EndpointError::AdminTransferInProgress)
+/// * Neither the configurator nor the admin is the signer (EndpointError::CallerNotAuthorized)
+/// * The config delay is set and the rate limit is loosened (EndpointError::ConfigChangeTimelocked)
+/// * The limit is non-zero and the window is 0 (EndpointError::InvalidRateLimit)
+///
+/// # Events
+///
+/// Emits an `InboundRateLimitUpdated` event
+#[access_control(SetInboundRateLimit::validate(&ctx.accounts, &args, ctx.remaining_accounts))]
+pub fn set_inbound_rate_limit(
+    ctx: Context<SetInboundRateLimit>,
+    args: SetInboundRateLimitArgs,
+) -> Result<()> {
+    let integrator_chain_config = &mut ctx.accounts.integrator_chain_config;
+    integrator_chain_config.inbound_rate_limit.set_limit(
+        args.limit,
+        args.window,
+        Clock::get()?.slot,
+    )?;
+    integrator_chain_config.inbound_queue_delay = args.queue_delay;
+
+    emit_cpi!(InboundRateLimitUpdated {
+        integrator: args.integrator_program_id,
+        chain: args.chain_id,
+        limit: args.limit,
+        window: args.window,
+        queue_delay: args.queue_delay,
+    });
+
+    Ok(())
+}
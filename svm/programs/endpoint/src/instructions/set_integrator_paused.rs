@@ -0,0 +1,97 @@
+use crate::event::IntegratorPausedUpdated;
+use crate::state::{IntegratorConfig, Role};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetIntegratorPausedArgs {
+    /// The integrator_program for the integrator_config
+    pub integrator_program_id: Pubkey,
+
+    /// Whether sending messages is paused for every destination chain
+    pub paused_send: bool,
+
+    /// Whether receiving and executing messages is paused for every source chain
+    pub paused_recv: bool,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: SetIntegratorPausedArgs)]
+pub struct SetIntegratorPaused<'info> {
+    /// The current admin of the IntegratorConfig account
+    /// Or the holder of the pauser role, which can only pause
+    pub admin: Signer<'info>,
+
+    /// The IntegratorConfig account being updated
+    #[account(
+        mut,
+        seeds = [
+            IntegratorConfig::SEED_PREFIX,
+            args.integrator_program_id.key().as_ref(),
+        ],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+}
+
+impl<'info> SetIntegratorPaused<'info> {
+    pub fn validate(
+        &self,
+        args: &SetIntegratorPausedArgs,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        // The pauser can only pause
+        let unpauses = (self.integrator_config.paused_send && !args.paused_send)
+            || (self.integrator_config.paused_recv && !args.paused_recv);
+        let roles: &[Role] = if unpauses { &[] } else { &[Role::Pauser] };
+
+        self.integrator_config
+            .check_roles(roles, &self.admin, remaining_accounts)
+    }
+}
+
+/// Pauses or unpauses sending and receiving messages for an integrator on every chain.
+///
+/// This function performs the following steps:
+/// 1. Validates that the pauser is the signer and only pauses, or that the admin is the signer
+///    and that there's no pending admin transfer.
+/// 2. Updates the pause flags in the IntegratorConfig account.
+/// 3. Emits an IntegratorPausedUpdated event.
+///
+/// The adapter configuration is left untouched, so unpausing restores the previous behavior.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the set_integrator_paused instruction, containing:
+///   - `integrator_program_id`: The public key of the integrator program
+///   - `paused_send`: Whether sending messages is paused
+///   - `paused_recv`: Whether receiving and executing messages is paused
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is a pending admin transfer (EndpointError::AdminTransferInProgress)
+/// * Neither the admin nor, when only pausing, the pauser is the signer
+///   (EndpointError::CallerNotAuthorized)
+///
+/// # Events
+///
+/// Emits an `IntegratorPausedUpdated` event
+#[access_control(SetIntegratorPaused::validate(&ctx.accounts, &args, ctx.remaining_accounts))]
+pub fn set_integrator_paused(
+    ctx: Context<SetIntegratorPaused>,
+    args: SetIntegratorPausedArgs,
+) -> Result<()> {
+    let integrator_config = &mut ctx.accounts.integrator_config;
+    integrator_config.paused_send = args.paused_send;
+    integrator_config.paused_recv = args.paused_recv;
+
+    emit_cpi!(IntegratorPausedUpdated {
+        integrator: args.integrator_program_id,
+        paused_send: args.paused_send,
+        paused_recv: args.paused_recv,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use crate::event::MinAttestationAgeUpdated;
+use crate::state::{IntegratorChainConfig, IntegratorConfig, Role};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetMinAttestationAgeArgs {
+    /// The integrator_program for the integrator_config
+    pub integrator_program_id: Pubkey,
+
+    /// The source chain the minimum attestation age applies to
+    pub chain_id: u16,
+
+    /// The number of slots between the threshold being reached and the message being received,
+    /// 0 to disable the delay
+    pub min_attestation_age: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: SetMinAttestationAgeArgs)]
+pub struct SetMinAttestationAge<'info> {
+    /// The admin account that has the authority to set the minimum attestation age
+    /// Or the holder of the configurator role
+    pub admin: Signer<'info>,
+
+    /// The integrator config account
+    /// This is used to check that the signer is the admin of the config
+    #[account(
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The integrator chain config account
+    /// Adapters must have been enabled for the chain beforehand, else this will throw
+    /// `AccountNotInitialized`
+    #[account(
+        mut,
+        seeds = [
+            IntegratorChainConfig::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.chain_id.to_be_bytes().as_ref(),
+        ],
+        bump = integrator_chain_config.bump,
+    )]
+    pub integrator_chain_config: Account<'info, IntegratorChainConfig>,
+}
+
+impl<'info> SetMinAttestationAge<'info> {
+    pub fn validate(
+        &self,
+        args: &SetMinAttestationAgeArgs,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        self.integrator_config.check_roles(
+            &[Role::Configurator],
+            &self.admin,
+            remaining_accounts,
+        )?;
+
+        // Lowering the age must wait for the config delay
+        if args.min_attestation_age < self.integrator_chain_config.min_attestation_age {
+            self.integrator_config.check_no_config_delay()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Sets the minimum attestation age of an integrator for a specific chain
+///
+/// This function performs the following steps:
+/// 1. Validates that the configurator is the signer, or that the admin is the signer and that
+///    there's no pending admin transfer.
+/// 2. Checks that the age is not lowered while the config delay is set.
+/// 3. Updates the minimum attestation age in the IntegratorChainConfig account.
+/// 4. Emits a MinAttestationAgeUpdated event.
+///
+/// The age is counted from the slot at which the attestations of a message first met the receive
+/// threshold, which `attest_message` records. A message that only meets the threshold after it
+/// was lowered has no recorded slot, and can only be received once another enabled adapter
+/// attests to it.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the set_min_attestation_age instruction, containing:
+///   - `integrator_program_id`: The public key of the integrator program
+///   - `chain_id`: The source chain the minimum attestation age applies to
+///   - `min_attestation_age`: The age in slots, 0 to disable the delay
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is a pending admin transfer (EndpointError::AdminTransferInProgress)
+/// * Neither the configurator nor the admin is the signer (EndpointError::CallerNotAuthorized)
+/// * The age is lowered while the config delay is set (EndpointError::ConfigChangeTimelocked)
+///
+/// # Events
+///
+/// Emits a `MinAttestationAgeUpdated` event
+#[access_control(SetMinAttestationAge::validate(&ctx.accounts, &args, ctx.remaining_accounts))]
+pub fn set_min_attestation_age(
+    ctx: Context<SetMinAttestationAge>,
+    args: SetMinAttestationAgeArgs,
+) -> Result<()> {
+    ctx.accounts.integrator_chain_config.min_attestation_age = args.min_attestation_age;
+
+    emit_cpi!(MinAttestationAgeUpdated {
+        integrator: args.integrator_program_id,
+        chain: args.chain_id,
+        min_attestation_age: args.min_attestation_age,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use crate::event::OutboundRateLimitUpdated;
+use crate::state::{IntegratorChainConfig, IntegratorConfig, Role};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetOutboundRateLimitArgs {
+    /// The integrator_program for the integrator_config
+    pub integrator_program_id: Pubkey,
+
+    /// The destination chain the rate limit applies to
+    pub chain_id: u16,
+
+    /// The maximum number of messages per window, 0 to disable the rate limit
+    pub limit: u64,
+
+    /// The number of slots over which the limit refills
+    pub window: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: SetOutboundRateLimitArgs)]
+pub struct SetOutboundRateLimit<'info> {
+    /// The admin account that has the authority to set the rate limit
+    /// Or the holder of the configurator role
+    pub admin: Signer<'info>,
+
+    /// The integrator config account
+    /// This is used to check that the signer is the admin of the config
+    #[account(
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The integrator chain config account
+    /// Adapters must have been enabled for the chain beforehand, else this will throw
+    /// `AccountNotInitialized`
+    #[account(
+        mut,
+        seeds = [
+            IntegratorChainConfig::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.chain_id.to_be_bytes().as_ref(),
+        ],
+        bump = integrator_chain_config.bump,
+    )]
+    pub integrator_chain_config: Account<'info, IntegratorChainConfig>,
+}
+
+impl<'info> SetOutboundRateLimit<'info> {
+    pub fn validate(
+        &self,
+        args: &SetOutboundRateLimitArgs,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        self.integrator_config.check_roles(
+            &[Role::Configurator],
+            &self.admin,
+            remaining_accounts,
+        )?;
+
+        // Raising or disabling the rate limit must wait for the config delay
+        if self
+            .integrator_chain_config
+            .outbound_rate_limit
+            .is_loosened_by(args.limit, args.window)
+        {
+            self.integrator_config.check_no_config_delay()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Sets the outbound rate limit of an integrator for a specific chain
+///
+/// This function performs the following steps:
+/// 1. Validates that the configurator is the signer, or that the admin is the signer and that
+///    there's no pending admin transfer.
+/// 2. Checks that the rate limit is not loosened while the config delay is set.
+/// 3. Updates the outbound rate limit in the IntegratorChainConfig account.
+/// 4. Emits an OutboundRateLimitUpdated event.
+///
+/// The available capacity moves by the change of the limit, so messages already sent in the
+/// current window still count against the new limit. Enabling the rate limit starts with a full
+/// capacity.
+///
+/// While the config delay is set, disabling the rate limit, raising the limit or shortening the
+/// window must be proposed as a `SetOutboundRateLimit` config change. Tightening the rate limit
+/// takes effect immediately.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the set_outbound_rate_limit instruction, containing:
+///   - `integrator_program_id`: The public key of the integrator program
+///   - `chain_id`: The destination chain the rate limit applies to
+///   - `limit`: The maximum number of messages per window, 0 to disable the rate limit
+///   - `window`: The number of slots over which the limit refills
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is a pending admin transfer (EndpointError::AdminTransferInProgress)
+/// * Neither the configurator nor the admin is the signer (EndpointError::CallerNotAuthorized)
+/// * The config delay is set and the rate limit is loosened (EndpointError::ConfigChangeTimelocked)
+/// * The limit is non-zero and the window is 0 (EndpointError::InvalidRateLimit)
+///
+/// # Events
+///
+/// Emits an `OutboundRateLimitUpdated` event
+#[access_control(SetOutboundRateLimit::validate(&ctx.accounts, &args, ctx.remaining_accounts))]
+pub fn set_outbound_rate_limit(
+    ctx: Context<SetOutboundRateLimit>,
+    args: SetOutboundRateLimitArgs,
+) -> Result<()> {
+    ctx.accounts
+        .integrator_chain_config
+        .outbound_rate_limit
+        .set_limit(args.limit, args.window, Clock::get()?.slot)?;
+
+    emit_cpi!(OutboundRateLimitUpdated {
+        integrator: args.integrator_program_id,
+        chain: args.chain_id,
+        limit: args.limit,
+        window: args.window,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use crate::event::OutboxMessageTimeoutUpdated;
+use crate::state::IntegratorConfig;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetOutboxMessageTimeoutArgs {
+    /// The integrator_program for the integrator_config
+    pub integrator_program_id: Pubkey,
+
+    /// The number of slots after which outbox messages can be reclaimed, 0 to disable expiry
+    pub timeout: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: SetOutboxMessageTimeoutArgs)]
+pub struct SetOutboxMessageTimeout<'info> {
+    /// The current admin of the IntegratorConfig account
+    pub admin: Signer<'info>,
+
+    /// The IntegratorConfig account being updated
+    #[account(
+        mut,
+        seeds = [
+            IntegratorConfig::SEED_PREFIX,
+            args.integrator_program_id.key().as_ref(),
+        ],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+}
+
+impl<'info> SetOutboxMessageTimeout<'info> {
+    pub fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        self.integrator_config
+            .check_admin(&self.admin, remaining_accounts)
+    }
+}
+
+/// Sets the number of slots after which an outbox message of an integrator can be reclaimed.
+///
+/// This function performs the following steps:
+/// 1. Validates that the admin is the signer and that there's no pending admin transfer.
+/// 2. Updates the outbox message timeout in the IntegratorConfig account.
+/// 3. Emits an OutboxMessageTimeoutUpdated event.
+///
+/// The timeout applies to messages that are already in the outbox as well as new ones.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the set_outbox_message_timeout instruction, containing:
+///   - `integrator_program_id`: The public key of the integrator program
+///   - `timeout`: The timeout in slots, 0 to disable expiry
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is a pending admin transfer (EndpointError::AdminTransferInProgress)
+/// * The admin is not the signer (EndpointError::CallerNotAuthorized)
+///
+/// # Events
+///
+/// Emits an `OutboxMessageTimeoutUpdated` event
+#[access_control(SetOutboxMessageTimeout::validate(&ctx.accounts, ctx.remaining_accounts))]
+pub fn set_outbox_message_timeout(
+    ctx: Context<SetOutboxMessageTimeout>,
+    args: SetOutboxMessageTimeoutArgs,
+) -> Result<()> {
+    ctx.accounts.integrator_config.outbox_message_timeout = args.timeout;
+
+    emit_cpi!(OutboxMessageTimeoutUpdated {
+        integrator: args.integrator_program_id,
+        timeout: args.timeout,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::EndpointError, event::EndpointPausedUpdated, state::EndpointConfig};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetPausedArgs {
+    /// Whether the endpoint should be paused
+    pub paused: bool,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    /// The guardian of the endpoint
+    pub guardian: Signer<'info>,
+
+    /// The endpoint config account
+    #[account(
+        mut,
+        seeds = [EndpointConfig::SEED_PREFIX],
+        bump = endpoint_config.bump,
+        has_one = guardian @ EndpointError::CallerNotAuthorized,
+    )]
+    pub endpoint_config: Account<'info, EndpointConfig>,
+}
+
+/// Pauses or unpauses the endpoint
+///
+/// While paused, `send_message`, `pick_up_message`, `attest_message`, `recv_message` and
+/// `exec_message` fail with `EndpointPaused`. Outbox messages can still be cancelled or reclaimed
+/// so that their fees are not locked during an incident.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the set_paused instruction, containing:
+///   - `paused`: Whether the endpoint should be paused
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The signer is not the guardian (EndpointError::CallerNotAuthorized)
+///
+/// # Events
+///
+/// Emits an `EndpointPausedUpdated` event
+pub fn set_paused(ctx: Context<SetPaused>, args: SetPausedArgs) -> Result<()> {
+    ctx.accounts.endpoint_config.paused = args.paused;
+
+    emit_cpi!(EndpointPausedUpdated {
+        paused: args.paused,
+    });
+
+    Ok(())
+}
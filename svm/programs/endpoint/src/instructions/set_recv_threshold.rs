@@ -0,0 +1,120 @@
+use crate::error::EndpointError;
+use crate::event::RecvThresholdUpdated;
+use crate::state::{IntegratorChainConfig, IntegratorConfig, Role};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetRecvThresholdArgs {
+    /// The integrator_program for the integrator_config
+    pub integrator_program_id: Pubkey,
+
+    /// The source chain the threshold applies to
+    pub chain_id: u16,
+
+    /// The minimum number of enabled receive adapters that must attest, 0 to disable the check
+    pub threshold: u8,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: SetRecvThresholdArgs)]
+pub struct SetRecvThreshold<'info> {
+    /// The admin account that has the authority to set the threshold
+    /// Or the holder of the configurator role
+    pub admin: Signer<'info>,
+
+    /// The integrator config account
+    /// This is used to check that the signer is the admin of the config
+    #[account(
+        seeds = [IntegratorConfig::SEED_PREFIX, args.integrator_program_id.as_ref()],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The integrator chain config account
+    /// Receive adapters must have been enabled for the chain beforehand, else this will throw
+    /// `AccountNotInitialized`
+    #[account(
+        mut,
+        seeds = [
+            IntegratorChainConfig::SEED_PREFIX,
+            args.integrator_program_id.as_ref(),
+            args.chain_id.to_be_bytes().as_ref(),
+        ],
+        bump = integrator_chain_config.bump,
+    )]
+    pub integrator_chain_config: Account<'info, IntegratorChainConfig>,
+}
+
+impl<'info> SetRecvThreshold<'info> {
+    pub fn validate(
+        &self,
+        args: &SetRecvThresholdArgs,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        self.integrator_config.check_roles(
+            &[Role::Configurator],
+            &self.admin,
+            remaining_accounts,
+        )?;
+        self.integrator_config.check_no_config_delay()?;
+
+        // A threshold above the number of enabled receive adapters could never be met
+        require!(
+            usize::from(args.threshold) <= self.integrator_chain_config.recv_adapter_bitmap.len(),
+            EndpointError::InvalidThreshold
+        );
+
+        Ok(())
+    }
+}
+
+/// Sets the receive threshold of an integrator for a specific chain
+///
+/// This function performs the following steps:
+/// 1. Validates that the configurator is the signer, or that the admin is the signer and that
+///    there's no pending admin transfer.
+/// 2. Checks that the threshold does not exceed the number of enabled receive adapters.
+/// 3. Updates the receive threshold in the IntegratorChainConfig account.
+/// 4. Emits a RecvThresholdUpdated event.
+///
+/// Disabling receive adapters afterwards does not lower the threshold, so the admin must lower it
+/// first if needed.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+/// * `args` - The arguments for the set_recv_threshold instruction, containing:
+///   - `integrator_program_id`: The public key of the integrator program
+///   - `chain_id`: The source chain the threshold applies to
+///   - `threshold`: The new threshold, 0 to disable the check
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is a pending admin transfer (EndpointError::AdminTransferInProgress)
+/// * Neither the configurator nor the admin is the signer (EndpointError::CallerNotAuthorized)
+/// * The config delay is set (EndpointError::ConfigChangeTimelocked)
+/// * The threshold exceeds the number of enabled receive adapters (EndpointError::InvalidThreshold)
+///
+/// # Events
+///
+/// Emits a `RecvThresholdUpdated` event
+#[access_control(SetRecvThreshold::validate(&ctx.accounts, &args, ctx.remaining_accounts))]
+pub fn set_recv_threshold(
+    ctx: Context<SetRecvThreshold>,
+    args: SetRecvThresholdArgs,
+) -> Result<()> {
+    let integrator_chain_config = &mut ctx.accounts.integrator_chain_config;
+    let old_threshold = integrator_chain_config.recv_threshold;
+    integrator_chain_config.recv_threshold = args.threshold;
+
+    emit_cpi!(RecvThresholdUpdated {
+        integrator: args.integrator_program_id,
+        chain: args.chain_id,
+        old_threshold,
+        new_threshold: args.threshold,
+    });
+
+    Ok(())
+}
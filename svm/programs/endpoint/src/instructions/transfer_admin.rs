@@ -1,5 +1,5 @@
 use crate::error::EndpointError;
-use crate::event::{AdminUpdateRequested, AdminUpdated};
+use crate::event::{AdminTransferCancelled, AdminUpdateRequested, AdminUpdated};
 use crate::state::IntegratorConfig;
 use anchor_lang::prelude::*;
 
@@ -33,8 +33,9 @@ pub struct TransferAdmin<'info> {
 }
 
 impl<'info> TransferAdmin<'info> {
-    pub fn validate(&self) -> Result<()> {
-        self.integrator_config.check_admin(&self.admin)
+    pub fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        self.integrator_config
+            .check_admin(&self.admin, remaining_accounts)
     }
 }
 
@@ -46,16 +47,45 @@ pub struct ClaimAdmin<'info> {
 
     /// The IntegratorConfig account being claimed
     /// The constraint here checks that there is a pending admin transfer and the signer is the pending_admin
+    /// With a multisig admin, the current admin can only cancel the transfer if the threshold is 1,
+    /// else it must use `cancel_admin_transfer`
     #[account(
         mut,
         constraint = integrator_config.admin.is_some() @ EndpointError::CallerNotAuthorized,
         constraint = integrator_config.pending_admin.is_some() @ EndpointError::NoAdminTransferInProgress,
         constraint = integrator_config.pending_admin == Some(new_admin.key())
-        || integrator_config.admin == Some(new_admin.key()) @ EndpointError::CallerNotAuthorized,
+        || integrator_config.is_admin(&[new_admin.key()]) @ EndpointError::CallerNotAuthorized,
     )]
     pub integrator_config: Account<'info, IntegratorConfig>,
 }
 
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelAdminTransfer<'info> {
+    /// The current admin of the IntegratorConfig account
+    pub admin: Signer<'info>,
+
+    /// The IntegratorConfig account with the pending admin transfer
+    #[account(
+        mut,
+        seeds = [
+            IntegratorConfig::SEED_PREFIX,
+            integrator_config.integrator_program_id.key().as_ref(),
+        ],
+        bump = integrator_config.bump,
+        constraint = integrator_config.pending_admin.is_some() @ EndpointError::NoAdminTransferInProgress,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+}
+
+impl<'info> CancelAdminTransfer<'info> {
+    pub fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        // The pending admin transfer would fail `check_admin`
+        self.integrator_config
+            .check_admin_signature(&self.admin, remaining_accounts)
+    }
+}
+
 /// Initiates the transfer of admin rights for an IntegratorConfig account.
 ///
 /// This function performs the following steps:
@@ -81,13 +111,15 @@ pub struct ClaimAdmin<'info> {
 ///
 /// # Events
 /// Emits an `AdminUpdateRequested` event
-#[access_control(TransferAdmin::validate(&ctx.accounts))]
+#[access_control(TransferAdmin::validate(&ctx.accounts, ctx.remaining_accounts))]
 pub fn transfer_admin(ctx: Context<TransferAdmin>, args: TransferAdminArgs) -> Result<()> {
-    ctx.accounts.integrator_config.pending_admin = Some(args.new_admin);
+    let integrator_config = &mut ctx.accounts.integrator_config;
+    integrator_config.pending_admin = Some(args.new_admin);
 
+    // With a multisig admin the signer is one of the admin signers, so the admin key is emitted
     emit_cpi!(AdminUpdateRequested {
         integrator: args.integrator_program_id,
-        old_admin: ctx.accounts.admin.key(),
+        old_admin: integrator_config.admin.unwrap(),
         new_admin: args.new_admin,
     });
 
@@ -98,8 +130,10 @@ pub fn transfer_admin(ctx: Context<TransferAdmin>, args: TransferAdminArgs) -> R
 ///
 /// This function performs the following steps:
 /// 1. Validates that the signer is either the pending admin or the current admin.
-/// 2. Sets the new admin as the current admin.
-/// 3. Clears the pending admin field.
+/// 2. If the signer is the pending admin, sets it as the single admin key, clearing the multisig
+///    admin if any, and emits an AdminUpdated event.
+/// 3. Otherwise, emits an AdminTransferCancelled event, as `cancel_admin_transfer` does.
+/// 4. Clears the pending admin field.
 ///
 /// # Arguments
 ///
@@ -115,17 +149,69 @@ pub fn transfer_admin(ctx: Context<TransferAdmin>, args: TransferAdminArgs) -> R
 /// * There is no pending admin transfer (EndpointError::NoAdminTransferInProgress)
 /// * The signer is not the pending admin or the current admin (EndpointError::CallerNotAuthorized)
 ///
-/// Emits an `AdminUpdated` event
+/// # Events
+///
+/// Emits an `AdminUpdated` event, or an `AdminTransferCancelled` event if the current admin is the
+/// signer
 pub fn claim_admin(ctx: Context<ClaimAdmin>) -> Result<()> {
     // The constraints in ClaimAdmin struct ensure that pending_admin is Some and matches the signer
     // or the admin matches the signer
-    ctx.accounts.integrator_config.admin = Some(ctx.accounts.new_admin.key());
-    ctx.accounts.integrator_config.pending_admin = None;
+    let new_admin = ctx.accounts.new_admin.key();
+    let integrator_config = &mut ctx.accounts.integrator_config;
+    let integrator = integrator_config.integrator_program_id;
+    let old_admin = integrator_config.admin.unwrap();
+    let pending_admin = integrator_config.pending_admin.take().unwrap();
+
+    if pending_admin == new_admin {
+        integrator_config.admin = Some(new_admin);
+        integrator_config.admin_signers.clear();
+        integrator_config.admin_threshold = 0;
+
+        emit_cpi!(AdminUpdated {
+            integrator,
+            old_admin,
+            new_admin,
+        });
+    } else {
+        emit_cpi!(AdminTransferCancelled {
+            integrator,
+            admin: old_admin,
+            pending_admin,
+        });
+    }
+
+    Ok(())
+}
+
+/// Cancels a pending admin transfer of an IntegratorConfig account.
+///
+/// This function performs the following steps:
+/// 1. Validates that there is a pending admin transfer and that the current admin is the signer.
+/// 2. Clears the pending admin field.
+/// 3. Emits an AdminTransferCancelled event.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * There is no pending admin transfer (EndpointError::NoAdminTransferInProgress)
+/// * The current admin is not the signer (EndpointError::CallerNotAuthorized)
+///
+/// # Events
+///
+/// Emits an `AdminTransferCancelled` event
+#[access_control(CancelAdminTransfer::validate(&ctx.accounts, ctx.remaining_accounts))]
+pub fn cancel_admin_transfer(ctx: Context<CancelAdminTransfer>) -> Result<()> {
+    let integrator_config = &mut ctx.accounts.integrator_config;
+    let pending_admin = integrator_config.pending_admin.take().unwrap();
 
-    emit_cpi!(AdminUpdated {
-        integrator: ctx.accounts.integrator_config.integrator_program_id,
-        old_admin: ctx.accounts.integrator_config.admin.unwrap(),
-        new_admin: ctx.accounts.new_admin.key(),
+    emit_cpi!(AdminTransferCancelled {
+        integrator: integrator_config.integrator_program_id,
+        admin: integrator_config.admin.unwrap(),
+        pending_admin,
     });
 
     Ok(())
@@ -32,8 +32,9 @@ pub struct UpdateAdmin<'info> {
 }
 
 impl<'info> UpdateAdmin<'info> {
-    pub fn validate(&self) -> Result<()> {
-        self.integrator_config.check_admin(&self.admin)
+    pub fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        self.integrator_config
+            .check_admin(&self.admin, remaining_accounts)
     }
 }
 
@@ -43,7 +44,8 @@ impl<'info> UpdateAdmin<'info> {
 /// 1. Validates that the current admin is initiating the update.
 /// 2. Checks if there's a pending admin transfer.
 /// 3. Updates the admin field in the IntegratorConfig account.
-/// 4. Emits an AdminUpdated event.
+/// 4. Clears the multisig admin, if any, so that the new admin is the single admin key.
+/// 5. Emits an AdminUpdated event.
 ///
 /// # Arguments
 ///
@@ -65,15 +67,19 @@ impl<'info> UpdateAdmin<'info> {
 /// # Events
 ///
 /// Emits an `AdminUpdated` event
-#[access_control(UpdateAdmin::validate(&ctx.accounts))]
+#[access_control(UpdateAdmin::validate(&ctx.accounts, ctx.remaining_accounts))]
 pub fn update_admin(ctx: Context<UpdateAdmin>, args: UpdateAdminArgs) -> Result<()> {
-    ctx.accounts.integrator_config.admin = Some(args.new_admin);
+    let integrator_config = &mut ctx.accounts.integrator_config;
+    let old_admin = integrator_config.admin.unwrap();
+    integrator_config.admin = Some(args.new_admin);
+    integrator_config.admin_signers.clear();
+    integrator_config.admin_threshold = 0;
 
     // Emit the AdminUpdated event
     emit_cpi!(AdminUpdated {
         integrator: args.integrator_program_id,
         new_admin: args.new_admin,
-        old_admin: ctx.accounts.integrator_config.admin.unwrap(),
+        old_admin,
     });
 
     Ok(())
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::EndpointError,
+    event::ThresholdReachedSlotUpdated,
+    state::{AttestationInfo, IntegratorChainConfig, IntegratorConfig},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateThresholdReachedSlot<'info> {
+    /// The integrator config account of the destination integrator
+    /// This is required to exclude the attestations of adapters whose slot was reassigned
+    #[account(
+        seeds = [
+            IntegratorConfig::SEED_PREFIX,
+            attestation_info.dst_addr.to_pubkey().as_ref(),
+        ],
+        bump = integrator_config.bump,
+    )]
+    pub integrator_config: Account<'info, IntegratorConfig>,
+
+    /// The integrator chain config account of the source chain
+    /// This is required to read the enabled_bitmap and the receive threshold from
+    #[account(
+        seeds = [
+            IntegratorChainConfig::SEED_PREFIX,
+            attestation_info.dst_addr.to_pubkey().as_ref(),
+            attestation_info.src_chain.to_be_bytes().as_ref()
+        ],
+        bump = integrator_chain_config.bump,
+    )]
+    pub integrator_chain_config: Account<'info, IntegratorChainConfig>,
+
+    /// The attestation info account of the message
+    #[account(
+        mut,
+        seeds = [AttestationInfo::SEED_PREFIX, &attestation_info.message_hash],
+        bump = attestation_info.bump,
+    )]
+    pub attestation_info: Account<'info, AttestationInfo>,
+}
+
+impl<'info> UpdateThresholdReachedSlot<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            !self.attestation_info.executed,
+            EndpointError::AlreadyExecuted
+        );
+        Ok(())
+    }
+}
+
+/// Updates the slot at which a message met the receive threshold of its source chain
+///
+/// This function performs the following steps:
+/// 1. Checks that the message has not been executed.
+/// 2. Clears the attestations of the adapters whose slot was freed or migrated since they attested.
+/// 3. Records the current slot if the threshold is met and no slot was recorded, or resets it if
+///    the threshold is not met.
+/// 4. Emits a ThresholdReachedSlotUpdated event.
+///
+/// This can be called by anyone. `attest_message` and `recv_message` update the slot as well, but
+/// `recv_message` fails, and so reverts the update, while the threshold is not met or the minimum
+/// attestation age has not elapsed. This records the slot of a message whose threshold is met by a
+/// change of the chain's adapters or threshold, and resets it once the threshold is lost, so that
+/// its minimum attestation age restarts when the threshold is met again.
+///
+/// # Arguments
+///
+/// * `ctx` - The context of the instruction, containing the accounts involved
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The message has already been executed (EndpointError::AlreadyExecuted)
+///
+/// # Events
+///
+/// Emits a `ThresholdReachedSlotUpdated` event
+#[access_control(UpdateThresholdReachedSlot::validate(&ctx.accounts))]
+pub fn update_threshold_reached_slot(ctx: Context<UpdateThresholdReachedSlot>) -> Result<()> {
+    let attestation_info = &mut ctx.accounts.attestation_info;
+
+    attestation_info.clear_stale_attestations(&ctx.accounts.integrator_config)?;
+    attestation_info
+        .update_threshold_reached_slot(&ctx.accounts.integrator_chain_config, Clock::get()?.slot);
+
+    emit_cpi!(ThresholdReachedSlotUpdated {
+        message_hash: attestation_info.message_hash,
+        src_chain: attestation_info.src_chain,
+        src_addr: attestation_info.src_addr,
+        sequence: attestation_info.sequence,
+        dst_addr: attestation_info.dst_addr,
+        threshold_reached_slot: attestation_info.threshold_reached_slot,
+    });
+
+    Ok(())
+}
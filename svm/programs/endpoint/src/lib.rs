@@ -10,11 +10,22 @@ use instructions::*;
 
 declare_id!("FMPF1RnXz1vvZ6eovoEQqMPXYRUgYqFKFMXzTJkbWWVD");
 
+// The Wormhole chain ID of the chain the endpoint is deployed on. `solana` is enabled by default,
+// so the other features take precedence over it
 cfg_if::cfg_if! {
-    if #[cfg(feature = "solana")] {
+    if #[cfg(all(feature = "pythnet", feature = "custom-chain-id"))] {
+        compile_error!("Only one of the 'pythnet' and 'custom-chain-id' features can be enabled.");
+    } else if #[cfg(feature = "custom-chain-id")] {
+        pub const CHAIN_ID: u16 = utils::chain_id::parse_chain_id(env!(
+            "ENDPOINT_CHAIN_ID",
+            "The 'custom-chain-id' feature requires the ENDPOINT_CHAIN_ID environment variable."
+        ));
+    } else if #[cfg(feature = "pythnet")] {
+        pub const CHAIN_ID: u16 = 26;
+    } else if #[cfg(feature = "solana")] {
         pub const CHAIN_ID: u16 = 1;
     } else {
-        compile_error!("The 'solana' feature must be enabled.");
+        compile_error!("One of the 'solana', 'pythnet' or 'custom-chain-id' features must be enabled.");
     }
 }
 
@@ -23,6 +34,29 @@ cfg_if::cfg_if! {
 pub mod endpoint {
     use super::*;
 
+    /// Initializes the program-wide endpoint config. Only the upgrade authority of the endpoint
+    /// program can call this, once
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `InitializeArgs` struct containing:
+    ///     * `guardian` - The guardian allowed to pause and unpause the endpoint
+    pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
+        instructions::initialize::initialize(ctx, args)
+    }
+
+    /// Pauses or unpauses sending, picking up, attesting to, receiving and executing messages
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `SetPausedArgs` struct containing:
+    ///     * `paused` - Whether the endpoint should be paused
+    pub fn set_paused(ctx: Context<SetPaused>, args: SetPausedArgs) -> Result<()> {
+        instructions::set_paused::set_paused(ctx, args)
+    }
+
     /// Registers an integrator and initializes their configuration
     ///
     /// # Arguments
@@ -47,6 +81,31 @@ pub mod endpoint {
         instructions::add_adapter::add_adapter(ctx, args)
     }
 
+    /// Removes a registered adapter of an integrator, freeing its slot for a new adapter
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `RemoveAdapterArgs` struct containing:
+    ///     * `integrator_program_id` - The program id of the integrator_program
+    ///     * `adapter_program_id` - The address of the adapter to remove
+    pub fn remove_adapter(ctx: Context<RemoveAdapter>, args: RemoveAdapterArgs) -> Result<()> {
+        instructions::remove_adapter::remove_adapter(ctx, args)
+    }
+
+    /// Replaces a registered adapter of an integrator, migrating its slot to a new adapter
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `ReplaceAdapterArgs` struct containing:
+    ///     * `integrator_program_id` - The program id of the integrator_program
+    ///     * `adapter_program_id` - The address of the adapter to replace
+    ///     * `new_adapter_program_id` - The address of the adapter taking over the slot
+    pub fn replace_adapter(ctx: Context<ReplaceAdapter>, args: ReplaceAdapterArgs) -> Result<()> {
+        instructions::replace_adapter::replace_adapter(ctx, args)
+    }
+
     /// Sets an adapter as a receive adapter for a specific chain
     ///
     /// # Arguments
@@ -99,6 +158,23 @@ pub mod endpoint {
         instructions::disable_adapter::disable_send_adapter(ctx, args)
     }
 
+    /// Sets the send and receive adapters of an integrator for a specific chain in one instruction
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `SetChainAdaptersArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `chain_id` - The chain the adapters are set for
+    ///     * `send_adapter_bitmap` - The adapters to enable for sending
+    ///     * `recv_adapter_bitmap` - The adapters to enable for receiving
+    pub fn set_chain_adapters<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SetChainAdapters<'info>>,
+        args: SetChainAdaptersArgs,
+    ) -> Result<()> {
+        instructions::set_chain_adapters::set_chain_adapters(ctx, args)
+    }
+
     /// Updates the admin of an IntegratorConfig account
     ///
     /// # Arguments
@@ -134,6 +210,15 @@ pub mod endpoint {
         instructions::transfer_admin::claim_admin(ctx)
     }
 
+    /// Cancels a pending admin transfer of an IntegratorConfig account
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    pub fn cancel_admin_transfer(ctx: Context<CancelAdminTransfer>) -> Result<()> {
+        instructions::transfer_admin::cancel_admin_transfer(ctx)
+    }
+
     /// Discards the admin role for an IntegratorConfig account, making it immutable
     ///
     /// # Arguments
@@ -143,6 +228,238 @@ pub mod endpoint {
         instructions::discard_admin::discard_admin(ctx)
     }
 
+    /// Grants a role of an integrator to an account, replacing its previous holder
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `GrantRoleArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `role` - The role to grant
+    ///     * `account` - The account the role is granted to
+    pub fn grant_role(ctx: Context<GrantRole>, args: GrantRoleArgs) -> Result<()> {
+        instructions::grant_role::grant_role(ctx, args)
+    }
+
+    /// Revokes a role of an integrator from its holder
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `RevokeRoleArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `role` - The role to revoke
+    pub fn revoke_role(ctx: Context<RevokeRole>, args: RevokeRoleArgs) -> Result<()> {
+        instructions::revoke_role::revoke_role(ctx, args)
+    }
+
+    /// Sets the multisig admin of an IntegratorConfig account
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `SetAdminSignersArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `signers` - The signer set of the multisig admin
+    ///     * `threshold` - The number of signers that must sign admin instructions
+    pub fn set_admin_signers(
+        ctx: Context<SetAdminSigners>,
+        args: SetAdminSignersArgs,
+    ) -> Result<()> {
+        instructions::set_admin_signers::set_admin_signers(ctx, args)
+    }
+
+    /// Increases the number of slots between the proposal and the execution of config changes of
+    /// an integrator
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `SetConfigDelayArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `delay` - The delay in slots
+    pub fn set_config_delay(ctx: Context<SetConfigDelay>, args: SetConfigDelayArgs) -> Result<()> {
+        instructions::set_config_delay::set_config_delay(ctx, args)
+    }
+
+    /// Proposes a config change of an integrator, executable once the config delay has elapsed
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `ProposeConfigChangeArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `change` - The proposed change
+    pub fn propose_config_change(
+        ctx: Context<ProposeConfigChange>,
+        args: ProposeConfigChangeArgs,
+    ) -> Result<()> {
+        instructions::propose_config_change::propose_config_change(ctx, args)
+    }
+
+    /// Executes a proposed config change once the config delay has elapsed. Anyone can call this
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    pub fn execute_config_change<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteConfigChange<'info>>,
+    ) -> Result<()> {
+        instructions::execute_config_change::execute_config_change(ctx)
+    }
+
+    /// Cancels a proposed config change. The admin or the guardian of the endpoint can call this
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+        instructions::cancel_config_change::cancel_config_change(ctx)
+    }
+
+    /// Sets the number of slots after which outbox messages of an integrator can be reclaimed
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `SetOutboxMessageTimeoutArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `timeout` - The timeout in slots, 0 to disable expiry
+    pub fn set_outbox_message_timeout(
+        ctx: Context<SetOutboxMessageTimeout>,
+        args: SetOutboxMessageTimeoutArgs,
+    ) -> Result<()> {
+        instructions::set_outbox_message_timeout::set_outbox_message_timeout(ctx, args)
+    }
+
+    /// Sets the minimum number of enabled receive adapters that must attest to a message from a
+    /// specific chain before `recv_message` accepts it
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `SetRecvThresholdArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `chain_id` - The source chain the threshold applies to
+    ///     * `threshold` - The new threshold, 0 to disable the check
+    pub fn set_recv_threshold(
+        ctx: Context<SetRecvThreshold>,
+        args: SetRecvThresholdArgs,
+    ) -> Result<()> {
+        instructions::set_recv_threshold::set_recv_threshold(ctx, args)
+    }
+
+    /// Pauses or unpauses sending and receiving messages for an integrator on every chain
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `SetIntegratorPausedArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `paused_send` - Whether sending messages is paused
+    ///     * `paused_recv` - Whether receiving and executing messages is paused
+    pub fn set_integrator_paused(
+        ctx: Context<SetIntegratorPaused>,
+        args: SetIntegratorPausedArgs,
+    ) -> Result<()> {
+        instructions::set_integrator_paused::set_integrator_paused(ctx, args)
+    }
+
+    /// Pauses or unpauses sending and receiving messages for an integrator on a specific chain
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `SetChainPausedArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `chain_id` - The chain the pause flags apply to
+    ///     * `paused_send` - Whether sending messages to the chain is paused
+    ///     * `paused_recv` - Whether receiving and executing messages from the chain is paused
+    pub fn set_chain_paused(ctx: Context<SetChainPaused>, args: SetChainPausedArgs) -> Result<()> {
+        instructions::set_chain_paused::set_chain_paused(ctx, args)
+    }
+
+    /// Sets the source addresses allowed to send messages from a chain
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `SetChainPeersArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `chain_id` - The source chain the peers apply to
+    ///     * `peers` - The allowed source addresses, empty to allow any
+    pub fn set_chain_peers(ctx: Context<SetChainPeers>, args: SetChainPeersArgs) -> Result<()> {
+        instructions::set_chain_peers::set_chain_peers(ctx, args)
+    }
+
+    /// Enables or disables the restriction of send destinations to the peers of each chain
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `SetEnforcePeersArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `enforce_peers` - Whether `send_message` only accepts peers as destinations
+    pub fn set_enforce_peers(
+        ctx: Context<SetEnforcePeers>,
+        args: SetEnforcePeersArgs,
+    ) -> Result<()> {
+        instructions::set_enforce_peers::set_enforce_peers(ctx, args)
+    }
+
+    /// Sets the rate limit on the messages an integrator sends to a chain
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `SetOutboundRateLimitArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `chain_id` - The destination chain the rate limit applies to
+    ///     * `limit` - The maximum number of messages per window, 0 to disable the rate limit
+    ///     * `window` - The number of slots over which the limit refills
+    pub fn set_outbound_rate_limit(
+        ctx: Context<SetOutboundRateLimit>,
+        args: SetOutboundRateLimitArgs,
+    ) -> Result<()> {
+        instructions::set_outbound_rate_limit::set_outbound_rate_limit(ctx, args)
+    }
+
+    /// Sets the rate limit on the messages an integrator receives from a chain, and the delay
+    /// after which the messages exceeding it can be released
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `SetInboundRateLimitArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `chain_id` - The source chain the rate limit applies to
+    ///     * `limit` - The maximum number of messages per window, 0 to disable the rate limit
+    ///     * `window` - The number of slots over which the limit refills
+    ///     * `queue_delay` - The number of slots after which a queued message can be released
+    pub fn set_inbound_rate_limit(
+        ctx: Context<SetInboundRateLimit>,
+        args: SetInboundRateLimitArgs,
+    ) -> Result<()> {
+        instructions::set_inbound_rate_limit::set_inbound_rate_limit(ctx, args)
+    }
+
+    /// Sets the number of slots that must elapse after a message reached the receive threshold
+    /// of a chain before it can be received
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `SetMinAttestationAgeArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `chain_id` - The source chain the minimum attestation age applies to
+    ///     * `min_attestation_age` - The age in slots, 0 to disable the delay
+    pub fn set_min_attestation_age(
+        ctx: Context<SetMinAttestationAge>,
+        args: SetMinAttestationAgeArgs,
+    ) -> Result<()> {
+        instructions::set_min_attestation_age::set_min_attestation_age(ctx, args)
+    }
+
     /// Sends a message through the endpoint
     ///
     /// # Arguments
@@ -154,7 +471,14 @@ pub mod endpoint {
     ///     * `dst_chain` - The destination chain ID
     ///     * `dst_addr` - The destination address
     ///     * `payload_hash` - The hash of the message payload
-    pub fn send_message(ctx: Context<SendMessage>, args: SendMessageArgs) -> Result<()> {
+    ///     * `adapter_instructions` - The encoded per-adapter instructions
+    ///
+    /// The send-enabled adapter programs must be passed as remaining accounts, ordered by adapter
+    /// index, so their delivery fees can be quoted and escrowed
+    pub fn send_message<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SendMessage<'info>>,
+        args: SendMessageArgs,
+    ) -> Result<()> {
         instructions::send_message::send_message(ctx, args)
     }
 
@@ -166,13 +490,96 @@ pub mod endpoint {
     ///     * `outbox_message` - The outbox message to pick up
     ///     * `adapter_info` - The adapter info account
     ///     * `adapter_pda` - The adapter PDA signer
+    ///     * `adapter_fee_vault` - The fee vault receiving the adapter's escrowed fee
     /// * `args` - The `PickUpMessageArgs` struct containing:
     ///     * `adapter_program_id` - The program ID of the adapter
     ///     * `adapter_pda_bump` - The bump for the adapter PDA
-    pub fn pick_up_message(ctx: Context<PickUpMessage>, args: PickUpMessageArgs) -> Result<()> {
+    ///
+    /// # Returns
+    ///
+    /// The instruction payload the integrator provided for this adapter (empty if none)
+    pub fn pick_up_message(
+        ctx: Context<PickUpMessage>,
+        args: PickUpMessageArgs,
+    ) -> Result<Vec<u8>> {
         instructions::pick_up_message::pick_up_message(ctx, args)
     }
 
+    /// Initializes the fee vault of an adapter program, which receives the adapter's delivery
+    /// fees when it picks up messages. Anyone can call this
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `InitAdapterFeeVaultArgs` struct containing:
+    ///     * `adapter_program_id` - The program ID of the adapter
+    pub fn init_adapter_fee_vault(
+        ctx: Context<InitAdapterFeeVault>,
+        args: InitAdapterFeeVaultArgs,
+    ) -> Result<()> {
+        instructions::init_adapter_fee_vault::init_adapter_fee_vault(ctx, args)
+    }
+
+    /// Claims the delivery fees held in the fee vault of an adapter
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `ClaimAdapterFeesArgs` struct containing:
+    ///     * `adapter_program_id` - The program ID of the adapter
+    ///     * `adapter_pda_bump` - The bump for the adapter PDA
+    pub fn claim_adapter_fees(
+        ctx: Context<ClaimAdapterFees>,
+        args: ClaimAdapterFeesArgs,
+    ) -> Result<()> {
+        instructions::claim_adapter_fees::claim_adapter_fees(ctx, args)
+    }
+
+    /// Cancels a message that has not been picked up by any adapter yet, returning its rent and
+    /// escrowed fees to the refund recipient
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `CancelMessageArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `integrator_program_pda_bump` - The bump for the integrator_program_pda derivation
+    ///     * `sequence` - The sequence of the message to cancel
+    pub fn cancel_message(ctx: Context<CancelMessage>, args: CancelMessageArgs) -> Result<()> {
+        instructions::cancel_message::cancel_message(ctx, args)
+    }
+
+    /// Reclaims an expired outbox message, returning its rent and unclaimed fees to the refund
+    /// recipient. Anyone can call this once the integrator's outbox message timeout has elapsed
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    pub fn reclaim_outbox_message(ctx: Context<ReclaimOutboxMessage>) -> Result<()> {
+        instructions::reclaim_outbox_message::reclaim_outbox_message(ctx)
+    }
+
+    /// Quotes the total delivery price of a message across the send-enabled adapters
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction, with the send-enabled adapter programs passed
+    ///   as remaining accounts ordered by adapter index
+    /// * `args` - The `QuoteDeliveryPriceArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `dst_chain` - The destination chain ID
+    ///     * `adapter_instructions` - The encoded per-adapter instructions
+    ///
+    /// # Returns
+    ///
+    /// The total delivery price in lamports
+    pub fn quote_delivery_price<'info>(
+        ctx: Context<'_, '_, 'info, 'info, QuoteDeliveryPrice<'info>>,
+        args: QuoteDeliveryPriceArgs,
+    ) -> Result<u64> {
+        instructions::quote_delivery_price::quote_delivery_price(ctx, args)
+    }
+
     /// Attests to a message
     ///
     /// # Arguments
@@ -191,6 +598,81 @@ pub mod endpoint {
         instructions::attest_message::attest_message(ctx, args)
     }
 
+    /// Returns the status of a message
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `GetMessageStatusArgs` struct containing:
+    ///     * `src_chain` - The source chain ID
+    ///     * `src_addr` - The source address
+    ///     * `sequence` - The sequence number
+    ///     * `dst_chain` - The destination chain ID
+    ///     * `integrator_program_id` - The program ID of the integrator, aka dst_addr
+    ///     * `payload_hash` - The hash of the message payload
+    ///
+    /// # Returns
+    ///
+    /// The enabled receive adapters bitmap, the attested adapters bitmap and whether the message
+    /// has been executed
+    pub fn get_message_status(
+        ctx: Context<GetMessageStatus>,
+        args: GetMessageStatusArgs,
+    ) -> Result<MessageStatus> {
+        instructions::get_message_status::get_message_status(ctx, args)
+    }
+
+    /// Gets the number of messages an integrator can currently send to a chain
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    /// * `args` - The `GetOutboundCapacityArgs` struct containing:
+    ///     * `integrator_program_id` - The program ID of the integrator
+    ///     * `dst_chain` - The destination chain ID
+    ///
+    /// # Returns
+    ///
+    /// The remaining capacity of the outbound rate limit, `u64::MAX` if it is disabled
+    pub fn get_outbound_capacity(
+        ctx: Context<GetOutboundCapacity>,
+        args: GetOutboundCapacityArgs,
+    ) -> Result<u64> {
+        instructions::get_outbound_capacity::get_outbound_capacity(ctx, args)
+    }
+
+    /// Closes the attestation info account of an executed message, or of a message whose sequence
+    /// has been executed, refunding its rent to the account that paid for it. Replay protection is
+    /// kept by the replay bitmap account
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    pub fn close_attestation_info(ctx: Context<CloseAttestationInfo>) -> Result<()> {
+        instructions::close_attestation_info::close_attestation_info(ctx)
+    }
+
+    /// Releases a message queued by the inbound rate limit once its queue delay has elapsed, so
+    /// that it can be received
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    pub fn release_queued_message(ctx: Context<ReleaseQueuedMessage>) -> Result<()> {
+        instructions::release_queued_message::release_queued_message(ctx)
+    }
+
+    /// Records the slot at which a message met the receive threshold of its source chain, or
+    /// resets it if the threshold is no longer met, so that the minimum attestation age is
+    /// counted from the current threshold state
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of the instruction
+    pub fn update_threshold_reached_slot(ctx: Context<UpdateThresholdReachedSlot>) -> Result<()> {
+        instructions::update_threshold_reached_slot::update_threshold_reached_slot(ctx)
+    }
+
     /// Executes a message
     ///
     /// # Arguments
@@ -224,10 +706,17 @@ pub mod endpoint {
     ///     * `dst_chain` - The destination chain ID
     ///     * `integrator_program_id` - The program ID of the integrator, aka dst_addr
     ///     * `payload_hash` - The hash of the message payload
+    ///     * `queue_if_rate_limited` - Whether to queue the message instead of failing if it
+    ///       exceeds the inbound rate limit
+    ///
+    /// # Returns
+    ///
+    /// Whether the message has been received, or queued by the inbound rate limit if
+    /// `queue_if_rate_limited` is set
     pub fn recv_message(
         ctx: Context<RecvMessage>,
         args: recv_message::RecvMessageArgs,
-    ) -> Result<()> {
+    ) -> Result<recv_message::RecvStatus> {
         recv_message::recv_message(ctx, args)
     }
 }
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+/// Holds the delivery fees released to an adapter program when it picks up messages
+///
+/// There is a single vault per adapter program, shared by every integrator. It is owned by the
+/// endpoint and kept rent exempt, so fees smaller than the rent exemption minimum can be credited
+/// to it. The adapter claims the fees with `claim_adapter_fees`.
+#[account]
+#[derive(InitSpace)]
+pub struct AdapterFeeVault {
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// The program ID of the adapter the fees belong to
+    /// This is used as a seed for PDA derivation
+    pub adapter_program_id: Pubkey,
+}
+
+impl AdapterFeeVault {
+    /// Seed prefix for deriving AdapterFeeVault PDAs
+    pub const SEED_PREFIX: &'static [u8] = b"adapter_fee_vault";
+
+    pub fn pda(adapter_program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::SEED_PREFIX, adapter_program_id.as_ref()],
+            &crate::ID,
+        )
+    }
+}
@@ -21,6 +21,10 @@ pub struct AdapterInfo {
     /// Index of the adapter with respect to the adapter_info vector in
     /// IntegratorConfig
     pub index: u8,
+
+    /// Number of chains the adapter is enabled on, counting send and receive separately
+    /// The adapter can only be removed once it is disabled on every chain
+    pub enabled_count: u32,
 }
 
 impl AdapterInfo {
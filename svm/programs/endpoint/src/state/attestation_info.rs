@@ -3,7 +3,22 @@ use anchor_lang::solana_program::keccak;
 use universal_address::UniversalAddress;
 
 use crate::error::EndpointError;
+use crate::state::{IntegratorChainConfig, IntegratorConfig};
 use crate::utils::bitmap::Bitmap;
+use crate::CHAIN_ID;
+
+/// Whether a message has been queued by the inbound rate limit of its source chain
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Clone, Copy, Debug, InitSpace)]
+pub enum QueueStatus {
+    /// The message has not been queued
+    NotQueued,
+
+    /// The message has been queued and can be released from `release_slot`
+    Queued { release_slot: u64 },
+
+    /// The message has been released and `recv_message` no longer rate limits it
+    Released,
+}
 
 #[account]
 #[derive(InitSpace)]
@@ -38,12 +53,42 @@ pub struct AttestationInfo {
 
     /// The bitmap of receive-enabled adapters for this source chain that have attested to the message
     pub attested_adapters: Bitmap,
+
+    /// The `adapter_epoch` of the integrator when `attested_adapters` was last checked against
+    /// the slots freed or migrated since
+    pub adapter_epoch: u64,
+
+    /// Whether the message has been queued by the inbound rate limit
+    pub queue_status: QueueStatus,
+
+    /// The slot at which the enabled receive adapters that attested first met the receive
+    /// threshold of the source chain
+    /// The minimum attestation age of the chain is counted from this slot
+    pub threshold_reached_slot: Option<u64>,
+
+    /// The account that paid the rent of this account
+    /// The rent is refunded to it when the account is closed with `close_attestation_info`
+    pub payer: Pubkey,
 }
 
 impl AttestationInfo {
     /// Seed prefix for deriving AttestionInfo PDAs
     pub const SEED_PREFIX: &'static [u8] = b"attestation_info";
 
+    /// Checks that neither chain ID is 0 and that the message is destined to this chain
+    pub fn validate_chains(src_chain: u16, dst_chain: u16) -> Result<()> {
+        require!(
+            src_chain != 0 && dst_chain != 0,
+            EndpointError::InvalidChainId
+        );
+        require!(
+            dst_chain == CHAIN_ID,
+            EndpointError::InvalidDestinationChain
+        );
+
+        Ok(())
+    }
+
     pub fn new(
         bump: u8,
         src_chain: u16,
@@ -53,11 +98,7 @@ impl AttestationInfo {
         dst_addr: UniversalAddress,
         payload_hash: [u8; 32],
     ) -> Result<Self> {
-        // Check that neither chain ID is 0
-        require!(
-            src_chain != 0 && dst_chain != 0,
-            EndpointError::InvalidChainId
-        );
+        Self::validate_chains(src_chain, dst_chain)?;
 
         let mut info = Self {
             bump,
@@ -70,6 +111,10 @@ impl AttestationInfo {
             message_hash: [0; 32],
             executed: false,
             attested_adapters: Bitmap::new(),
+            adapter_epoch: 0,
+            queue_status: QueueStatus::NotQueued,
+            threshold_reached_slot: None,
+            payer: Pubkey::default(),
         };
 
         info.message_hash = info.compute_own_message_hash();
@@ -77,6 +122,69 @@ impl AttestationInfo {
         Ok(info)
     }
 
+    /// Clears the attestations of the adapters whose slot was freed or migrated since they were
+    /// last checked
+    /// Attestations of a removed or replaced adapter then no longer count towards the threshold,
+    /// and the adapter reusing its slot can attest to the message
+    pub fn clear_stale_attestations(&mut self, integrator_config: &IntegratorConfig) -> Result<()> {
+        self.attested_adapters = self.current_attestations(integrator_config)?;
+        self.adapter_epoch = integrator_config.adapter_epoch;
+        Ok(())
+    }
+
+    /// Returns the attested adapters, excluding those whose slot was freed or migrated since
+    /// they were last checked
+    pub fn current_attestations(&self, integrator_config: &IntegratorConfig) -> Result<Bitmap> {
+        let stale = integrator_config.reassigned_since(self.adapter_epoch)?;
+        Ok(Bitmap::from_value(
+            self.attested_adapters.as_value() & !stale.as_value(),
+        ))
+    }
+
+    /// Returns whether the number of enabled receive adapters that attested meets the receive
+    /// threshold of the chain
+    /// Only attestations from currently enabled receive adapters count towards the threshold
+    pub fn is_threshold_met(&self, integrator_chain_config: &IntegratorChainConfig) -> bool {
+        self.attested_adapters
+            .count_enabled_bits(integrator_chain_config.recv_adapter_bitmap)
+            >= integrator_chain_config.effective_recv_threshold()
+    }
+
+    /// Updates `threshold_reached_slot` from the current threshold state at the given slot,
+    /// returning whether the threshold is met
+    /// The slot is recorded when the threshold is met and was not already, and reset once it is
+    /// no longer met, so that the minimum attestation age restarts when it is met again
+    pub fn update_threshold_reached_slot(
+        &mut self,
+        integrator_chain_config: &IntegratorChainConfig,
+        slot: u64,
+    ) -> bool {
+        let threshold_met = self.is_threshold_met(integrator_chain_config);
+        if !threshold_met {
+            self.threshold_reached_slot = None;
+        } else if self.threshold_reached_slot.is_none() {
+            self.threshold_reached_slot = Some(slot);
+        }
+        threshold_met
+    }
+
+    /// Checks that the minimum attestation age has elapsed at the given slot since the threshold
+    /// was reached
+    pub fn check_attestation_age(&self, min_attestation_age: u64, slot: u64) -> Result<()> {
+        if min_attestation_age == 0 {
+            return Ok(());
+        }
+        let threshold_reached_slot = self
+            .threshold_reached_slot
+            .ok_or(EndpointError::AttestationTooRecent)?;
+        require!(
+            slot >= threshold_reached_slot.saturating_add(min_attestation_age),
+            EndpointError::AttestationTooRecent
+        );
+
+        Ok(())
+    }
+
     pub fn compute_own_message_hash(&self) -> [u8; 32] {
         Self::compute_message_hash(
             self.src_chain,
@@ -159,4 +267,131 @@ mod tests {
             "Computed hash does not match expected hash"
         );
     }
+
+    #[test]
+    fn test_validate_chains() {
+        assert!(AttestationInfo::validate_chains(2, CHAIN_ID).is_ok());
+        assert_eq!(
+            AttestationInfo::validate_chains(0, CHAIN_ID).unwrap_err(),
+            EndpointError::InvalidChainId.into()
+        );
+        assert_eq!(
+            AttestationInfo::validate_chains(2, 0).unwrap_err(),
+            EndpointError::InvalidChainId.into()
+        );
+        assert_eq!(
+            AttestationInfo::validate_chains(2, CHAIN_ID + 1).unwrap_err(),
+            EndpointError::InvalidDestinationChain.into()
+        );
+    }
+
+    #[test]
+    fn test_check_attestation_age() {
+        let mut info = AttestationInfo::new(
+            255,
+            2,
+            UniversalAddress::from_bytes([1; 32]),
+            1,
+            CHAIN_ID,
+            UniversalAddress::from_bytes([2; 32]),
+            [3; 32],
+        )
+        .unwrap();
+
+        // Without a minimum age the threshold slot is not needed
+        assert!(info.check_attestation_age(0, 0).is_ok());
+        assert_eq!(
+            info.check_attestation_age(10, 100).unwrap_err(),
+            EndpointError::AttestationTooRecent.into()
+        );
+
+        info.threshold_reached_slot = Some(100);
+        assert_eq!(
+            info.check_attestation_age(10, 109).unwrap_err(),
+            EndpointError::AttestationTooRecent.into()
+        );
+        assert!(info.check_attestation_age(10, 110).is_ok());
+    }
+
+    #[test]
+    fn test_update_threshold_reached_slot() {
+        let mut info = AttestationInfo::new(
+            255,
+            2,
+            UniversalAddress::from_bytes([1; 32]),
+            1,
+            CHAIN_ID,
+            UniversalAddress::from_bytes([2; 32]),
+            [3; 32],
+        )
+        .unwrap();
+        let mut config = IntegratorChainConfig::new(255, 2, Pubkey::new_unique());
+        config.recv_adapter_bitmap.set(0, true).unwrap();
+        config.recv_adapter_bitmap.set(1, true).unwrap();
+        config.recv_threshold = 2;
+        info.attested_adapters.set(0, true).unwrap();
+
+        assert!(!info.update_threshold_reached_slot(&config, 100));
+        assert_eq!(info.threshold_reached_slot, None);
+
+        // Lowering the threshold records the slot at which it is met
+        config.recv_threshold = 1;
+        assert!(info.update_threshold_reached_slot(&config, 110));
+        assert_eq!(info.threshold_reached_slot, Some(110));
+
+        // The slot is kept while the threshold stays met
+        assert!(info.update_threshold_reached_slot(&config, 120));
+        assert_eq!(info.threshold_reached_slot, Some(110));
+
+        // Disabling the attesting adapter resets the slot, which restarts once it is met again
+        config.recv_adapter_bitmap.set(0, false).unwrap();
+        assert!(!info.update_threshold_reached_slot(&config, 130));
+        assert_eq!(info.threshold_reached_slot, None);
+        config.recv_adapter_bitmap.set(0, true).unwrap();
+        assert!(info.update_threshold_reached_slot(&config, 140));
+        assert_eq!(info.threshold_reached_slot, Some(140));
+    }
+
+    #[test]
+    fn test_is_threshold_met_zero_threshold() {
+        let mut info = AttestationInfo::new(
+            255,
+            2,
+            UniversalAddress::from_bytes([1; 32]),
+            1,
+            CHAIN_ID,
+            UniversalAddress::from_bytes([2; 32]),
+            [3; 32],
+        )
+        .unwrap();
+        let mut config = IntegratorChainConfig::new(255, 2, Pubkey::new_unique());
+        config.recv_adapter_bitmap.set(0, true).unwrap();
+        assert!(info.is_threshold_met(&config));
+
+        // With a minimum attestation age, an attestation from an enabled adapter is required
+        config.min_attestation_age = 10;
+        assert!(!info.is_threshold_met(&config));
+        info.attested_adapters.set(0, true).unwrap();
+        assert!(info.is_threshold_met(&config));
+        config.recv_adapter_bitmap.set(0, false).unwrap();
+        assert!(!info.is_threshold_met(&config));
+    }
+
+    #[test]
+    fn test_new_invalid_destination_chain() {
+        let result = AttestationInfo::new(
+            255,
+            2,
+            UniversalAddress::from_bytes([1; 32]),
+            1,
+            CHAIN_ID + 1,
+            UniversalAddress::from_bytes([2; 32]),
+            [3; 32],
+        );
+
+        assert_eq!(
+            result.err(),
+            Some(EndpointError::InvalidDestinationChain.into())
+        );
+    }
 }
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// Program-wide configuration of the endpoint
+/// There is a single instance of this account, created once with `initialize`
+#[account]
+#[derive(InitSpace)]
+pub struct EndpointConfig {
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// The guardian allowed to pause and unpause the endpoint
+    pub guardian: Pubkey,
+
+    /// Whether sending, picking up, attesting to, receiving and executing messages is paused
+    pub paused: bool,
+}
+
+impl EndpointConfig {
+    /// Seed prefix for deriving the EndpointConfig PDA
+    pub const SEED_PREFIX: &'static [u8] = b"endpoint_config";
+
+    pub fn pda() -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED_PREFIX], &crate::ID)
+    }
+}
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
-use crate::utils::bitmap::Bitmap;
+use crate::error::EndpointError;
+use crate::utils::{bitmap::Bitmap, rate_limit::RateLimit};
+use universal_address::UniversalAddress;
 
 /// Manages the adapters for a specific integrator on a particular chain.
 ///
@@ -25,12 +27,67 @@ pub struct IntegratorChainConfig {
 
     /// Bitmap tracking the status of receive adapters
     pub recv_adapter_bitmap: Bitmap,
+
+    /// Minimum number of enabled receive adapters that must attest to a message before
+    /// `recv_message` accepts it. A value of 0 disables the check, unless a minimum attestation
+    /// age is set
+    pub recv_threshold: u8,
+
+    /// Whether sending messages to this chain is paused
+    pub paused_send: bool,
+
+    /// Whether receiving and executing messages from this chain is paused
+    pub paused_recv: bool,
+
+    /// Addresses on this chain allowed to send messages to the integrator. An empty list allows
+    /// any source address. These are also the only destination addresses `send_message` accepts
+    /// for this chain when the integrator enforces peers
+    #[max_len(8)]
+    pub peers: Vec<UniversalAddress>,
+
+    /// Rate limit on the messages sent to this chain
+    pub outbound_rate_limit: RateLimit,
+
+    /// Rate limit on the messages received from this chain
+    /// `recv_message` queues the messages exceeding it instead of receiving them
+    pub inbound_rate_limit: RateLimit,
+
+    /// Number of slots after which a message queued by the inbound rate limit can be released
+    pub inbound_queue_delay: u64,
+
+    /// Number of slots that must elapse after a message reached the receive threshold before
+    /// `recv_message` accepts it, giving watchers time to disable a compromised adapter. A value
+    /// of 0 disables the delay. While it is set, a receive threshold of 0 still requires one
+    /// attestation from an enabled receive adapter
+    pub min_attestation_age: u64,
 }
 
 impl IntegratorChainConfig {
     /// Seed prefix for deriving IntegratorChainConfig PDAs
     pub const SEED_PREFIX: &'static [u8] = b"integrator_chain_config";
 
+    /// Maximum number of peers per chain
+    pub const MAX_PEERS: usize = 8;
+
+    /// Returns the config of a chain with no enabled adapters, peers or limits
+    pub fn new(bump: u8, chain_id: u16, integrator_program_id: Pubkey) -> Self {
+        Self {
+            bump,
+            integrator_program_id,
+            chain_id,
+            send_adapter_bitmap: Bitmap::new(),
+            recv_adapter_bitmap: Bitmap::new(),
+            recv_threshold: 0,
+            paused_send: false,
+            paused_recv: false,
+            peers: Vec::new(),
+            outbound_rate_limit: RateLimit::default(),
+            inbound_rate_limit: RateLimit::default(),
+            inbound_queue_delay: 0,
+            min_attestation_age: 0,
+        }
+    }
+
     pub fn pda(integrator_program: &Pubkey, chain_id: u16) -> (Pubkey, u8) {
         Pubkey::find_program_address(
             &[
@@ -41,4 +98,138 @@ impl IntegratorChainConfig {
             &crate::ID,
         )
     }
+
+    /// Deserializes the given integrator chain config account, returning `None` if the account is
+    /// not initialized
+    pub fn try_load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.owner != &crate::ID {
+            return Ok(None);
+        }
+        Ok(Some(Self::try_deserialize(
+            &mut &account.try_borrow_data()?[..],
+        )?))
+    }
+
+    /// Checks that there are at most `MAX_PEERS` peers and no duplicates
+    /// Returns the number of enabled receive adapters that must attest to a message
+    /// With a minimum attestation age, a threshold of 0 still requires one attestation, so that
+    /// disabling the adapters that attested stops the message before the age has elapsed
+    pub fn effective_recv_threshold(&self) -> u8 {
+        if self.min_attestation_age > 0 {
+            self.recv_threshold.max(1)
+        } else {
+            self.recv_threshold
+        }
+    }
+
+    pub fn validate_peers(peers: &[UniversalAddress]) -> Result<()> {
+        require!(peers.len() <= Self::MAX_PEERS, EndpointError::InvalidPeers);
+        for (i, peer) in peers.iter().enumerate() {
+            require!(!peers[..i].contains(peer), EndpointError::InvalidPeers);
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether replacing the peers allows an address that is currently rejected, either
+    /// as a source address or as a destination when the integrator enforces peers
+    pub fn loosens_peers(&self, peers: &[UniversalAddress], enforce_peers: bool) -> bool {
+        if self.peers.is_empty() {
+            // Any source address is already allowed, but new peers are new destinations
+            return enforce_peers && !peers.is_empty();
+        }
+        peers.is_empty() || peers.iter().any(|peer| !self.peers.contains(peer))
+    }
+
+    /// Returns whether the source address is allowed to send messages from the chain
+    pub fn is_peer(&self, src_addr: &UniversalAddress) -> bool {
+        self.peers.is_empty() || self.peers.contains(src_addr)
+    }
+
+    /// Returns whether messages can be sent to the destination address on the chain
+    pub fn is_destination(&self, dst_addr: &UniversalAddress, enforce_peers: bool) -> bool {
+        !enforce_peers || self.peers.contains(dst_addr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn integrator_chain_config() -> IntegratorChainConfig {
+        IntegratorChainConfig::new(255, 2, Pubkey::new_unique())
+    }
+
+    #[test]
+    fn test_is_peer() {
+        let mut config = integrator_chain_config();
+        let peer = UniversalAddress::from_bytes([1; 32]);
+        let other = UniversalAddress::from_bytes([2; 32]);
+
+        // Any source address is allowed without peers
+        assert!(config.is_peer(&peer));
+        assert!(config.is_peer(&other));
+
+        config.peers = vec![peer];
+        assert!(config.is_peer(&peer));
+        assert!(!config.is_peer(&other));
+    }
+
+    #[test]
+    fn test_is_destination() {
+        let mut config = integrator_chain_config();
+        let peer = UniversalAddress::from_bytes([1; 32]);
+        let other = UniversalAddress::from_bytes([2; 32]);
+
+        // Any destination address is allowed without enforcement
+        assert!(config.is_destination(&other, false));
+
+        // No destination address is allowed with enforcement and no peers
+        assert!(!config.is_destination(&peer, true));
+
+        config.peers = vec![peer];
+        assert!(config.is_destination(&peer, true));
+        assert!(!config.is_destination(&other, true));
+        assert!(config.is_destination(&other, false));
+    }
+
+    #[test]
+    fn test_validate_peers() {
+        let peers: Vec<UniversalAddress> = (0..=IntegratorChainConfig::MAX_PEERS as u8)
+            .map(|i| UniversalAddress::from_bytes([i; 32]))
+            .collect();
+
+        assert!(IntegratorChainConfig::validate_peers(&[]).is_ok());
+        assert!(
+            IntegratorChainConfig::validate_peers(&peers[..IntegratorChainConfig::MAX_PEERS])
+                .is_ok()
+        );
+        assert_eq!(
+            IntegratorChainConfig::validate_peers(&peers).unwrap_err(),
+            EndpointError::InvalidPeers.into()
+        );
+        assert_eq!(
+            IntegratorChainConfig::validate_peers(&[peers[0], peers[1], peers[0]]).unwrap_err(),
+            EndpointError::InvalidPeers.into()
+        );
+    }
+
+    #[test]
+    fn test_loosens_peers() {
+        let mut config = integrator_chain_config();
+        let peer = UniversalAddress::from_bytes([1; 32]);
+        let other = UniversalAddress::from_bytes([2; 32]);
+
+        // Without peers any source is allowed, so setting peers only loosens the destinations
+        assert!(!config.loosens_peers(&[], true));
+        assert!(!config.loosens_peers(&[peer], false));
+        assert!(config.loosens_peers(&[peer], true));
+
+        config.peers = vec![peer, other];
+        assert!(!config.loosens_peers(&[peer, other], true));
+        assert!(!config.loosens_peers(&[other], false));
+        assert!(!config.loosens_peers(&[other], true));
+        assert!(config.loosens_peers(&[], false));
+        assert!(config.loosens_peers(&[peer, UniversalAddress::from_bytes([3; 32])], false));
+    }
 }
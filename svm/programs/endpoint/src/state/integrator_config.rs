@@ -1,6 +1,18 @@
 use anchor_lang::prelude::*;
 
 use crate::error::EndpointError;
+use crate::utils::bitmap::Bitmap;
+
+/// A role that can be granted by the admin of an integrator for a subset of admin instructions
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    /// Manages adapters: adds, enables, disables, removes and replaces adapters, sets receive
+    /// thresholds and proposes config changes
+    Configurator,
+
+    /// Responds to incidents: disables adapters, pauses and cancels proposed config changes
+    Pauser,
+}
 
 /// Manages the configuration for a specific integrator.
 #[account]
@@ -14,17 +26,66 @@ pub struct IntegratorConfig {
     pub integrator_program_id: Pubkey,
 
     /// Admin of the IntegratorConfig account
+    /// While `admin_threshold` is non-zero, the admin signer set approves admin instructions
+    /// instead, and this only records that the admin has not been discarded
     pub admin: Option<Pubkey>,
 
+    /// Signer set of the multisig admin of the IntegratorConfig account
+    #[max_len(10)]
+    pub admin_signers: Vec<Pubkey>,
+
+    /// Number of `admin_signers` that must sign admin instructions
+    /// A value of 0 means the config is administered by the single `admin` key
+    pub admin_threshold: u8,
+
     /// Pending admin of the IntegratorConfig account
     /// If this exists, any other admin related functions will not be authorised
     /// This must be null (in other words claim_admin will need to be called) before other ixs are
     /// enabled
     pub pending_admin: Option<Pubkey>,
 
+    /// Holder of the configurator role, if granted
+    pub configurator: Option<Pubkey>,
+
+    /// Holder of the pauser role, if granted
+    pub pauser: Option<Pubkey>,
+
+    /// Number of slots after which an outbox message that has not been picked up by every send
+    /// adapter can be reclaimed by anyone. A value of 0 means outbox messages never expire
+    pub outbox_message_timeout: u64,
+
+    /// Whether sending messages is paused for every destination chain
+    pub paused_send: bool,
+
+    /// Whether receiving and executing messages is paused for every source chain
+    pub paused_recv: bool,
+
+    /// Whether `send_message` only accepts destination addresses that are peers of the
+    /// destination chain
+    pub enforce_peers: bool,
+
+    /// Number of slots between the proposal of a config change and the slot from which it can be
+    /// executed. While non-zero, adding and enabling adapters and setting receive thresholds must
+    /// go through `propose_config_change`. A value of 0 means changes take effect immediately
+    pub config_delay: u64,
+
+    /// Identifier of the next proposed config change
+    pub next_config_change_id: u64,
+
     /// Vector of registered adapter addresses
+    /// The slot of a removed adapter is set to the default pubkey until a new adapter reuses it
     #[max_len(128)]
     pub adapter_infos: Vec<Pubkey>,
+
+    /// Incremented every time the slot of an adapter is freed or migrated to a new adapter
+    pub adapter_epoch: u64,
+
+    /// The `adapter_epoch` at which each slot of `adapter_infos` was last freed or migrated, 0 if
+    /// it never was
+    /// Attestations and outbox messages recorded at an earlier epoch belong to the previous
+    /// adapter of the slot, and do not carry over to the adapter reusing it
+    #[max_len(128)]
+    pub adapter_epochs: Vec<u64>,
 }
 
 impl IntegratorConfig {
@@ -34,6 +95,9 @@ impl IntegratorConfig {
     /// Maximum number of adapters allowed
     pub const MAX_ADAPTERS: usize = 128;
 
+    /// Maximum number of signers of a multisig admin
+    pub const MAX_ADMIN_SIGNERS: usize = 10;
+
     pub fn pda(integrator_program_id: &Pubkey) -> (Pubkey, u8) {
         Pubkey::find_program_address(
             &[Self::SEED_PREFIX, integrator_program_id.as_ref()],
@@ -41,11 +105,12 @@ impl IntegratorConfig {
         )
     }
 
-    pub fn check_admin(&self, signer: &Signer) -> Result<()> {
-        require!(
-            self.admin == Some(signer.key()),
-            EndpointError::CallerNotAuthorized
-        );
+    /// Checks that an admin instruction is approved by the admin
+    ///
+    /// With a multisig admin, the `admin` signer and the signers among `remaining_accounts` are
+    /// counted towards the threshold
+    pub fn check_admin(&self, signer: &Signer, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        self.check_admin_signature(signer, remaining_accounts)?;
         require!(
             self.pending_admin.is_none(),
             EndpointError::AdminTransferInProgress
@@ -53,14 +118,411 @@ impl IntegratorConfig {
         Ok(())
     }
 
+    /// Checks that the admin signed, whether or not an admin transfer is in progress
+    pub fn check_admin_signature(
+        &self,
+        signer: &Signer,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        let signers: Vec<Pubkey> = std::iter::once(signer.key())
+            .chain(
+                remaining_accounts
+                    .iter()
+                    .filter(|account| account.is_signer)
+                    .map(|account| account.key()),
+            )
+            .collect();
+        require!(self.is_admin(&signers), EndpointError::CallerNotAuthorized);
+        Ok(())
+    }
+
+    /// Checks that an instruction is approved by the holder of one of the given roles, or by the
+    /// admin
+    pub fn check_roles(
+        &self,
+        roles: &[Role],
+        signer: &Signer,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        if roles.iter().any(|role| self.has_role(*role, &signer.key())) {
+            return Ok(());
+        }
+        self.check_admin(signer, remaining_accounts)
+    }
+
+    /// Returns the holder of a role, if granted
+    pub fn role(&self, role: Role) -> Option<Pubkey> {
+        match role {
+            Role::Configurator => self.configurator,
+            Role::Pauser => self.pauser,
+        }
+    }
+
+    /// Grants a role to an account, or revokes it with `None`
+    pub fn set_role(&mut self, role: Role, account: Option<Pubkey>) {
+        match role {
+            Role::Configurator => self.configurator = account,
+            Role::Pauser => self.pauser = account,
+        }
+    }
+
+    /// Returns whether the account holds the role
+    pub fn has_role(&self, role: Role, account: &Pubkey) -> bool {
+        self.role(role) == Some(*account)
+    }
+
+    /// Returns whether the given signers are the admin, or enough of the multisig admin signers
+    pub fn is_admin(&self, signers: &[Pubkey]) -> bool {
+        if self.admin.is_none() {
+            return false;
+        }
+
+        if self.admin_threshold == 0 {
+            return signers.first() == self.admin.as_ref();
+        }
+
+        // Each admin signer counts once, however many times it signs
+        let approvals = self
+            .admin_signers
+            .iter()
+            .filter(|admin_signer| signers.contains(admin_signer))
+            .count();
+        approvals >= usize::from(self.admin_threshold)
+    }
+
+    /// Checks that admin changes that increase trust can take effect immediately
+    pub fn check_no_config_delay(&self) -> Result<()> {
+        require!(
+            self.config_delay == 0,
+            EndpointError::ConfigChangeTimelocked
+        );
+        Ok(())
+    }
+
+    /// Validates a multisig admin signer set and threshold
+    ///
+    /// A threshold of 0 with no signers switches back to the single `admin` key
+    pub fn validate_admin_signers(signers: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            signers.len() <= Self::MAX_ADMIN_SIGNERS,
+            EndpointError::InvalidAdminSigners
+        );
+        require!(
+            usize::from(threshold) <= signers.len() && (threshold > 0 || signers.is_empty()),
+            EndpointError::InvalidAdminSigners
+        );
+        for (index, signer) in signers.iter().enumerate() {
+            require!(
+                !signers[..index].contains(signer),
+                EndpointError::InvalidAdminSigners
+            );
+        }
+        Ok(())
+    }
+
     /// The `init` constraint in the add_adapter instruction checks that the adapter has not been added. If it is,
     /// `AccountAlreadyInUse` error will be thrown
-    pub fn add_adapter(&mut self, adapter: Pubkey) -> Result<()> {
+    ///
+    /// Returns the index of the adapter, which is the first slot of a removed adapter if there is
+    /// one
+    pub fn add_adapter(&mut self, adapter: Pubkey) -> Result<u8> {
+        // The default pubkey marks the slot of a removed adapter
+        require_keys_neq!(
+            adapter,
+            Pubkey::default(),
+            EndpointError::InvalidAdapterProgram
+        );
+
+        if let Some(index) = self
+            .adapter_infos
+            .iter()
+            .position(|adapter_program_id| *adapter_program_id == Pubkey::default())
+        {
+            self.adapter_infos[index] = adapter;
+            return Ok(index as u8);
+        }
+
         require!(
             self.adapter_infos.len() < Self::MAX_ADAPTERS,
             EndpointError::MaxAdaptersReached
         );
         self.adapter_infos.push(adapter);
+        self.adapter_epochs.push(0);
+        Ok((self.adapter_infos.len() - 1) as u8)
+    }
+
+    /// Frees the slot of a removed adapter so that `add_adapter` can reuse it
+    /// The attestations and pending pick ups of the removed adapter are not carried over
+    pub fn remove_adapter(&mut self, index: u8) {
+        self.adapter_infos[usize::from(index)] = Pubkey::default();
+        self.end_adapter_epoch(index);
+    }
+
+    /// Returns the bitmap of the indices of the registered adapters, excluding removed adapters
+    pub fn registered_bitmap(&self) -> Result<Bitmap> {
+        let mut bitmap = Bitmap::new();
+        for (index, adapter_program_id) in self.adapter_infos.iter().enumerate() {
+            if *adapter_program_id != Pubkey::default() {
+                bitmap.set(index as u8, true)?;
+            }
+        }
+        Ok(bitmap)
+    }
+
+    /// Migrates the slot of an adapter to a new adapter program
+    /// The attestations and pending pick ups of the replaced adapter are not carried over
+    pub fn replace_adapter(&mut self, index: u8, adapter: Pubkey) -> Result<()> {
+        require_keys_neq!(
+            adapter,
+            Pubkey::default(),
+            EndpointError::InvalidAdapterProgram
+        );
+        self.adapter_infos[usize::from(index)] = adapter;
+        self.end_adapter_epoch(index);
         Ok(())
     }
+
+    /// Records that the adapter holding a slot is leaving it, so that its attestations and
+    /// pending pick ups are not taken over by the next adapter of the slot
+    fn end_adapter_epoch(&mut self, index: u8) {
+        self.adapter_epoch += 1;
+        self.adapter_epochs[usize::from(index)] = self.adapter_epoch;
+    }
+
+    /// Returns the bitmap of the slots that were freed or migrated after the given epoch
+    pub fn reassigned_since(&self, epoch: u64) -> Result<Bitmap> {
+        let mut bitmap = Bitmap::new();
+        for (index, adapter_epoch) in self.adapter_epochs.iter().enumerate() {
+            if *adapter_epoch > epoch {
+                bitmap.set(index as u8, true)?;
+            }
+        }
+        Ok(bitmap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::AttestationInfo;
+    use crate::CHAIN_ID;
+    use universal_address::UniversalAddress;
+
+    fn integrator_config() -> IntegratorConfig {
+        IntegratorConfig {
+            bump: 255,
+            integrator_program_id: Pubkey::new_unique(),
+            admin: Some(Pubkey::new_unique()),
+            admin_signers: Vec::new(),
+            admin_threshold: 0,
+            pending_admin: None,
+            configurator: None,
+            pauser: None,
+            outbox_message_timeout: 0,
+            paused_send: false,
+            paused_recv: false,
+            enforce_peers: false,
+            config_delay: 0,
+            next_config_change_id: 0,
+            adapter_infos: Vec::new(),
+            adapter_epoch: 0,
+            adapter_epochs: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_is_admin() {
+        let config = integrator_config();
+        let admin = config.admin.unwrap();
+        assert!(config.is_admin(&[admin]));
+        assert!(!config.is_admin(&[Pubkey::new_unique(), admin]));
+        assert!(!config.is_admin(&[]));
+    }
+
+    #[test]
+    fn test_is_admin_discarded() {
+        let mut config = integrator_config();
+        let admin = config.admin.unwrap();
+        config.admin = None;
+        assert!(!config.is_admin(&[admin]));
+    }
+
+    #[test]
+    fn test_is_admin_multisig() {
+        let mut config = integrator_config();
+        let admin = config.admin.unwrap();
+        let signers = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        config.admin_signers = signers.to_vec();
+        config.admin_threshold = 2;
+
+        assert!(config.is_admin(&[signers[0], signers[2]]));
+        assert!(config.is_admin(&[signers[2], Pubkey::new_unique(), signers[1]]));
+        assert!(!config.is_admin(&[signers[0]]));
+        assert!(!config.is_admin(&[signers[0], signers[0]]));
+        // The single admin key has no authority while the multisig is active
+        assert!(!config.is_admin(&[admin]));
+    }
+
+    #[test]
+    fn test_roles() {
+        let mut config = integrator_config();
+        let account = Pubkey::new_unique();
+        assert!(!config.has_role(Role::Configurator, &account));
+
+        config.set_role(Role::Configurator, Some(account));
+        assert!(config.has_role(Role::Configurator, &account));
+        assert!(!config.has_role(Role::Pauser, &account));
+        assert_eq!(config.role(Role::Configurator), Some(account));
+
+        config.set_role(Role::Configurator, None);
+        assert!(!config.has_role(Role::Configurator, &account));
+    }
+
+    #[test]
+    fn test_validate_admin_signers() {
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        assert!(IntegratorConfig::validate_admin_signers(&signers, 1).is_ok());
+        assert!(IntegratorConfig::validate_admin_signers(&signers, 2).is_ok());
+        assert!(IntegratorConfig::validate_admin_signers(&[], 0).is_ok());
+
+        assert!(IntegratorConfig::validate_admin_signers(&signers, 3).is_err());
+        assert!(IntegratorConfig::validate_admin_signers(&signers, 0).is_err());
+        assert!(IntegratorConfig::validate_admin_signers(&[signers[0], signers[0]], 1).is_err());
+        assert!(IntegratorConfig::validate_admin_signers(
+            &[Pubkey::new_unique(); IntegratorConfig::MAX_ADMIN_SIGNERS + 1],
+            1
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_add_adapter() {
+        let mut config = integrator_config();
+        assert_eq!(config.add_adapter(Pubkey::new_unique()).unwrap(), 0);
+        assert_eq!(config.add_adapter(Pubkey::new_unique()).unwrap(), 1);
+        assert_eq!(config.adapter_infos.len(), 2);
+    }
+
+    #[test]
+    fn test_add_adapter_max_adapters() {
+        let mut config = integrator_config();
+        for _ in 0..IntegratorConfig::MAX_ADAPTERS {
+            config.add_adapter(Pubkey::new_unique()).unwrap();
+        }
+        assert_eq!(
+            config.add_adapter(Pubkey::new_unique()).unwrap_err(),
+            EndpointError::MaxAdaptersReached.into()
+        );
+    }
+
+    #[test]
+    fn test_add_adapter_reuses_removed_slot() {
+        let mut config = integrator_config();
+        for _ in 0..IntegratorConfig::MAX_ADAPTERS {
+            config.add_adapter(Pubkey::new_unique()).unwrap();
+        }
+        config.remove_adapter(3);
+        config.remove_adapter(1);
+        assert_eq!(config.adapter_infos[1], Pubkey::default());
+
+        let adapter = Pubkey::new_unique();
+        assert_eq!(config.add_adapter(adapter).unwrap(), 1);
+        assert_eq!(config.adapter_infos[1], adapter);
+        assert_eq!(config.add_adapter(Pubkey::new_unique()).unwrap(), 3);
+        assert_eq!(config.adapter_infos.len(), IntegratorConfig::MAX_ADAPTERS);
+    }
+
+    #[test]
+    fn test_add_adapter_default_pubkey() {
+        let mut config = integrator_config();
+        assert!(config.add_adapter(Pubkey::default()).is_err());
+        assert!(config.adapter_infos.is_empty());
+    }
+
+    #[test]
+    fn test_reassigned_since() {
+        let mut config = integrator_config();
+        for _ in 0..3 {
+            config.add_adapter(Pubkey::new_unique()).unwrap();
+        }
+        assert!(config.reassigned_since(0).unwrap().is_empty());
+
+        config.remove_adapter(2);
+        config.replace_adapter(0, Pubkey::new_unique()).unwrap();
+        assert_eq!(config.adapter_epoch, 2);
+        assert_eq!(config.reassigned_since(0).unwrap().as_value(), 0b101);
+        assert_eq!(config.reassigned_since(1).unwrap().as_value(), 0b001);
+        assert!(config.reassigned_since(2).unwrap().is_empty());
+
+        // Reusing a freed slot keeps its epoch
+        config.add_adapter(Pubkey::new_unique()).unwrap();
+        assert_eq!(config.reassigned_since(1).unwrap().as_value(), 0b001);
+    }
+
+    #[test]
+    fn test_clear_stale_attestations() {
+        let mut info = AttestationInfo::new(
+            255,
+            2,
+            UniversalAddress::from_bytes([1; 32]),
+            1,
+            CHAIN_ID,
+            UniversalAddress::from_bytes([2; 32]),
+            [3; 32],
+        )
+        .unwrap();
+        let mut config = integrator_config();
+        for _ in 0..3 {
+            config.add_adapter(Pubkey::new_unique()).unwrap();
+        }
+        info.attested_adapters = Bitmap::from_value(0b111);
+        info.clear_stale_attestations(&config).unwrap();
+        assert_eq!(info.attested_adapters.as_value(), 0b111);
+
+        // The attestation of the replaced adapter no longer counts
+        config.replace_adapter(1, Pubkey::new_unique()).unwrap();
+        assert_eq!(
+            info.current_attestations(&config).unwrap().as_value(),
+            0b101
+        );
+        info.clear_stale_attestations(&config).unwrap();
+        assert_eq!(info.attested_adapters.as_value(), 0b101);
+
+        // The new adapter of the slot attests after the slot was migrated
+        info.attested_adapters.set(1, true).unwrap();
+        info.clear_stale_attestations(&config).unwrap();
+        assert_eq!(info.attested_adapters.as_value(), 0b111);
+
+        // Freeing and reusing a slot invalidates the attestation of the removed adapter
+        config.remove_adapter(0);
+        config.add_adapter(Pubkey::new_unique()).unwrap();
+        info.clear_stale_attestations(&config).unwrap();
+        assert_eq!(info.attested_adapters.as_value(), 0b110);
+    }
+
+    #[test]
+    fn test_registered_bitmap() {
+        let mut config = integrator_config();
+        assert!(config.registered_bitmap().unwrap().is_empty());
+        for _ in 0..3 {
+            config.add_adapter(Pubkey::new_unique()).unwrap();
+        }
+        assert_eq!(config.registered_bitmap().unwrap().as_value(), 0b111);
+        config.remove_adapter(1);
+        assert_eq!(config.registered_bitmap().unwrap().as_value(), 0b101);
+    }
+
+    #[test]
+    fn test_replace_adapter() {
+        let mut config = integrator_config();
+        config.add_adapter(Pubkey::new_unique()).unwrap();
+        let adapter = Pubkey::new_unique();
+        config.replace_adapter(0, adapter).unwrap();
+        assert_eq!(config.adapter_infos, vec![adapter]);
+        assert!(config.replace_adapter(0, Pubkey::default()).is_err());
+    }
 }
@@ -1,13 +1,21 @@
+mod adapter_fee_vault;
 mod adapter_info;
 mod attestation_info;
+mod endpoint_config;
 mod integrator_chain_config;
 mod integrator_config;
 mod outbox_message;
+mod pending_config_change;
+mod replay_bitmap;
 mod sequence_tracker;
 
+pub use adapter_fee_vault::*;
 pub use adapter_info::*;
 pub use attestation_info::*;
+pub use endpoint_config::*;
 pub use integrator_chain_config::*;
 pub use integrator_config::*;
 pub use outbox_message::*;
+pub use pending_config_change::*;
+pub use replay_bitmap::*;
 pub use sequence_tracker::*;
@@ -1,15 +1,31 @@
 use anchor_lang::prelude::*;
 use universal_address::UniversalAddress;
 
-use crate::utils::bitmap::Bitmap;
+use crate::utils::{
+    adapter_instructions, adapter_instructions::AdapterInstruction, bitmap::Bitmap,
+};
+
+/// The delivery fee escrowed for a send adapter
+#[derive(PartialEq, Eq, Clone, Copy, Debug, AnchorDeserialize, AnchorSerialize, InitSpace)]
+pub struct AdapterFee {
+    /// Index of the adapter with respect to the adapter_infos vector in IntegratorConfig
+    pub index: u8,
+
+    /// The fee in lamports released to the adapter when it picks up the message
+    pub amount: u64,
+}
 
 #[derive(InitSpace, Debug)]
 #[account]
 pub struct OutboxMessage {
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
     /// The sending integrator as a 32-byte universal address
     pub src_addr: UniversalAddress,
 
     /// The sequence number of the message
+    /// This is used as a seed for PDA derivation, along with the integrator program ID
     pub sequence: u64,
 
     /// The destination chain's Wormhole Chain ID
@@ -21,9 +37,140 @@ pub struct OutboxMessage {
     /// The keccak256 of an arbitrary payload (32 bytes)
     pub payload_hash: [u8; 32],
 
+    /// The bitmap of send-enabled adapters for this destination chain when the message was sent
+    pub send_adapters: Bitmap,
+
     /// The bitmap of send-enabled adapters for this destination chain that have not picked up the message
     pub outstanding_adapters: Bitmap,
 
     /// The recipient of the lamports when this account is closed
     pub refund_recipient: Pubkey,
+
+    /// The slot at which the message was sent
+    /// Used to determine when the message can be reclaimed, see `IntegratorConfig::outbox_message_timeout`
+    pub created_at: u64,
+
+    /// The `adapter_epoch` of the integrator when the message was sent
+    /// Adapters reusing a slot freed or migrated after this epoch cannot pick up the message
+    pub adapter_epoch: u64,
+
+    /// The adapter instructions provided by the integrator, sorted by adapter index
+    /// Each send adapter receives its own instruction payload on `pick_up_message`
+    #[max_len(0)]
+    pub adapter_instructions: Vec<AdapterInstruction>,
+
+    /// The delivery fees escrowed in this account for each send adapter, sorted by adapter index
+    /// Unclaimed fees are returned to `refund_recipient` when the account is closed
+    #[max_len(0)]
+    pub adapter_fees: Vec<AdapterFee>,
+}
+
+impl OutboxMessage {
+    /// Seed prefix for deriving OutboxMessage PDAs
+    pub const SEED_PREFIX: &'static [u8] = b"outbox_message";
+
+    pub fn pda(integrator_program_id: &Pubkey, sequence: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                Self::SEED_PREFIX,
+                integrator_program_id.as_ref(),
+                sequence.to_be_bytes().as_ref(),
+            ],
+            &crate::ID,
+        )
+    }
+
+    /// Returns the account space needed to store an outbox message with the given encoded
+    /// adapter instructions and number of send adapters
+    pub fn space(encoded_adapter_instructions: &[u8], send_adapters_num: usize) -> usize {
+        8 + Self::INIT_SPACE
+            + adapter_instructions::stored_size(encoded_adapter_instructions)
+            + send_adapters_num * AdapterFee::INIT_SPACE
+    }
+
+    /// Returns whether no adapter has picked up the message yet
+    pub fn is_unpicked(&self) -> bool {
+        self.outstanding_adapters == self.send_adapters
+    }
+
+    /// Clears the outstanding adapters whose slot was freed or migrated since the message was sent
+    /// Their previous adapters can no longer pick up the message, and their fees are returned to
+    /// the refund recipient when the account is closed
+    pub fn clear_reassigned_adapters(&mut self, reassigned: Bitmap) {
+        self.outstanding_adapters =
+            Bitmap::from_value(self.outstanding_adapters.as_value() & !reassigned.as_value());
+    }
+
+    /// Returns whether the message can be reclaimed at `current_slot` given the integrator's
+    /// outbox message timeout. Messages never expire if the timeout is 0
+    pub fn is_expired(&self, timeout: u64, current_slot: u64) -> bool {
+        timeout != 0 && current_slot >= self.created_at.saturating_add(timeout)
+    }
+
+    /// Returns the fee escrowed for the adapter at `index`, or 0 if there is none
+    pub fn adapter_fee(&self, index: u8) -> u64 {
+        self.adapter_fees
+            .iter()
+            .find(|fee| fee.index == index)
+            .map_or(0, |fee| fee.amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outbox_message(send_adapters: u128, created_at: u64) -> OutboxMessage {
+        OutboxMessage {
+            bump: 255,
+            src_addr: UniversalAddress::from_bytes([1; 32]),
+            sequence: 0,
+            dst_chain: 2,
+            dst_addr: UniversalAddress::from_bytes([2; 32]),
+            payload_hash: [3; 32],
+            send_adapters: Bitmap::from_value(send_adapters),
+            outstanding_adapters: Bitmap::from_value(send_adapters),
+            refund_recipient: Pubkey::default(),
+            created_at,
+            adapter_epoch: 0,
+            adapter_instructions: vec![],
+            adapter_fees: vec![],
+        }
+    }
+
+    #[test]
+    fn test_is_unpicked() {
+        let mut message = outbox_message(0b11, 0);
+        assert!(message.is_unpicked());
+
+        // Once any adapter picked up the message it is no longer unpicked
+        message.outstanding_adapters.set(0, false).unwrap();
+        assert!(!message.is_unpicked());
+    }
+
+    #[test]
+    fn test_clear_reassigned_adapters() {
+        let mut message = outbox_message(0b111, 0);
+        message.outstanding_adapters.set(0, false).unwrap();
+
+        message.clear_reassigned_adapters(Bitmap::from_value(0b1001));
+        assert_eq!(message.outstanding_adapters.as_value(), 0b110);
+        message.clear_reassigned_adapters(Bitmap::from_value(0b10));
+        assert_eq!(message.outstanding_adapters.as_value(), 0b100);
+        assert_eq!(message.send_adapters.as_value(), 0b111);
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let message = outbox_message(0b1, 100);
+
+        assert!(!message.is_expired(10, 109));
+        assert!(message.is_expired(10, 110));
+
+        // A timeout of 0 disables expiry
+        assert!(!message.is_expired(0, u64::MAX));
+
+        // The expiry slot saturates instead of overflowing
+        assert!(!message.is_expired(u64::MAX, u64::MAX - 1));
+    }
 }
@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Role;
+use crate::utils::bitmap::Bitmap;
+use universal_address::UniversalAddress;
+
+/// An admin change to the configuration of an integrator that is subject to the config delay
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub enum ConfigChange {
+    /// Registers a new adapter
+    AddAdapter { adapter_program_id: Pubkey },
+
+    /// Enables a registered adapter for sending to a chain
+    EnableSendAdapter {
+        chain_id: u16,
+        adapter_program_id: Pubkey,
+    },
+
+    /// Enables a registered adapter for receiving from a chain
+    EnableRecvAdapter {
+        chain_id: u16,
+        adapter_program_id: Pubkey,
+    },
+
+    /// Disables an adapter for sending to a chain
+    DisableSendAdapter {
+        chain_id: u16,
+        adapter_program_id: Pubkey,
+    },
+
+    /// Disables an adapter for receiving from a chain
+    DisableRecvAdapter {
+        chain_id: u16,
+        adapter_program_id: Pubkey,
+    },
+
+    /// Sets the receive threshold for a chain
+    SetRecvThreshold { chain_id: u16, threshold: u8 },
+
+    /// Sets the config delay of the integrator
+    SetConfigDelay { delay: u64 },
+
+    /// Sets the minimum attestation age for a chain
+    SetMinAttestationAge {
+        chain_id: u16,
+        min_attestation_age: u64,
+    },
+
+    /// Replaces the peers for a chain
+    SetChainPeers {
+        chain_id: u16,
+        #[max_len(8)]
+        peers: Vec<UniversalAddress>,
+    },
+
+    /// Migrates the slot of an adapter to a new adapter program
+    ReplaceAdapter {
+        adapter_program_id: Pubkey,
+        new_adapter_program_id: Pubkey,
+    },
+
+    /// Overwrites the send and receive adapters for a chain
+    SetChainAdapters {
+        chain_id: u16,
+        send_adapter_bitmap: Bitmap,
+        recv_adapter_bitmap: Bitmap,
+    },
+
+    /// Sets the outbound rate limit for a chain
+    SetOutboundRateLimit {
+        chain_id: u16,
+        limit: u64,
+        window: u64,
+    },
+
+    /// Sets the inbound rate limit and queue delay for a chain
+    SetInboundRateLimit {
+        chain_id: u16,
+        limit: u64,
+        window: u64,
+        queue_delay: u64,
+    },
+}
+
+impl ConfigChange {
+    /// The registered adapter the change applies to, if any
+    pub fn adapter_program_id(&self) -> Option<Pubkey> {
+        match *self {
+            Self::EnableSendAdapter {
+                adapter_program_id, ..
+            }
+            | Self::EnableRecvAdapter {
+                adapter_program_id, ..
+            }
+            | Self::DisableSendAdapter {
+                adapter_program_id, ..
+            }
+            | Self::DisableRecvAdapter {
+                adapter_program_id, ..
+            }
+            | Self::ReplaceAdapter {
+                adapter_program_id, ..
+            } => Some(adapter_program_id),
+            Self::AddAdapter { .. }
+            | Self::SetRecvThreshold { .. }
+            | Self::SetConfigDelay { .. }
+            | Self::SetMinAttestationAge { .. }
+            | Self::SetChainPeers { .. }
+            | Self::SetChainAdapters { .. }
+            | Self::SetOutboundRateLimit { .. }
+            | Self::SetInboundRateLimit { .. } => None,
+        }
+    }
+
+    /// The adapter the change registers, if any
+    pub fn new_adapter_program_id(&self) -> Option<Pubkey> {
+        match *self {
+            Self::AddAdapter { adapter_program_id } => Some(adapter_program_id),
+            Self::ReplaceAdapter {
+                new_adapter_program_id,
+                ..
+            } => Some(new_adapter_program_id),
+            _ => None,
+        }
+    }
+
+    /// The roles allowed to propose the change besides the admin
+    /// The config delay protects the admin from the configurator, so only the admin can change it
+    pub fn roles(&self) -> &'static [Role] {
+        match self {
+            Self::SetConfigDelay { .. } => &[],
+            _ => &[Role::Configurator],
+        }
+    }
+
+    /// The chain the change applies to, if any
+    pub fn chain_id(&self) -> Option<u16> {
+        match *self {
+            Self::EnableSendAdapter { chain_id, .. }
+            | Self::EnableRecvAdapter { chain_id, .. }
+            | Self::DisableSendAdapter { chain_id, .. }
+            | Self::DisableRecvAdapter { chain_id, .. }
+            | Self::SetRecvThreshold { chain_id, .. }
+            | Self::SetMinAttestationAge { chain_id, .. }
+            | Self::SetChainPeers { chain_id, .. }
+            | Self::SetChainAdapters { chain_id, .. }
+            | Self::SetOutboundRateLimit { chain_id, .. }
+            | Self::SetInboundRateLimit { chain_id, .. } => Some(chain_id),
+            Self::AddAdapter { .. } | Self::SetConfigDelay { .. } | Self::ReplaceAdapter { .. } => {
+                None
+            }
+        }
+    }
+}
+
+/// A config change proposed by the admin of an integrator, which anyone can execute once the
+/// config delay has elapsed
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct PendingConfigChange {
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// The program ID of the integrator
+    /// This is used as a seed for PDA derivation
+    pub integrator_program_id: Pubkey,
+
+    /// Identifier of the change, unique per integrator
+    /// This is used as a seed for PDA derivation
+    pub id: u64,
+
+    /// The proposed change
+    pub change: ConfigChange,
+
+    /// The slot from which the change can be executed
+    pub eta: u64,
+
+    /// The account that paid the rent of this account, refunded when the change is executed or
+    /// cancelled
+    pub payer: Pubkey,
+}
+
+impl PendingConfigChange {
+    /// Seed prefix for deriving PendingConfigChange PDAs
+    pub const SEED_PREFIX: &'static [u8] = b"pending_config_change";
+
+    pub fn pda(integrator_program_id: &Pubkey, id: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                Self::SEED_PREFIX,
+                integrator_program_id.as_ref(),
+                id.to_be_bytes().as_ref(),
+            ],
+            &crate::ID,
+        )
+    }
+}
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use universal_address::UniversalAddress;
+
+/// Compact replay protection for executed messages, kept after their `AttestationInfo` account
+/// has been closed.
+///
+/// Each account tracks the executed flag of `SEQUENCES_PER_ACCOUNT` consecutive sequences sent by
+/// a single source address on a source chain to an integrator.
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct ReplayBitmap {
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// The program ID of the receiving integrator
+    /// This is used as a seed for PDA derivation
+    pub integrator_program_id: Pubkey,
+
+    /// The source chain of the messages
+    /// This is used as a seed for PDA derivation
+    pub src_chain: u16,
+
+    /// The source address of the messages
+    /// This is used as a seed for PDA derivation
+    pub src_addr: UniversalAddress,
+
+    /// The index of this account, i.e. the sequence of the messages divided by `SEQUENCES_PER_ACCOUNT`
+    /// This is used as a seed for PDA derivation
+    pub index: u64,
+
+    /// One bit per sequence, set once the message with that sequence has been executed
+    pub executed: [u8; 128],
+}
+
+impl ReplayBitmap {
+    /// Seed prefix for deriving ReplayBitmap PDAs
+    pub const SEED_PREFIX: &'static [u8] = b"replay_bitmap";
+
+    /// Number of sequences tracked by a single account
+    pub const SEQUENCES_PER_ACCOUNT: u64 = 128 * 8;
+
+    pub fn new(
+        bump: u8,
+        integrator_program_id: Pubkey,
+        src_chain: u16,
+        src_addr: UniversalAddress,
+        sequence: u64,
+    ) -> Self {
+        Self {
+            bump,
+            integrator_program_id,
+            src_chain,
+            src_addr,
+            index: Self::index_of(sequence),
+            executed: [0; 128],
+        }
+    }
+
+    pub fn pda(
+        integrator_program_id: &Pubkey,
+        src_chain: u16,
+        src_addr: &UniversalAddress,
+        sequence: u64,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                Self::SEED_PREFIX,
+                integrator_program_id.as_ref(),
+                src_chain.to_be_bytes().as_ref(),
+                src_addr.to_bytes().as_ref(),
+                Self::index_of(sequence).to_be_bytes().as_ref(),
+            ],
+            &crate::ID,
+        )
+    }
+
+    /// Returns the index of the account tracking `sequence`
+    pub fn index_of(sequence: u64) -> u64 {
+        sequence / Self::SEQUENCES_PER_ACCOUNT
+    }
+
+    /// Returns the byte offset and mask of `sequence` within the `executed` bitmap
+    fn position(sequence: u64) -> (usize, u8) {
+        let bit = sequence % Self::SEQUENCES_PER_ACCOUNT;
+        ((bit / 8) as usize, 1 << (bit % 8))
+    }
+
+    pub fn is_executed(&self, sequence: u64) -> bool {
+        let (byte, mask) = Self::position(sequence);
+        self.executed[byte] & mask != 0
+    }
+
+    pub fn set_executed(&mut self, sequence: u64) {
+        let (byte, mask) = Self::position(sequence);
+        self.executed[byte] |= mask;
+    }
+
+    /// Returns whether `sequence` is marked as executed in a possibly uninitialized replay bitmap
+    /// account. The account address must already have been checked against the expected PDA
+    pub fn is_executed_in(account: &AccountInfo, sequence: u64) -> Result<bool> {
+        if account.owner != &crate::ID {
+            return Ok(false);
+        }
+        let replay_bitmap = Self::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        Ok(replay_bitmap.is_executed(sequence))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replay_bitmap() -> ReplayBitmap {
+        ReplayBitmap {
+            bump: 255,
+            integrator_program_id: Pubkey::default(),
+            src_chain: 2,
+            src_addr: UniversalAddress::from_bytes([1; 32]),
+            index: 0,
+            executed: [0; 128],
+        }
+    }
+
+    #[test]
+    fn test_set_executed() {
+        let mut bitmap = replay_bitmap();
+
+        for sequence in [0, 7, 8, 1023] {
+            assert!(!bitmap.is_executed(sequence));
+            bitmap.set_executed(sequence);
+            assert!(bitmap.is_executed(sequence));
+        }
+
+        // Neighbouring sequences are unaffected
+        assert!(!bitmap.is_executed(1));
+        assert!(!bitmap.is_executed(9));
+        assert!(!bitmap.is_executed(1022));
+    }
+
+    #[test]
+    fn test_sequences_wrap_per_account() {
+        let mut bitmap = replay_bitmap();
+        bitmap.index = 1;
+
+        // Sequence 1024 is the first sequence tracked by the account at index 1
+        bitmap.set_executed(ReplayBitmap::SEQUENCES_PER_ACCOUNT);
+        assert!(bitmap.is_executed(ReplayBitmap::SEQUENCES_PER_ACCOUNT));
+        assert_eq!(bitmap.executed[0], 1);
+    }
+
+    #[test]
+    fn test_new() {
+        let bitmap = ReplayBitmap::new(
+            255,
+            Pubkey::default(),
+            2,
+            UniversalAddress::from_bytes([1; 32]),
+            2050,
+        );
+
+        assert_eq!(bitmap.index, 2);
+        assert_eq!(bitmap.executed, [0; 128]);
+    }
+
+    #[test]
+    fn test_index_of() {
+        assert_eq!(ReplayBitmap::index_of(0), 0);
+        assert_eq!(ReplayBitmap::index_of(1023), 0);
+        assert_eq!(ReplayBitmap::index_of(1024), 1);
+        assert_eq!(ReplayBitmap::index_of(u64::MAX), u64::MAX / 1024);
+    }
+}
@@ -0,0 +1,273 @@
+// Rust port of the EVM `AdapterInstructions` library so both implementations share the same wire format
+// Link: evm/src/libraries/AdapterInstructions.sol
+use crate::error::EndpointError;
+use anchor_lang::prelude::*;
+use std::result::Result as StdResult;
+
+/// Variable-length adapter-specific instruction that can be passed by the integrator to the endpoint
+/// and by the endpoint to the adapter.
+/// The index field refers to the index of the adapter that this instruction should be passed to.
+///
+/// The serialization format is:
+/// - index - 1 byte
+/// - payloadLength - 2 bytes (big endian)
+/// - payload - `payloadLength` bytes
+#[derive(PartialEq, Eq, Clone, Debug, AnchorDeserialize, AnchorSerialize, InitSpace)]
+pub struct AdapterInstruction {
+    /// Index of the adapter with respect to the adapter_infos vector in IntegratorConfig
+    pub index: u8,
+
+    /// The adapter specific payload
+    /// The actual length is accounted for when the owning account is allocated
+    #[max_len(0)]
+    pub payload: Vec<u8>,
+}
+
+impl AdapterInstruction {
+    /// Encodes an adapter instruction.
+    pub fn encode(&self) -> StdResult<Vec<u8>, EndpointError> {
+        let payload_length =
+            u16::try_from(self.payload.len()).map_err(|_| EndpointError::PayloadTooLong)?;
+
+        let mut encoded = Vec::with_capacity(3 + self.payload.len());
+        encoded.push(self.index);
+        encoded.extend_from_slice(&payload_length.to_be_bytes());
+        encoded.extend_from_slice(&self.payload);
+        Ok(encoded)
+    }
+
+    /// Parses a byte array into an adapter instruction.
+    pub fn parse(encoded: &[u8]) -> StdResult<Self, EndpointError> {
+        let (instruction, offset) = Self::parse_unchecked(encoded, 0)?;
+        if offset != encoded.len() {
+            return Err(EndpointError::InvalidAdapterInstructions);
+        }
+        Ok(instruction)
+    }
+
+    /// Parses an adapter instruction starting at `offset` without checking for leftover bytes.
+    /// Returns the parsed instruction along with the offset of the next unread byte.
+    fn parse_unchecked(encoded: &[u8], offset: usize) -> StdResult<(Self, usize), EndpointError> {
+        let header = encoded
+            .get(offset..offset + 3)
+            .ok_or(EndpointError::InvalidAdapterInstructions)?;
+        let index = header[0];
+        let payload_length = usize::from(u16::from_be_bytes([header[1], header[2]]));
+
+        let start = offset + 3;
+        let payload = encoded
+            .get(start..start + payload_length)
+            .ok_or(EndpointError::InvalidAdapterInstructions)?
+            .to_vec();
+
+        Ok((Self { index, payload }, start + payload_length))
+    }
+}
+
+/// Encodes an array of adapter instructions, prefixed by the number of entries (1 byte).
+pub fn encode_instructions(
+    instructions: &[AdapterInstruction],
+) -> StdResult<Vec<u8>, EndpointError> {
+    let instructions_length =
+        u8::try_from(instructions.len()).map_err(|_| EndpointError::TooManyInstructions)?;
+
+    let mut encoded = vec![instructions_length];
+    for instruction in instructions {
+        encoded.extend(instruction.encode()?);
+    }
+    Ok(encoded)
+}
+
+/// Parses a byte array into a list of adapter instructions.
+///
+/// An empty byte array is valid and results in no instructions. Every instruction index must be
+/// lower than `num_registered_adapters`. If the same index is provided more than once, the last
+/// instruction wins, matching the sparse array semantics of the EVM implementation.
+/// The returned list is sorted by adapter index.
+pub fn parse_instructions(
+    encoded: &[u8],
+    num_registered_adapters: usize,
+) -> StdResult<Vec<AdapterInstruction>, EndpointError> {
+    let mut instructions: Vec<AdapterInstruction> = Vec::new();
+
+    if encoded.is_empty() {
+        return Ok(instructions);
+    }
+
+    let instructions_length = encoded[0];
+    let mut offset = 1;
+
+    for _ in 0..instructions_length {
+        let (instruction, next_offset) = AdapterInstruction::parse_unchecked(encoded, offset)?;
+        offset = next_offset;
+
+        // Instruction index is out of bounds
+        if usize::from(instruction.index) >= num_registered_adapters {
+            return Err(EndpointError::InvalidInstructionIndex);
+        }
+
+        match instructions.binary_search_by_key(&instruction.index, |i| i.index) {
+            Ok(position) => instructions[position] = instruction,
+            Err(position) => instructions.insert(position, instruction),
+        }
+    }
+
+    if offset != encoded.len() {
+        return Err(EndpointError::InvalidAdapterInstructions);
+    }
+
+    Ok(instructions)
+}
+
+/// Returns the number of bytes needed to store the instructions parsed from `encoded` as a
+/// `Vec<AdapterInstruction>`, excluding the 4 byte length prefix of the vector itself.
+///
+/// This is an upper bound: duplicate indices are only stored once.
+/// Malformed input still yields a size, parsing is expected to reject it afterwards.
+pub fn stored_size(encoded: &[u8]) -> usize {
+    match encoded.split_first() {
+        // Each entry is stored as index (1 byte) + vector length (4 bytes) + payload
+        // whereas it is encoded as index (1 byte) + payload length (2 bytes) + payload
+        Some((instructions_length, rest)) => rest.len() + 2 * usize::from(*instructions_length),
+        None => 0,
+    }
+}
+
+/// Returns the payload of the instruction for the adapter at `index`, or an empty payload if
+/// there is none.
+pub fn payload_for(instructions: &[AdapterInstruction], index: u8) -> Vec<u8> {
+    instructions
+        .binary_search_by_key(&index, |i| i.index)
+        .map(|position| instructions[position].payload.clone())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instruction(index: u8, payload: &[u8]) -> AdapterInstruction {
+        AdapterInstruction {
+            index,
+            payload: payload.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_encode_instruction() {
+        let encoded = instruction(2, &[0xaa, 0xbb]).encode().unwrap();
+        assert_eq!(encoded, vec![0x02, 0x00, 0x02, 0xaa, 0xbb]);
+    }
+
+    #[test]
+    fn test_encode_instruction_payload_too_long() {
+        let too_long = instruction(0, &vec![0; usize::from(u16::MAX) + 1]);
+        assert_eq!(too_long.encode(), Err(EndpointError::PayloadTooLong));
+    }
+
+    #[test]
+    fn test_encode_too_many_instructions() {
+        let instructions = vec![instruction(0, &[]); 256];
+        assert_eq!(
+            encode_instructions(&instructions),
+            Err(EndpointError::TooManyInstructions)
+        );
+    }
+
+    #[test]
+    fn test_parse_instruction_round_trip() {
+        let original = instruction(7, &[1, 2, 3]);
+        let encoded = original.encode().unwrap();
+        assert_eq!(AdapterInstruction::parse(&encoded).unwrap(), original);
+    }
+
+    #[test]
+    fn test_parse_instruction_trailing_bytes() {
+        let mut encoded = instruction(7, &[1, 2, 3]).encode().unwrap();
+        encoded.push(0);
+        assert_eq!(
+            AdapterInstruction::parse(&encoded),
+            Err(EndpointError::InvalidAdapterInstructions)
+        );
+    }
+
+    #[test]
+    fn test_parse_instructions_round_trip() {
+        let instructions = vec![
+            instruction(0, &[1]),
+            instruction(3, &[]),
+            instruction(1, &[2, 2]),
+        ];
+        let encoded = encode_instructions(&instructions).unwrap();
+        let parsed = parse_instructions(&encoded, 4).unwrap();
+
+        // The parsed instructions are sorted by index
+        assert_eq!(
+            parsed,
+            vec![
+                instruction(0, &[1]),
+                instruction(1, &[2, 2]),
+                instruction(3, &[])
+            ]
+        );
+        assert_eq!(payload_for(&parsed, 1), vec![2, 2]);
+        assert_eq!(payload_for(&parsed, 2), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_parse_instructions_empty() {
+        assert!(parse_instructions(&[], 0).unwrap().is_empty());
+        assert!(parse_instructions(&[0], 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_instructions_duplicate_index_last_wins() {
+        let instructions = vec![instruction(1, &[1]), instruction(1, &[2])];
+        let encoded = encode_instructions(&instructions).unwrap();
+        assert_eq!(
+            parse_instructions(&encoded, 2).unwrap(),
+            vec![instruction(1, &[2])]
+        );
+    }
+
+    #[test]
+    fn test_parse_instructions_index_out_of_bounds() {
+        let encoded = encode_instructions(&[instruction(2, &[])]).unwrap();
+        assert_eq!(
+            parse_instructions(&encoded, 2),
+            Err(EndpointError::InvalidInstructionIndex)
+        );
+    }
+
+    #[test]
+    fn test_parse_instructions_truncated() {
+        let mut encoded = encode_instructions(&[instruction(0, &[1, 2, 3])]).unwrap();
+        encoded.pop();
+        assert_eq!(
+            parse_instructions(&encoded, 1),
+            Err(EndpointError::InvalidAdapterInstructions)
+        );
+    }
+
+    #[test]
+    fn test_parse_instructions_trailing_bytes() {
+        let mut encoded = encode_instructions(&[instruction(0, &[1])]).unwrap();
+        encoded.push(0);
+        assert_eq!(
+            parse_instructions(&encoded, 1),
+            Err(EndpointError::InvalidAdapterInstructions)
+        );
+    }
+
+    #[test]
+    fn test_stored_size() {
+        let instructions = vec![instruction(0, &[1, 2]), instruction(1, &[3])];
+        let encoded = encode_instructions(&instructions).unwrap();
+        let parsed = parse_instructions(&encoded, 2).unwrap();
+        let serialized = parsed.try_to_vec().unwrap();
+
+        // `serialized` includes the 4 byte length prefix of the vector
+        assert_eq!(stored_size(&encoded), serialized.len() - 4);
+        assert_eq!(stored_size(&[]), 0);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::error::EndpointError;
+use crate::state::{IntegratorChainConfig, IntegratorConfig};
+use crate::utils::adapter_instructions::{self, AdapterInstruction};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::Instruction,
+    program::{get_return_data, invoke},
+};
+
+/// Interface that send adapters must implement so the endpoint can query them.
+///
+/// Adapters expose an instruction named `quote_delivery_price` that takes no accounts and an
+/// `AdapterQuoteArgs` struct as argument, and returns the delivery price in lamports as a
+/// borsh-serialized `u64` via `set_return_data`. An Anchor adapter gets this for free by declaring
+/// `pub fn quote_delivery_price(ctx: Context<QuoteDeliveryPrice>, args: AdapterQuoteArgs) -> Result<u64>`.
+pub const QUOTE_DELIVERY_PRICE_DISCRIMINATOR: [u8; 8] = [186, 179, 94, 14, 27, 143, 233, 14];
+
+/// Arguments passed by the endpoint to an adapter's `quote_delivery_price` instruction
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AdapterQuoteArgs {
+    /// The Wormhole chain ID of the target chain
+    pub recipient_chain: u16,
+
+    /// The instructions specific to this adapter. May be empty
+    pub instructions: Vec<u8>,
+}
+
+/// Fetches the delivery price from a single adapter program via CPI
+pub fn quote_delivery_price(
+    adapter_program: &AccountInfo,
+    recipient_chain: u16,
+    instructions: Vec<u8>,
+) -> Result<u64> {
+    let mut data = QUOTE_DELIVERY_PRICE_DISCRIMINATOR.to_vec();
+    data.extend(
+        AdapterQuoteArgs {
+            recipient_chain,
+            instructions,
+        }
+        .try_to_vec()?,
+    );
+
+    invoke(
+        &Instruction {
+            program_id: adapter_program.key(),
+            accounts: vec![],
+            data,
+        },
+        &[adapter_program.clone()],
+    )?;
+
+    // Make sure the return data was set by the adapter itself
+    match get_return_data() {
+        Some((program_id, return_data)) if program_id == adapter_program.key() => {
+            u64::try_from_slice(&return_data)
+                .map_err(|_| EndpointError::InvalidDeliveryPriceQuote.into())
+        }
+        _ => err!(EndpointError::InvalidDeliveryPriceQuote),
+    }
+}
+
+/// Fetches the delivery price of every send-enabled adapter of an integrator for a chain.
+///
+/// `adapter_programs` must contain the program account of each send-enabled adapter, ordered by
+/// adapter index. Returns the `(adapter index, delivery price)` of each send-enabled adapter.
+pub fn quote_send_adapters(
+    integrator_config: &IntegratorConfig,
+    integrator_chain_config: &IntegratorChainConfig,
+    adapter_programs: &[AccountInfo],
+    instructions: &[AdapterInstruction],
+) -> Result<Vec<(u8, u64)>> {
+    let mut adapter_programs = adapter_programs.iter();
+    let mut quotes = Vec::new();
+
+    for (index, adapter_program_id) in integrator_config.adapter_infos.iter().enumerate() {
+        let index = index as u8;
+        if !integrator_chain_config.send_adapter_bitmap.get(index)? {
+            continue;
+        }
+
+        let adapter_program = adapter_programs
+            .next()
+            .ok_or(EndpointError::InvalidAdapterProgram)?;
+        require_keys_eq!(
+            adapter_program.key(),
+            *adapter_program_id,
+            EndpointError::InvalidAdapterProgram
+        );
+
+        let price = quote_delivery_price(
+            adapter_program,
+            integrator_chain_config.chain_id,
+            adapter_instructions::payload_for(instructions, index),
+        )?;
+        quotes.push((index, price));
+    }
+
+    Ok(quotes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::hash::hash;
+
+    #[test]
+    fn test_quote_delivery_price_discriminator() {
+        let sighash = hash(b"global:quote_delivery_price");
+        assert_eq!(QUOTE_DELIVERY_PRICE_DISCRIMINATOR, sighash.to_bytes()[..8]);
+    }
+}
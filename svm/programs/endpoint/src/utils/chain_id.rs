@@ -0,0 +1,59 @@
+/// Parses a chain ID at compile time, e.g. from the `ENDPOINT_CHAIN_ID` environment variable
+///
+/// Panics, and thus fails the build when used in a constant, if the value is empty, contains
+/// anything other than decimal digits, is 0 or does not fit in a `u16`.
+pub const fn parse_chain_id(value: &str) -> u16 {
+    let bytes = value.as_bytes();
+    assert!(!bytes.is_empty(), "chain ID must not be empty");
+
+    let mut chain_id: u32 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        assert!(byte.is_ascii_digit(), "chain ID must be a decimal number");
+
+        chain_id = chain_id * 10 + (byte - b'0') as u32;
+        assert!(chain_id <= u16::MAX as u32, "chain ID must fit in a u16");
+        i += 1;
+    }
+
+    assert!(chain_id != 0, "chain ID must not be 0");
+    chain_id as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_chain_id() {
+        assert_eq!(parse_chain_id("1"), 1);
+        assert_eq!(parse_chain_id("26"), 26);
+        assert_eq!(parse_chain_id("0051"), 51);
+        assert_eq!(parse_chain_id("65535"), u16::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "chain ID must not be empty")]
+    fn test_parse_chain_id_empty() {
+        parse_chain_id("");
+    }
+
+    #[test]
+    #[should_panic(expected = "chain ID must be a decimal number")]
+    fn test_parse_chain_id_not_a_number() {
+        parse_chain_id("0x1a");
+    }
+
+    #[test]
+    #[should_panic(expected = "chain ID must fit in a u16")]
+    fn test_parse_chain_id_overflow() {
+        parse_chain_id("65536");
+    }
+
+    #[test]
+    #[should_panic(expected = "chain ID must not be 0")]
+    fn test_parse_chain_id_zero() {
+        parse_chain_id("0");
+    }
+}
@@ -1 +1,5 @@
+pub mod adapter_instructions;
+pub mod adapter_interface;
 pub mod bitmap;
+pub mod chain_id;
+pub mod rate_limit;
@@ -0,0 +1,218 @@
+use crate::error::EndpointError;
+use anchor_lang::prelude::*;
+
+/// Token bucket limiting the number of messages per window of slots.
+///
+/// The bucket holds up to `limit` messages and refills linearly over `window` slots, so a full
+/// bucket is available again `window` slots after it has been emptied.
+#[derive(
+    PartialEq, Eq, Clone, Copy, Debug, Default, AnchorDeserialize, AnchorSerialize, InitSpace,
+)]
+pub struct RateLimit {
+    /// Maximum number of messages per window. A value of 0 disables the rate limit
+    pub limit: u64,
+
+    /// Number of slots over which an empty bucket refills to `limit`
+    pub window: u64,
+
+    /// Number of messages available as of `last_refill_slot`
+    pub capacity: u64,
+
+    /// Slot at which `capacity` was last updated
+    pub last_refill_slot: u64,
+
+    /// Refill accrued as of `last_refill_slot` that does not make up a whole message yet, in
+    /// units of `1 / window` message
+    pub refill_remainder: u64,
+}
+
+impl RateLimit {
+    pub fn is_enabled(&self) -> bool {
+        self.limit > 0
+    }
+
+    /// Returns the number of messages available at the given slot, `u64::MAX` if disabled
+    pub fn capacity_at(&self, slot: u64) -> u64 {
+        if !self.is_enabled() {
+            return u64::MAX;
+        }
+        self.refill_at(slot).0
+    }
+
+    /// Returns the capacity and the refill remainder at the given slot
+    /// The remainder is carried over so that frequent updates do not drop the partial refill
+    fn refill_at(&self, slot: u64) -> (u64, u64) {
+        let elapsed = slot.saturating_sub(self.last_refill_slot);
+        let accrued =
+            u128::from(elapsed) * u128::from(self.limit) + u128::from(self.refill_remainder);
+        let window = u128::from(self.window);
+        let capacity = u128::from(self.capacity).saturating_add(accrued / window);
+        if capacity >= u128::from(self.limit) {
+            // A full bucket does not accrue any further refill
+            return (self.limit, 0);
+        }
+        // Bounded by `limit` and `window`, so these fit in a u64
+        (capacity as u64, (accrued % window) as u64)
+    }
+
+    /// Consumes one message from the bucket at the given slot, returning whether it was available
+    pub fn try_consume(&mut self, slot: u64) -> bool {
+        if !self.is_enabled() {
+            return true;
+        }
+        let (capacity, refill_remainder) = self.refill_at(slot);
+        if capacity == 0 {
+            return false;
+        }
+        self.capacity = capacity - 1;
+        self.last_refill_slot = slot;
+        self.refill_remainder = refill_remainder;
+        true
+    }
+
+    /// Consumes one message from the bucket at the given slot
+    pub fn consume(&mut self, slot: u64) -> Result<()> {
+        require!(self.try_consume(slot), EndpointError::RateLimitExceeded);
+        Ok(())
+    }
+
+    /// Returns whether updating to the given limit and window lets more messages through
+    ///
+    /// Disabling the rate limit, raising the limit or shortening the window loosens it, while
+    /// enabling it never does
+    pub fn is_loosened_by(&self, limit: u64, window: u64) -> bool {
+        self.is_enabled() && (limit == 0 || limit > self.limit || window < self.window)
+    }
+
+    /// Updates the limit and window at the given slot
+    ///
+    /// The available capacity moves by the change of the limit, so messages already sent in the
+    /// current window still count against the new limit. Enabling the rate limit starts with a
+    /// full bucket.
+    pub fn set_limit(&mut self, limit: u64, window: u64, slot: u64) -> Result<()> {
+        require!(limit == 0 || window > 0, EndpointError::InvalidRateLimit);
+        let capacity = if self.is_enabled() {
+            let capacity = self.capacity_at(slot);
+            if limit > self.limit {
+                capacity.saturating_add(limit - self.limit)
+            } else {
+                capacity.saturating_sub(self.limit - limit)
+            }
+        } else {
+            limit
+        };
+        *self = Self {
+            limit,
+            window,
+            capacity: capacity.min(limit),
+            last_refill_slot: slot,
+            refill_remainder: 0,
+        };
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limit() {
+        let mut rate_limit = RateLimit::default();
+        assert!(!rate_limit.is_enabled());
+        assert_eq!(rate_limit.capacity_at(0), u64::MAX);
+        rate_limit.consume(0).unwrap();
+
+        rate_limit.set_limit(2, 100, 10).unwrap();
+        assert_eq!(rate_limit.capacity_at(10), 2);
+        rate_limit.consume(10).unwrap();
+        rate_limit.consume(10).unwrap();
+        assert_eq!(
+            rate_limit.consume(10).unwrap_err(),
+            EndpointError::RateLimitExceeded.into()
+        );
+
+        // Refills linearly over the window
+        assert_eq!(rate_limit.capacity_at(59), 0);
+        assert_eq!(rate_limit.capacity_at(60), 1);
+        assert_eq!(rate_limit.capacity_at(110), 2);
+        assert_eq!(rate_limit.capacity_at(1_000), 2);
+
+        rate_limit.consume(60).unwrap();
+        assert_eq!(rate_limit.capacity_at(60), 0);
+        assert!(!rate_limit.try_consume(60));
+        assert!(rate_limit.try_consume(110));
+    }
+
+    #[test]
+    fn test_rate_limit_set_limit() {
+        let mut rate_limit = RateLimit::default();
+        assert_eq!(
+            rate_limit.set_limit(1, 0, 0).unwrap_err(),
+            EndpointError::InvalidRateLimit.into()
+        );
+
+        rate_limit.set_limit(3, 100, 0).unwrap();
+        rate_limit.consume(0).unwrap();
+        rate_limit.consume(0).unwrap();
+
+        // Messages sent in the current window count against the new limit
+        rate_limit.set_limit(5, 100, 0).unwrap();
+        assert_eq!(rate_limit.capacity_at(0), 3);
+        rate_limit.set_limit(2, 100, 0).unwrap();
+        assert_eq!(rate_limit.capacity_at(0), 0);
+
+        // Disabling and enabling again starts with a full bucket
+        rate_limit.set_limit(0, 0, 0).unwrap();
+        assert_eq!(rate_limit.capacity_at(0), u64::MAX);
+        rate_limit.set_limit(2, 100, 0).unwrap();
+        assert_eq!(rate_limit.capacity_at(0), 2);
+    }
+
+    #[test]
+    fn test_rate_limit_is_loosened_by() {
+        let mut rate_limit = RateLimit::default();
+        assert!(!rate_limit.is_loosened_by(2, 100));
+        assert!(!rate_limit.is_loosened_by(0, 0));
+
+        rate_limit.set_limit(2, 100, 0).unwrap();
+        assert!(!rate_limit.is_loosened_by(2, 100));
+        assert!(!rate_limit.is_loosened_by(1, 100));
+        assert!(!rate_limit.is_loosened_by(2, 200));
+        assert!(rate_limit.is_loosened_by(3, 100));
+        assert!(rate_limit.is_loosened_by(2, 50));
+        assert!(rate_limit.is_loosened_by(0, 0));
+    }
+
+    #[test]
+    fn test_rate_limit_keeps_partial_refill() {
+        // One message refills every 10 slots
+        let mut rate_limit = RateLimit::default();
+        rate_limit.set_limit(10, 100, 0).unwrap();
+        for _ in 0..10 {
+            rate_limit.consume(0).unwrap();
+        }
+
+        // Messages consumed more often than one refills still let the bucket refill
+        let mut consumed = 0;
+        for slot in (3..=300).step_by(3) {
+            if rate_limit.try_consume(slot) {
+                consumed += 1;
+            }
+        }
+        assert_eq!(consumed, 30);
+        assert_eq!(rate_limit.capacity_at(300), 0);
+        assert_eq!(rate_limit.capacity_at(310), 1);
+
+        // The window does not need to be a multiple of the limit
+        let mut rate_limit = RateLimit::default();
+        rate_limit.set_limit(3, 10, 300).unwrap();
+        for _ in 0..3 {
+            rate_limit.consume(300).unwrap();
+        }
+        let consumed = (301..=400)
+            .filter(|slot| rate_limit.try_consume(*slot))
+            .count();
+        assert_eq!(consumed, 30);
+    }
+}
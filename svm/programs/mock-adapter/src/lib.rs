@@ -1,13 +1,17 @@
 use anchor_lang::prelude::*;
-use endpoint::cpi::accounts::{AttestMessage, PickUpMessage};
-use endpoint::instructions::{AttestMessageArgs, PickUpMessageArgs};
+use endpoint::cpi::accounts::{AttestMessage, ClaimAdapterFees, PickUpMessage};
+use endpoint::instructions::{AttestMessageArgs, ClaimAdapterFeesArgs, PickUpMessageArgs};
 use endpoint::program::Endpoint;
+use endpoint::utils::adapter_interface::AdapterQuoteArgs;
 use endpoint::{self};
 use universal_address::UniversalAddress;
 
 // Declare the program ID for the mock adapter
 declare_id!("5k8XySmYJ6nQTF8ZFZtRoevjCx9Y9PS5MT9oJDLNA162");
 
+/// The flat delivery price, in lamports, quoted by the mock adapter
+pub const DELIVERY_PRICE: u64 = 1_000;
+
 #[program]
 pub mod mock_adapter {
 
@@ -17,13 +21,14 @@ pub mod mock_adapter {
     ///
     /// This function demonstrates how an adapter program would interact
     /// with the endpoint program to pick up a message from the outbox.
-    pub fn invoke_pick_up_message(ctx: Context<InvokePickUpMessage>) -> Result<()> {
+    /// The adapter instructions provided by the integrator are returned to the caller.
+    pub fn invoke_pick_up_message(ctx: Context<InvokePickUpMessage>) -> Result<Vec<u8>> {
         // Prepare the seeds for PDA signing
         let bump_seed = &[ctx.bumps.adapter_pda][..];
         let signer_seeds: &[&[&[u8]]] = &[&[b"adapter_pda", bump_seed]];
 
         // Perform the CPI call to the endpoint program's pick_up_message instruction
-        endpoint::cpi::pick_up_message(
+        let adapter_instructions = endpoint::cpi::pick_up_message(
             ctx.accounts
                 .invoke_pick_up_message()
                 .with_signer(signer_seeds),
@@ -31,9 +36,40 @@ pub mod mock_adapter {
                 adapter_program_id: crate::id(),
                 adapter_pda_bump: ctx.bumps.adapter_pda,
             },
-        )?;
+        )?
+        .get();
 
-        Ok(())
+        Ok(adapter_instructions)
+    }
+
+    /// Invokes the claim_adapter_fees instruction on the endpoint program via CPI
+    ///
+    /// The delivery fees released to the adapter are transferred to the recipient
+    pub fn invoke_claim_adapter_fees(ctx: Context<InvokeClaimAdapterFees>) -> Result<()> {
+        // Prepare the seeds for PDA signing
+        let bump_seed = &[ctx.bumps.adapter_pda][..];
+        let signer_seeds: &[&[&[u8]]] = &[&[b"adapter_pda", bump_seed]];
+
+        // Perform the CPI call to the endpoint program's claim_adapter_fees instruction
+        endpoint::cpi::claim_adapter_fees(
+            ctx.accounts
+                .invoke_claim_adapter_fees()
+                .with_signer(signer_seeds),
+            ClaimAdapterFeesArgs {
+                adapter_program_id: crate::id(),
+                adapter_pda_bump: ctx.bumps.adapter_pda,
+            },
+        )
+    }
+
+    /// Quotes the delivery price of a message, as required by the endpoint adapter interface
+    ///
+    /// The mock adapter charges a flat price plus one lamport per byte of adapter instructions
+    pub fn quote_delivery_price(
+        _ctx: Context<QuoteDeliveryPrice>,
+        args: AdapterQuoteArgs,
+    ) -> Result<u64> {
+        Ok(DELIVERY_PRICE + args.instructions.len() as u64)
     }
 
     /// Invokes the attest_message instruction on the endpoint program via CPI
@@ -66,6 +102,11 @@ pub mod mock_adapter {
     }
 }
 
+/// Accounts struct for the quote_delivery_price instruction
+/// The endpoint invokes this instruction without any accounts
+#[derive(Accounts)]
+pub struct QuoteDeliveryPrice {}
+
 /// Accounts struct for the invoke_pick_up_message instruction
 #[derive(Accounts)]
 pub struct InvokePickUpMessage<'info> {
@@ -78,6 +119,10 @@ pub struct InvokePickUpMessage<'info> {
     /// CHECK: This account is checked by the endpoint program
     pub adapter_info: UncheckedAccount<'info>,
 
+    /// The integrator config account
+    /// CHECK: This account is checked by the endpoint program
+    pub integrator_config: UncheckedAccount<'info>,
+
     /// The adapter PDA account, used for signing
     #[account(
         seeds = [b"adapter_pda"],
@@ -85,6 +130,12 @@ pub struct InvokePickUpMessage<'info> {
     )]
     pub adapter_pda: SystemAccount<'info>,
 
+    /// The fee vault of the adapter
+    /// The delivery fee escrowed for this adapter is released to this account
+    /// CHECK: This account is checked by the endpoint program
+    #[account(mut)]
+    pub adapter_fee_vault: UncheckedAccount<'info>,
+
     /// CHECK: This should be seeded with `__event_authority`
     #[account(
             seeds = [b"__event_authority"],
@@ -100,6 +151,10 @@ pub struct InvokePickUpMessage<'info> {
     /// CHECK: this is a refund recipient that will be passed in by integrator
     pub refund_recipient: AccountInfo<'info>,
 
+    /// The endpoint config account
+    /// CHECK: This account is checked by the endpoint program
+    pub endpoint_config: UncheckedAccount<'info>,
+
     /// The system program
     pub system_program: Program<'info, System>,
 
@@ -114,16 +169,72 @@ impl<'info> InvokePickUpMessage<'info> {
         let cpi_accounts = PickUpMessage {
             outbox_message: self.outbox_message.to_account_info(),
             adapter_info: self.adapter_info.to_account_info(),
+            integrator_config: self.integrator_config.to_account_info(),
             adapter_pda: self.adapter_pda.to_account_info(),
+            adapter_fee_vault: self.adapter_fee_vault.to_account_info(),
             event_authority: self.event_authority.to_account_info(),
             program: self.program.to_account_info(),
             refund_recipient: self.refund_recipient.to_account_info(),
+            endpoint_config: self.endpoint_config.to_account_info(),
             system_program: self.system_program.to_account_info(),
         };
         CpiContext::new(cpi_program, cpi_accounts)
     }
 }
 
+/// Accounts struct for the invoke_claim_adapter_fees instruction
+#[derive(Accounts)]
+pub struct InvokeClaimAdapterFees<'info> {
+    /// The adapter PDA account, used for signing
+    #[account(
+        seeds = [b"adapter_pda"],
+        bump,
+    )]
+    pub adapter_pda: SystemAccount<'info>,
+
+    /// The fee vault of the adapter
+    /// CHECK: This account is checked by the endpoint program
+    #[account(mut)]
+    pub adapter_fee_vault: UncheckedAccount<'info>,
+
+    /// The account receiving the fees
+    /// CHECK: This account is chosen by the caller
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// The event authority PDA
+    /// CHECK: This should be seeded with `__event_authority`
+    #[account(
+        seeds = [b"__event_authority"],
+        bump,
+        seeds::program = endpoint::id(),
+    )]
+    pub event_authority: AccountInfo<'info>,
+
+    /// CHECK: Self-CPI will fail if the program is n
+    pub program: AccountInfo<'info>,
+
+    /// The endpoint program
+    pub endpoint_program: Program<'info, Endpoint>,
+}
+
+impl<'info> InvokeClaimAdapterFees<'info> {
+    /// Helper function to create the CpiContext for the claim_adapter_fees instruction
+    pub fn invoke_claim_adapter_fees(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, ClaimAdapterFees<'info>> {
+        let cpi_program = self.endpoint_program.to_account_info();
+        let cpi_accounts = ClaimAdapterFees {
+            adapter_pda: self.adapter_pda.to_account_info(),
+            adapter_fee_vault: self.adapter_fee_vault.to_account_info(),
+            recipient: self.recipient.to_account_info(),
+            event_authority: self.event_authority.to_account_info(),
+            program: self.program.to_account_info(),
+        };
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InvokeAttestMessageArgs {
     pub src_chain: u16,
@@ -152,6 +263,10 @@ pub struct InvokeAttestMessage<'info> {
     )]
     pub adapter_pda: SystemAccount<'info>,
 
+    /// The integrator config account
+    /// CHECK: This account is checked by the endpoint program
+    pub integrator_config: UncheckedAccount<'info>,
+
     /// The integrator chain config account
     /// CHECK: This account is checked by the endpoint program
     pub integrator_chain_config: UncheckedAccount<'info>,
@@ -161,6 +276,10 @@ pub struct InvokeAttestMessage<'info> {
     #[account(mut)]
     pub attestation_info: UncheckedAccount<'info>,
 
+    /// The replay bitmap account
+    /// CHECK: This account is checked by the endpoint program
+    pub replay_bitmap: UncheckedAccount<'info>,
+
     /// The event authority PDA
     /// CHECK: This should be seeded with `__event_authority`
     #[account(
@@ -173,6 +292,10 @@ pub struct InvokeAttestMessage<'info> {
     /// CHECK: Self-CPI will fail if the program is n
     pub program: AccountInfo<'info>,
 
+    /// The endpoint config account
+    /// CHECK: This account is checked by the endpoint program
+    pub endpoint_config: UncheckedAccount<'info>,
+
     /// The system program
     pub system_program: Program<'info, System>,
 
@@ -188,10 +311,13 @@ impl<'info> InvokeAttestMessage<'info> {
             payer: self.payer.to_account_info(),
             adapter_info: self.adapter_info.to_account_info(),
             adapter_pda: self.adapter_pda.to_account_info(),
+            integrator_config: self.integrator_config.to_account_info(),
             integrator_chain_config: self.integrator_chain_config.to_account_info(),
             attestation_info: self.attestation_info.to_account_info(),
+            replay_bitmap: self.replay_bitmap.to_account_info(),
             event_authority: self.event_authority.to_account_info(),
             program: self.program.to_account_info(),
+            endpoint_config: self.endpoint_config.to_account_info(),
             system_program: self.system_program.to_account_info(),
         };
         CpiContext::new(cpi_program, cpi_accounts)
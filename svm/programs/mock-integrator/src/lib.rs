@@ -2,8 +2,8 @@ use anchor_lang::prelude::*;
 use endpoint::program::Endpoint;
 use endpoint::{self};
 use endpoint::{
-    cpi::accounts::{RecvMessage, Register, SendMessage},
-    instructions::{RegisterArgs, SendMessageArgs},
+    cpi::accounts::{CancelMessage, RecvMessage, Register, SendMessage},
+    instructions::{CancelMessageArgs, RegisterArgs, SendMessageArgs},
 };
 use universal_address::UniversalAddress;
 
@@ -35,42 +35,71 @@ pub mod mock_integrator {
         Ok(())
     }
 
-    pub fn invoke_send_message(
-        ctx: Context<InvokeSendMessage>,
+    /// Invokes the send_message instruction on the endpoint program via CPI
+    /// The send-enabled adapter programs are forwarded as remaining accounts
+    pub fn invoke_send_message<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InvokeSendMessage<'info>>,
         args: InvokeSendMessageArgs,
     ) -> Result<()> {
         let bump_seed = &[ctx.bumps.integrator_program_pda][..];
         let signer_seeds: &[&[&[u8]]] = &[&[b"endpoint_integrator", bump_seed]];
 
         endpoint::cpi::send_message(
-            ctx.accounts.invoke_send_message().with_signer(signer_seeds),
+            ctx.accounts
+                .invoke_send_message()
+                .with_signer(signer_seeds)
+                .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
             SendMessageArgs {
                 integrator_program_id: crate::ID,
                 integrator_program_pda_bump: ctx.bumps.integrator_program_pda,
                 dst_chain: args.dst_chain,
                 dst_addr: args.dst_addr,
                 payload_hash: args.payload_hash,
+                adapter_instructions: args.adapter_instructions,
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Invokes the cancel_message instruction on the endpoint program via CPI
+    pub fn invoke_cancel_message(
+        ctx: Context<InvokeCancelMessage>,
+        args: InvokeCancelMessageArgs,
+    ) -> Result<()> {
+        let bump_seed = &[ctx.bumps.integrator_program_pda][..];
+        let signer_seeds: &[&[&[u8]]] = &[&[b"endpoint_integrator", bump_seed]];
+
+        endpoint::cpi::cancel_message(
+            ctx.accounts
+                .invoke_cancel_message()
+                .with_signer(signer_seeds),
+            CancelMessageArgs {
+                integrator_program_id: crate::ID,
+                integrator_program_pda_bump: ctx.bumps.integrator_program_pda,
+                sequence: args.sequence,
             },
         )?;
         Ok(())
     }
 
     /// Invokes the recv_message instruction on the endpoint program via CPI
+    /// A queued message must not be processed until it is released and received again
     pub fn invoke_recv_message(
         ctx: Context<InvokeRecvMessage>,
         args: endpoint::instructions::RecvMessageArgs,
-    ) -> Result<()> {
+    ) -> Result<endpoint::instructions::RecvStatus> {
         // Prepare the seeds for PDA signing
         let bump_seed = &[ctx.bumps.integrator_program_pda][..];
         let signer_seeds: &[&[&[u8]]] = &[&[b"endpoint_integrator", bump_seed]];
 
         // Perform the CPI call to the endpoint program's recv_message instruction
-        endpoint::cpi::recv_message(
+        let status = endpoint::cpi::recv_message(
             ctx.accounts.invoke_recv_message().with_signer(signer_seeds),
             args,
-        )?;
+        )?
+        .get();
 
-        Ok(())
+        Ok(status)
     }
 
     /// Invokes the exec_message instruction on the endpoint program via CPI
@@ -156,6 +185,7 @@ pub struct InvokeSendMessageArgs {
     pub dst_chain: u16,
     pub dst_addr: UniversalAddress,
     pub payload_hash: [u8; 32],
+    pub adapter_instructions: Vec<u8>,
 }
 
 #[derive(Accounts)]
@@ -169,13 +199,16 @@ pub struct InvokeSendMessage<'info> {
     )]
     pub integrator_program_pda: SystemAccount<'info>,
 
+    /// CHECK: This account is checked by the endpoint program
+    pub integrator_config: UncheckedAccount<'info>,
+
     #[account(mut)]
     /// CHECK: This account is checked by the endpoint program
     pub integrator_chain_config: UncheckedAccount<'info>,
 
     #[account(mut)]
     /// CHECK: This account is initialized by the endpoint program
-    pub outbox_message: Signer<'info>,
+    pub outbox_message: UncheckedAccount<'info>,
 
     #[account(mut)]
     /// CHECK: This account is checked by the endpoint program
@@ -195,6 +228,10 @@ pub struct InvokeSendMessage<'info> {
 
     pub endpoint_program: Program<'info, Endpoint>,
 
+    /// The endpoint config account
+    /// CHECK: This account is checked by the endpoint program
+    pub endpoint_config: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -204,9 +241,11 @@ impl<'info> InvokeSendMessage<'info> {
         let cpi_accounts = SendMessage {
             integrator_program_pda: self.integrator_program_pda.to_account_info(),
             payer: self.payer.to_account_info(),
+            integrator_config: self.integrator_config.to_account_info(),
             integrator_chain_config: self.integrator_chain_config.to_account_info(),
             outbox_message: self.outbox_message.to_account_info(),
             sequence_tracker: self.sequence_tracker.to_account_info(),
+            endpoint_config: self.endpoint_config.to_account_info(),
             system_program: self.system_program.to_account_info(),
             event_authority: self.event_authority.to_account_info(),
             program: self.program.to_account_info(),
@@ -215,6 +254,56 @@ impl<'info> InvokeSendMessage<'info> {
     }
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InvokeCancelMessageArgs {
+    pub sequence: u64,
+}
+
+#[derive(Accounts)]
+pub struct InvokeCancelMessage<'info> {
+    #[account(
+        seeds = [b"endpoint_integrator"],
+        bump,
+    )]
+    pub integrator_program_pda: SystemAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: This account is checked by the endpoint program
+    pub outbox_message: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: This account is checked by the endpoint program
+    pub refund_recipient: UncheckedAccount<'info>,
+
+    /// The event authority PDA
+    /// CHECK: This should be seeded with `__event_authority`
+    #[account(
+        seeds = [b"__event_authority"],
+        bump,
+        seeds::program = endpoint::id(),
+    )]
+    pub event_authority: AccountInfo<'info>,
+
+    /// CHECK: Self-CPI will fail if the program is n
+    pub program: AccountInfo<'info>,
+
+    pub endpoint_program: Program<'info, Endpoint>,
+}
+
+impl<'info> InvokeCancelMessage<'info> {
+    pub fn invoke_cancel_message(&self) -> CpiContext<'_, '_, '_, 'info, CancelMessage<'info>> {
+        let cpi_program = self.endpoint_program.to_account_info();
+        let cpi_accounts = CancelMessage {
+            integrator_program_pda: self.integrator_program_pda.to_account_info(),
+            outbox_message: self.outbox_message.to_account_info(),
+            refund_recipient: self.refund_recipient.to_account_info(),
+            event_authority: self.event_authority.to_account_info(),
+            program: self.program.to_account_info(),
+        };
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
 /// Accounts struct for the invoke_recv_message instruction
 #[derive(Accounts)]
 pub struct InvokeRecvMessage<'info> {
@@ -232,8 +321,18 @@ pub struct InvokeRecvMessage<'info> {
     #[account(mut)]
     pub attestation_info: UncheckedAccount<'info>,
 
+    /// The replay bitmap account
+    /// CHECK: This account is checked by the endpoint program
+    #[account(mut)]
+    pub replay_bitmap: UncheckedAccount<'info>,
+
+    /// The integrator config account
+    /// CHECK: This account is checked by the endpoint program
+    pub integrator_config: UncheckedAccount<'info>,
+
     /// The integrator chain config account
     /// CHECK: This account is checked by the endpoint program
+    #[account(mut)]
     pub integrator_chain_config: UncheckedAccount<'info>,
 
     /// The event authority PDA
@@ -248,6 +347,10 @@ pub struct InvokeRecvMessage<'info> {
     /// CHECK: Self-CPI will fail if the program is n
     pub program: AccountInfo<'info>,
 
+    /// The endpoint config account
+    /// CHECK: This account is checked by the endpoint program
+    pub endpoint_config: UncheckedAccount<'info>,
+
     /// The system program
     pub system_program: Program<'info, System>,
 
@@ -263,7 +366,10 @@ impl<'info> InvokeRecvMessage<'info> {
             integrator_program_pda: self.integrator_program_pda.to_account_info(),
             payer: self.payer.to_account_info(),
             attestation_info: self.attestation_info.to_account_info(),
+            replay_bitmap: self.replay_bitmap.to_account_info(),
+            integrator_config: self.integrator_config.to_account_info(),
             integrator_chain_config: self.integrator_chain_config.to_account_info(),
+            endpoint_config: self.endpoint_config.to_account_info(),
             system_program: self.system_program.to_account_info(),
             event_authority: self.event_authority.to_account_info(),
             program: self.program.to_account_info(),
@@ -288,6 +394,19 @@ pub struct InvokeExecMessage<'info> {
     #[account(mut)]
     pub attestation_info: UncheckedAccount<'info>,
 
+    /// The replay bitmap account
+    /// CHECK: This account is checked by the endpoint program
+    #[account(mut)]
+    pub replay_bitmap: UncheckedAccount<'info>,
+
+    /// The integrator config account
+    /// CHECK: This account is checked by the endpoint program
+    pub integrator_config: UncheckedAccount<'info>,
+
+    /// The integrator chain config account
+    /// CHECK: This account is checked by the endpoint program
+    pub integrator_chain_config: UncheckedAccount<'info>,
+
     /// The event authority PDA
     /// CHECK: This should be seeded with `__event_authority`
     #[account(
@@ -300,6 +419,10 @@ pub struct InvokeExecMessage<'info> {
     /// CHECK: Self-CPI will fail if the program is n
     pub program: AccountInfo<'info>,
 
+    /// The endpoint config account
+    /// CHECK: This account is checked by the endpoint program
+    pub endpoint_config: UncheckedAccount<'info>,
+
     /// The system program
     pub system_program: Program<'info, System>,
 
@@ -317,6 +440,10 @@ impl<'info> InvokeExecMessage<'info> {
             payer: self.payer.to_account_info(),
             integrator_program_pda: self.integrator_program_pda.to_account_info(),
             attestation_info: self.attestation_info.to_account_info(),
+            replay_bitmap: self.replay_bitmap.to_account_info(),
+            integrator_config: self.integrator_config.to_account_info(),
+            integrator_chain_config: self.integrator_chain_config.to_account_info(),
+            endpoint_config: self.endpoint_config.to_account_info(),
             system_program: self.system_program.to_account_info(),
             event_authority: self.event_authority.to_account_info(),
             program: self.program.to_account_info(),
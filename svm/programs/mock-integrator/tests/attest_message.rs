@@ -8,6 +8,7 @@ use crate::instructions::attest_message::attest_message;
 use crate::instructions::enable_adapter::enable_recv_adapter;
 use crate::instructions::exec_message::exec_message;
 use crate::instructions::register::register;
+use crate::instructions::set_paused::set_paused;
 
 use anchor_lang::prelude::*;
 use common::setup::{get_account, setup};
@@ -345,3 +346,45 @@ async fn test_attest_message_invalid_destination_chain() {
 }
 
 // TODO: test using disabled_adapter. Need to find out how to make two adapters without having to duplicate the program
+
+#[tokio::test]
+async fn test_attest_message_paused() {
+    let (
+        mut context,
+        payer,
+        _,
+        _,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    ) = setup_test_environment(2).await;
+
+    // The payer is the guardian set by `setup`
+    set_paused(&mut context, &payer, &payer, true)
+        .await
+        .unwrap();
+
+    let result = attest_message(
+        &mut context,
+        &payer,
+        adapter_info_pda,
+        adapter_pda,
+        integrator_chain_config_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        1,
+        1,
+        UniversalAddress::from_pubkey(&mock_integrator::id()),
+        [3u8; 32],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::EndpointPaused.into())
+        )
+    );
+}
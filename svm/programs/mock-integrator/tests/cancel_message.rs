@@ -0,0 +1,284 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+mod instructions;
+
+use crate::instructions::add_adapter::add_adapter;
+use crate::instructions::cancel_message::cancel_message;
+use crate::instructions::enable_adapter::enable_send_adapter;
+use crate::instructions::pick_up_message::pick_up_message;
+use crate::instructions::register::register;
+use crate::instructions::send_message::send_message;
+
+use anchor_lang::prelude::*;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use common::execute_transaction::execute_transaction;
+use common::setup::setup;
+use endpoint::state::{
+    AdapterInfo, IntegratorChainConfig, IntegratorConfig, OutboxMessage, SequenceTracker,
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    signature::Keypair,
+    signer::Signer,
+    system_instruction,
+    transaction::TransactionError,
+};
+use universal_address::UniversalAddress;
+
+async fn setup_test_environment() -> (ProgramTestContext, Keypair, Pubkey, Pubkey, Pubkey, Pubkey) {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+    let admin = Keypair::new();
+    let integrator_program_id = mock_integrator::id();
+    let chain_id: u16 = 1;
+
+    let (integrator_config_pda, _) = IntegratorConfig::pda(&integrator_program_id);
+    let (integrator_chain_config_pda, _) =
+        IntegratorChainConfig::pda(&integrator_program_id, chain_id);
+    let (integrator_program_pda, _) =
+        Pubkey::find_program_address(&[b"endpoint_integrator"], &integrator_program_id);
+
+    register(
+        &mut context,
+        &payer,
+        &admin,
+        integrator_config_pda,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    let adapter_program_id = mock_adapter::id();
+    let (adapter_info_pda, _) = AdapterInfo::pda(&integrator_program_id, &adapter_program_id);
+    let (adapter_pda, _) = Pubkey::find_program_address(&[b"adapter_pda"], &adapter_program_id);
+
+    add_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        adapter_info_pda,
+        integrator_program_id,
+        adapter_program_id,
+    )
+    .await
+    .unwrap();
+
+    enable_send_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        chain_id,
+        adapter_program_id,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    // Fund the adapter PDA so it stays rent exempt when receiving delivery fees
+    let rent = context.banks_client.get_rent().await.unwrap();
+    execute_transaction(
+        &mut context,
+        system_instruction::transfer(&payer.pubkey(), &adapter_pda, rent.minimum_balance(0)),
+        &[&payer],
+        &payer,
+    )
+    .await
+    .unwrap();
+
+    let (sequence_tracker_pda, _) = SequenceTracker::pda(&integrator_program_id);
+    let (outbox_message, _) = OutboxMessage::pda(&integrator_program_id, 0);
+    send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        outbox_message,
+        sequence_tracker_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        [2u8; 32],
+        &[adapter_program_id],
+        vec![],
+    )
+    .await
+    .unwrap();
+
+    (
+        context,
+        payer,
+        integrator_program_pda,
+        outbox_message,
+        adapter_info_pda,
+        adapter_pda,
+    )
+}
+
+#[tokio::test]
+async fn test_cancel_message_success() {
+    let (mut context, payer, integrator_program_pda, outbox_message, _, _) =
+        setup_test_environment().await;
+
+    // The refund recipient is the payer, who also pays the transaction fee, so a separate
+    // fee payer is used to check the refund exactly
+    let fee_payer = Keypair::new();
+    execute_transaction(
+        &mut context,
+        system_instruction::transfer(&payer.pubkey(), &fee_payer.pubkey(), 1_000_000_000),
+        &[&payer],
+        &payer,
+    )
+    .await
+    .unwrap();
+
+    let outbox_lamports = context
+        .banks_client
+        .get_balance(outbox_message)
+        .await
+        .unwrap();
+    let refund_balance_before = context
+        .banks_client
+        .get_balance(payer.pubkey())
+        .await
+        .unwrap();
+
+    cancel_message(
+        &mut context,
+        &fee_payer,
+        integrator_program_pda,
+        outbox_message,
+        payer.pubkey(),
+        0,
+    )
+    .await
+    .unwrap();
+
+    // The outbox message is closed
+    let account = context
+        .banks_client
+        .get_account(outbox_message)
+        .await
+        .unwrap();
+    assert!(account.is_none());
+
+    // The rent and the escrowed delivery fee are returned to the refund recipient
+    let refund_balance_after = context
+        .banks_client
+        .get_balance(payer.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(
+        refund_balance_after,
+        refund_balance_before + outbox_lamports
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_message_after_pick_up() {
+    let (mut context, payer, integrator_program_pda, outbox_message, adapter_info_pda, adapter_pda) =
+        setup_test_environment().await;
+
+    // With a single send adapter the outbox message is closed on pick up, so it can no longer be
+    // cancelled. Partially picked up messages are covered by the `OutboxMessage` unit tests
+    pick_up_message(
+        &mut context,
+        &payer,
+        outbox_message,
+        adapter_info_pda,
+        adapter_pda,
+        payer.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    let result = cancel_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        outbox_message,
+        payer.pubkey(),
+        0,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::AccountNotInitialized.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_message_wrong_refund_recipient() {
+    let (mut context, payer, integrator_program_pda, outbox_message, _, _) =
+        setup_test_environment().await;
+
+    let result = cancel_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        outbox_message,
+        Keypair::new().pubkey(),
+        0,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintHasOne.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_message_without_integrator_signature() {
+    let (mut context, payer, integrator_program_pda, outbox_message, _, _) =
+        setup_test_environment().await;
+
+    // Calling the endpoint directly, the integrator program PDA cannot sign
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+    let (_, integrator_program_pda_bump) =
+        Pubkey::find_program_address(&[b"endpoint_integrator"], &mock_integrator::id());
+
+    let accounts = endpoint::accounts::CancelMessage {
+        integrator_program_pda,
+        outbox_message,
+        refund_recipient: payer.pubkey(),
+        event_authority,
+        program: endpoint::id(),
+    };
+    let mut accounts = accounts.to_account_metas(None);
+    accounts[0].is_signer = false;
+
+    let args = endpoint::instructions::CancelMessageArgs {
+        integrator_program_id: mock_integrator::id(),
+        integrator_program_pda_bump,
+        sequence: 0,
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts,
+        data: endpoint::instruction::CancelMessage { args }.data(),
+    };
+
+    let result = execute_transaction(&mut context, ix, &[&payer], &payer).await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::AccountNotSigner.into())
+        )
+    );
+}
@@ -0,0 +1,461 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+mod instructions;
+
+use crate::instructions::add_adapter::add_adapter;
+use crate::instructions::attest_message::attest_message;
+use crate::instructions::close_attestation_info::close_attestation_info;
+use crate::instructions::enable_adapter::enable_recv_adapter;
+use crate::instructions::exec_message::exec_message;
+use crate::instructions::get_message_status::get_message_status;
+use crate::instructions::recv_message::recv_message;
+use crate::instructions::register::register;
+
+use anchor_lang::prelude::*;
+use common::execute_transaction::execute_transaction;
+use common::setup::{get_account, setup};
+use endpoint::error::EndpointError;
+use endpoint::state::{
+    AdapterInfo, AttestationInfo, IntegratorChainConfig, IntegratorConfig, ReplayBitmap,
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer, system_instruction,
+    transaction::TransactionError,
+};
+use universal_address::UniversalAddress;
+
+const SRC_CHAIN: u16 = 2;
+const SEQUENCE: u64 = 1;
+const DST_CHAIN: u16 = 1;
+const PAYLOAD_HASH: [u8; 32] = [3u8; 32];
+
+fn src_addr() -> UniversalAddress {
+    UniversalAddress::from_bytes([1u8; 32])
+}
+
+fn attestation_info_pda() -> Pubkey {
+    AttestationInfo::pda(AttestationInfo::compute_message_hash(
+        SRC_CHAIN,
+        src_addr(),
+        SEQUENCE,
+        DST_CHAIN,
+        UniversalAddress::from_pubkey(&mock_integrator::id()),
+        PAYLOAD_HASH,
+    ))
+    .0
+}
+
+fn replay_bitmap_pda() -> Pubkey {
+    ReplayBitmap::pda(&mock_integrator::id(), SRC_CHAIN, &src_addr(), SEQUENCE).0
+}
+
+async fn setup_test_environment() -> (ProgramTestContext, Keypair, Pubkey, Pubkey, Pubkey) {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+    let admin = Keypair::new();
+    let integrator_program_id = mock_integrator::id();
+
+    let (integrator_config_pda, _) = IntegratorConfig::pda(&integrator_program_id);
+    let (integrator_chain_config_pda, _) =
+        IntegratorChainConfig::pda(&integrator_program_id, SRC_CHAIN);
+
+    register(
+        &mut context,
+        &payer,
+        &admin,
+        integrator_config_pda,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    let adapter_program_id = mock_adapter::id();
+    let (adapter_info_pda, _) = AdapterInfo::pda(&integrator_program_id, &adapter_program_id);
+    let (adapter_pda, _) = Pubkey::find_program_address(&[b"adapter_pda"], &adapter_program_id);
+
+    add_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        adapter_info_pda,
+        integrator_program_id,
+        adapter_program_id,
+    )
+    .await
+    .unwrap();
+
+    enable_recv_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        SRC_CHAIN,
+        adapter_program_id,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    (
+        context,
+        payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+    )
+}
+
+async fn attest(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    integrator_chain_config_pda: Pubkey,
+    adapter_info_pda: Pubkey,
+    adapter_pda: Pubkey,
+) -> std::result::Result<(), BanksClientError> {
+    attest_message(
+        context,
+        payer,
+        adapter_info_pda,
+        adapter_pda,
+        integrator_chain_config_pda,
+        SRC_CHAIN,
+        src_addr(),
+        SEQUENCE,
+        DST_CHAIN,
+        UniversalAddress::from_pubkey(&mock_integrator::id()),
+        PAYLOAD_HASH,
+    )
+    .await
+}
+
+async fn recv(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+) -> std::result::Result<(), BanksClientError> {
+    recv_message(
+        context,
+        payer,
+        attestation_info_pda(),
+        SRC_CHAIN,
+        src_addr(),
+        SEQUENCE,
+        DST_CHAIN,
+        PAYLOAD_HASH,
+    )
+    .await
+}
+
+/// Attests to and receives the message, then closes its attestation info
+async fn attest_recv_and_close(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    integrator_chain_config_pda: Pubkey,
+    adapter_info_pda: Pubkey,
+    adapter_pda: Pubkey,
+) {
+    attest(
+        context,
+        payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+    )
+    .await
+    .unwrap();
+    recv(context, payer).await.unwrap();
+    close_attestation_info(
+        context,
+        payer,
+        attestation_info_pda(),
+        payer.pubkey(),
+        replay_bitmap_pda(),
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_close_attestation_info_success() {
+    let (mut context, payer, integrator_chain_config_pda, adapter_info_pda, adapter_pda) =
+        setup_test_environment().await;
+
+    attest(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+    )
+    .await
+    .unwrap();
+    recv(&mut context, &payer).await.unwrap();
+
+    let attestation_info: AttestationInfo =
+        get_account(&mut context.banks_client, attestation_info_pda()).await;
+    assert_eq!(attestation_info.payer, payer.pubkey());
+
+    // The rent recipient is the payer of the attestation info, so a separate fee payer is used
+    // to check the refund exactly
+    let fee_payer = Keypair::new();
+    execute_transaction(
+        &mut context,
+        system_instruction::transfer(&payer.pubkey(), &fee_payer.pubkey(), 1_000_000_000),
+        &[&payer],
+        &payer,
+    )
+    .await
+    .unwrap();
+
+    let attestation_info_lamports = context
+        .banks_client
+        .get_balance(attestation_info_pda())
+        .await
+        .unwrap();
+    let payer_balance_before = context
+        .banks_client
+        .get_balance(payer.pubkey())
+        .await
+        .unwrap();
+
+    close_attestation_info(
+        &mut context,
+        &fee_payer,
+        attestation_info_pda(),
+        payer.pubkey(),
+        replay_bitmap_pda(),
+    )
+    .await
+    .unwrap();
+
+    // The attestation info is closed and its rent refunded to its payer
+    let account = context
+        .banks_client
+        .get_account(attestation_info_pda())
+        .await
+        .unwrap();
+    assert!(account.is_none());
+
+    let payer_balance_after = context
+        .banks_client
+        .get_balance(payer.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(
+        payer_balance_after,
+        payer_balance_before + attestation_info_lamports
+    );
+
+    // The sequence stays marked as executed in the replay bitmap
+    let replay_bitmap: ReplayBitmap =
+        get_account(&mut context.banks_client, replay_bitmap_pda()).await;
+    assert_eq!(replay_bitmap.integrator_program_id, mock_integrator::id());
+    assert_eq!(replay_bitmap.src_chain, SRC_CHAIN);
+    assert_eq!(replay_bitmap.src_addr, src_addr());
+    assert_eq!(replay_bitmap.index, 0);
+    assert!(replay_bitmap.is_executed(SEQUENCE));
+    assert!(!replay_bitmap.is_executed(SEQUENCE + 1));
+
+    // The message is still reported as executed
+    let status = get_message_status(
+        &mut context,
+        &payer,
+        SRC_CHAIN,
+        src_addr(),
+        SEQUENCE,
+        DST_CHAIN,
+        mock_integrator::id(),
+        PAYLOAD_HASH,
+    )
+    .await
+    .unwrap();
+    assert!(status.executed);
+    assert_eq!(status.attested_bitmap, 0);
+}
+
+#[tokio::test]
+async fn test_close_attestation_info_not_executed() {
+    let (mut context, payer, integrator_chain_config_pda, adapter_info_pda, adapter_pda) =
+        setup_test_environment().await;
+
+    attest(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+    )
+    .await
+    .unwrap();
+
+    let result = close_attestation_info(
+        &mut context,
+        &payer,
+        attestation_info_pda(),
+        payer.pubkey(),
+        replay_bitmap_pda(),
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::MessageNotExecuted.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_close_attestation_info_sequence_executed() {
+    let (mut context, payer, integrator_chain_config_pda, adapter_info_pda, adapter_pda) =
+        setup_test_environment().await;
+
+    attest(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+    )
+    .await
+    .unwrap();
+
+    // Executing the sequence with another payload means the attested message can never be
+    // received
+    exec_message(
+        &mut context,
+        &payer,
+        SRC_CHAIN,
+        src_addr(),
+        SEQUENCE,
+        DST_CHAIN,
+        UniversalAddress::from_pubkey(&mock_integrator::id()),
+        [4u8; 32],
+    )
+    .await
+    .unwrap();
+
+    close_attestation_info(
+        &mut context,
+        &payer,
+        attestation_info_pda(),
+        payer.pubkey(),
+        replay_bitmap_pda(),
+    )
+    .await
+    .unwrap();
+
+    assert!(context
+        .banks_client
+        .get_account(attestation_info_pda())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_close_attestation_info_wrong_rent_recipient() {
+    let (mut context, payer, integrator_chain_config_pda, adapter_info_pda, adapter_pda) =
+        setup_test_environment().await;
+
+    attest(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+    )
+    .await
+    .unwrap();
+    recv(&mut context, &payer).await.unwrap();
+
+    let result = close_attestation_info(
+        &mut context,
+        &payer,
+        attestation_info_pda(),
+        Keypair::new().pubkey(),
+        replay_bitmap_pda(),
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintAddress.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_attest_message_after_close() {
+    let (mut context, payer, integrator_chain_config_pda, adapter_info_pda, adapter_pda) =
+        setup_test_environment().await;
+
+    attest_recv_and_close(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+    )
+    .await;
+
+    // Attesting after the close succeeds without re-creating the attestation info
+    attest(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+    )
+    .await
+    .unwrap();
+
+    assert!(context
+        .banks_client
+        .get_account(attestation_info_pda())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_exec_message_after_close() {
+    let (mut context, payer, integrator_chain_config_pda, adapter_info_pda, adapter_pda) =
+        setup_test_environment().await;
+
+    attest_recv_and_close(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+    )
+    .await;
+
+    let result = exec_message(
+        &mut context,
+        &payer,
+        SRC_CHAIN,
+        src_addr(),
+        SEQUENCE,
+        DST_CHAIN,
+        UniversalAddress::from_pubkey(&mock_integrator::id()),
+        PAYLOAD_HASH,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::AlreadyExecuted.into())
+        )
+    );
+}
@@ -1,8 +1,9 @@
+use anchor_lang::{event::EVENT_IX_TAG_LE, AnchorDeserialize, Discriminator};
 use solana_program_test::*;
 use solana_sdk::{
     instruction::Instruction,
     signer::{keypair::Keypair, Signer},
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
 };
 
 pub async fn execute_transaction(
@@ -22,3 +23,78 @@ pub async fn execute_transaction(
 
     context.banks_client.process_transaction(transaction).await
 }
+
+/// Simulates a transaction and returns the return data set by the top level program
+pub async fn simulate_transaction(
+    context: &mut ProgramTestContext,
+    instruction: Instruction,
+    signers: &[&Keypair],
+    payer: &Keypair,
+) -> Result<Vec<u8>, TransactionError> {
+    let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer.pubkey()),
+        signers,
+        recent_blockhash,
+    );
+
+    let simulation = context
+        .banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+
+    if let Some(Err(err)) = simulation.result {
+        return Err(err);
+    }
+
+    Ok(simulation
+        .simulation_details
+        .and_then(|details| details.return_data)
+        .map(|return_data| return_data.data)
+        .unwrap_or_default())
+}
+
+/// Simulates a transaction and returns the events of the given type emitted with `emit_cpi!`
+pub async fn simulate_transaction_events<T: Discriminator + AnchorDeserialize>(
+    context: &mut ProgramTestContext,
+    instruction: Instruction,
+    signers: &[&Keypair],
+    payer: &Keypair,
+) -> Result<Vec<T>, TransactionError> {
+    let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer.pubkey()),
+        signers,
+        recent_blockhash,
+    );
+
+    let simulation = context
+        .banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+
+    if let Some(Err(err)) = simulation.result {
+        return Err(err);
+    }
+
+    // Events are emitted as self invocations whose data is the event tag followed by the
+    // discriminator and the serialized event
+    Ok(simulation
+        .simulation_details
+        .and_then(|details| details.inner_instructions)
+        .unwrap_or_default()
+        .into_iter()
+        .flatten()
+        .filter_map(|inner| {
+            let data = inner.instruction.data.strip_prefix(&EVENT_IX_TAG_LE)?;
+            let data = data.strip_prefix(&T::DISCRIMINATOR)?;
+            T::deserialize(&mut &data[..]).ok()
+        })
+        .collect())
+}
@@ -1,9 +1,47 @@
 use anchor_lang::prelude::*;
 use endpoint::id as endpoint_id;
-use solana_program_test::{ProgramTest, ProgramTestContext};
+use solana_program_test::{find_file, read_file, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+    signature::Keypair,
+    signer::{keypair::keypair_from_seed, Signer},
+};
 
+use crate::instructions::init_adapter_fee_vault::init_adapter_fee_vault;
+use crate::instructions::initialize::initialize;
+
+/// The upgrade authority of the endpoint program, which is the only one able to initialize it
+pub fn upgrade_authority() -> Keypair {
+    keypair_from_seed(&[7u8; 32]).unwrap()
+}
+
+/// Starts a test context with the endpoint initialized, with the payer as guardian, and the fee
+/// vault of the mock adapter initialized
 pub async fn setup() -> ProgramTestContext {
-    let mut program_test = ProgramTest::new("endpoint", endpoint_id(), None);
+    let mut context = setup_uninitialized().await;
+    let payer = context.payer.insecure_clone();
+
+    initialize(&mut context, &payer, &upgrade_authority(), payer.pubkey())
+        .await
+        .unwrap();
+    init_adapter_fee_vault(&mut context, &payer, mock_adapter::id())
+        .await
+        .unwrap();
+
+    context
+}
+
+/// Starts a test context without initializing the endpoint config
+pub async fn setup_uninitialized() -> ProgramTestContext {
+    let mut program_test = ProgramTest::default();
+    // The endpoint is deployed as an upgradeable program so that its upgrade authority can be
+    // checked when initializing it
+    add_upgradeable_program(
+        &mut program_test,
+        "endpoint",
+        endpoint_id(),
+        upgrade_authority().pubkey(),
+    );
     program_test.add_program("mock_integrator", mock_integrator::id(), None);
     program_test.add_program("mock_adapter", mock_adapter::id(), None);
 
@@ -11,6 +49,54 @@ pub async fn setup() -> ProgramTestContext {
     program_test.start_with_context().await
 }
 
+fn add_upgradeable_program(
+    program_test: &mut ProgramTest,
+    program_name: &str,
+    program_id: Pubkey,
+    upgrade_authority: Pubkey,
+) {
+    let elf = read_file(
+        find_file(&format!("{program_name}.so"))
+            .unwrap_or_else(|| panic!("{program_name}.so not found")),
+    );
+    let (programdata_address, _) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
+    let rent = Rent::default();
+
+    let program = bincode::serialize(&UpgradeableLoaderState::Program {
+        programdata_address,
+    })
+    .unwrap();
+    program_test.add_account(
+        program_id,
+        solana_sdk::account::Account {
+            lamports: rent.minimum_balance(program.len()),
+            data: program,
+            owner: bpf_loader_upgradeable::id(),
+            executable: true,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut programdata = bincode::serialize(&UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(upgrade_authority),
+    })
+    .unwrap();
+    programdata.resize(UpgradeableLoaderState::size_of_programdata_metadata(), 0);
+    programdata.extend_from_slice(&elf);
+    program_test.add_account(
+        programdata_address,
+        solana_sdk::account::Account {
+            lamports: rent.minimum_balance(programdata.len()),
+            data: programdata,
+            owner: bpf_loader_upgradeable::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
 pub async fn get_account<T: AccountDeserialize>(
     banks_client: &mut solana_program_test::BanksClient,
     address: Pubkey,
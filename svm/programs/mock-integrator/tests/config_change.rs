@@ -0,0 +1,697 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+mod instructions;
+
+use crate::instructions::add_adapter::add_adapter;
+use crate::instructions::cancel_config_change::cancel_config_change;
+use crate::instructions::disable_adapter::disable_recv_adapter;
+use crate::instructions::discard_admin::discard_admin;
+use crate::instructions::enable_adapter::enable_recv_adapter;
+use crate::instructions::execute_config_change::{
+    execute_config_change, execute_config_change_with_adapter_infos,
+};
+use crate::instructions::propose_config_change::propose_config_change;
+use crate::instructions::register::register;
+use crate::instructions::set_chain_peers::set_chain_peers;
+use crate::instructions::set_config_delay::set_config_delay;
+use crate::instructions::set_inbound_rate_limit::set_inbound_rate_limit;
+use crate::instructions::set_min_attestation_age::set_min_attestation_age;
+use crate::instructions::set_outbound_rate_limit::set_outbound_rate_limit;
+use crate::instructions::set_recv_threshold::set_recv_threshold;
+
+use common::setup::{get_account, setup};
+use endpoint::error::EndpointError;
+use endpoint::state::{
+    AdapterInfo, ConfigChange, IntegratorChainConfig, IntegratorConfig, PendingConfigChange,
+};
+use endpoint::utils::bitmap::Bitmap;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer,
+    transaction::TransactionError,
+};
+use universal_address::UniversalAddress;
+
+const DELAY: u64 = 100;
+const CHAIN_ID: u16 = 2;
+
+/// Registers the mock integrator with a registered adapter enabled for receiving from `CHAIN_ID`,
+/// then sets the config delay
+async fn setup_test_environment() -> (ProgramTestContext, Keypair, Keypair) {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+    let admin = Keypair::new();
+    let integrator_program_id = mock_integrator::id();
+    let (integrator_config_pda, _) = IntegratorConfig::pda(&integrator_program_id);
+
+    register(
+        &mut context,
+        &payer,
+        &admin,
+        integrator_config_pda,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    add_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        AdapterInfo::pda(&integrator_program_id, &mock_adapter::id()).0,
+        integrator_program_id,
+        mock_adapter::id(),
+    )
+    .await
+    .unwrap();
+
+    enable_recv_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        IntegratorChainConfig::pda(&integrator_program_id, CHAIN_ID).0,
+        AdapterInfo::pda(&integrator_program_id, &mock_adapter::id()).0,
+        CHAIN_ID,
+        mock_adapter::id(),
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    set_config_delay(&mut context, &admin, &payer, DELAY)
+        .await
+        .unwrap();
+
+    (context, payer, admin)
+}
+
+/// Proposes a change and warps to the slot from which it can be executed
+async fn propose_and_wait(
+    context: &mut ProgramTestContext,
+    admin: &Keypair,
+    payer: &Keypair,
+    id: u64,
+    change: ConfigChange,
+) {
+    propose_config_change(context, admin, payer, id, change)
+        .await
+        .unwrap();
+
+    let pending_config_change: PendingConfigChange = get_account(
+        &mut context.banks_client,
+        PendingConfigChange::pda(&mock_integrator::id(), id).0,
+    )
+    .await;
+    context
+        .warp_to_slot(pending_config_change.eta)
+        .expect("warp to slot");
+}
+
+fn assert_endpoint_error(result: std::result::Result<(), BanksClientError>, error: EndpointError) {
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(error.into()))
+    );
+}
+
+#[tokio::test]
+async fn test_config_delay_blocks_direct_changes() {
+    let (mut context, payer, admin) = setup_test_environment().await;
+    let integrator_program_id = mock_integrator::id();
+    let (integrator_config_pda, _) = IntegratorConfig::pda(&integrator_program_id);
+
+    let integrator_config: IntegratorConfig =
+        get_account(&mut context.banks_client, integrator_config_pda).await;
+    assert_eq!(integrator_config.config_delay, DELAY);
+
+    let adapter_program_id = Keypair::new().pubkey();
+    let result = add_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        AdapterInfo::pda(&integrator_program_id, &adapter_program_id).0,
+        integrator_program_id,
+        adapter_program_id,
+    )
+    .await;
+    assert_endpoint_error(result, EndpointError::ConfigChangeTimelocked);
+
+    let result = set_recv_threshold(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        IntegratorChainConfig::pda(&integrator_program_id, CHAIN_ID).0,
+        integrator_program_id,
+        CHAIN_ID,
+        1,
+    )
+    .await;
+    assert_endpoint_error(result, EndpointError::ConfigChangeTimelocked);
+
+    // Lowering the delay must be proposed
+    let result = set_config_delay(&mut context, &admin, &payer, DELAY - 1).await;
+    assert_endpoint_error(result, EndpointError::ConfigChangeTimelocked);
+
+    // Disabling adapters stays immediate
+    disable_recv_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        IntegratorChainConfig::pda(&integrator_program_id, CHAIN_ID).0,
+        AdapterInfo::pda(&integrator_program_id, &mock_adapter::id()).0,
+        CHAIN_ID,
+        mock_adapter::id(),
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_execute_add_adapter() {
+    let (mut context, payer, admin) = setup_test_environment().await;
+    let integrator_program_id = mock_integrator::id();
+    let adapter_program_id = Keypair::new().pubkey();
+    let change = ConfigChange::AddAdapter { adapter_program_id };
+
+    propose_config_change(&mut context, &admin, &payer, 0, change.clone())
+        .await
+        .unwrap();
+
+    let (pending_config_change_pda, bump) = PendingConfigChange::pda(&integrator_program_id, 0);
+    let pending_config_change: PendingConfigChange =
+        get_account(&mut context.banks_client, pending_config_change_pda).await;
+    assert_eq!(pending_config_change.bump, bump);
+    assert_eq!(
+        pending_config_change.integrator_program_id,
+        integrator_program_id
+    );
+    assert_eq!(pending_config_change.id, 0);
+    assert_eq!(pending_config_change.change, change);
+    assert_eq!(pending_config_change.payer, payer.pubkey());
+
+    // The change cannot be executed before the delay has elapsed
+    let result = execute_config_change(&mut context, &payer, 0, change.clone()).await;
+    assert_endpoint_error(result, EndpointError::ConfigDelayNotElapsed);
+
+    context
+        .warp_to_slot(pending_config_change.eta)
+        .expect("warp to slot");
+    execute_config_change(&mut context, &payer, 0, change)
+        .await
+        .unwrap();
+
+    let integrator_config: IntegratorConfig = get_account(
+        &mut context.banks_client,
+        IntegratorConfig::pda(&integrator_program_id).0,
+    )
+    .await;
+    assert_eq!(
+        integrator_config.adapter_infos,
+        vec![mock_adapter::id(), adapter_program_id]
+    );
+    assert_eq!(integrator_config.next_config_change_id, 1);
+
+    let adapter_info: AdapterInfo = get_account(
+        &mut context.banks_client,
+        AdapterInfo::pda(&integrator_program_id, &adapter_program_id).0,
+    )
+    .await;
+    assert_eq!(adapter_info.index, 1);
+    assert_eq!(adapter_info.adapter_program_id, adapter_program_id);
+
+    // The pending config change is closed
+    let account = context
+        .banks_client
+        .get_account(pending_config_change_pda)
+        .await
+        .unwrap();
+    assert!(account.is_none());
+}
+
+#[tokio::test]
+async fn test_execute_chain_changes() {
+    let (mut context, payer, admin) = setup_test_environment().await;
+    let integrator_program_id = mock_integrator::id();
+    let other_chain_id = CHAIN_ID + 1;
+
+    let changes = [
+        ConfigChange::SetRecvThreshold {
+            chain_id: CHAIN_ID,
+            threshold: 1,
+        },
+        ConfigChange::EnableSendAdapter {
+            chain_id: other_chain_id,
+            adapter_program_id: mock_adapter::id(),
+        },
+        ConfigChange::DisableRecvAdapter {
+            chain_id: CHAIN_ID,
+            adapter_program_id: mock_adapter::id(),
+        },
+    ];
+
+    // The receive threshold is validated when the change is executed
+    propose_and_wait(&mut context, &admin, &payer, 0, changes[0].clone()).await;
+    execute_config_change(&mut context, &payer, 0, changes[0].clone())
+        .await
+        .unwrap();
+    propose_and_wait(&mut context, &admin, &payer, 1, changes[1].clone()).await;
+    execute_config_change(&mut context, &payer, 1, changes[1].clone())
+        .await
+        .unwrap();
+
+    let integrator_chain_config: IntegratorChainConfig = get_account(
+        &mut context.banks_client,
+        IntegratorChainConfig::pda(&integrator_program_id, CHAIN_ID).0,
+    )
+    .await;
+    assert_eq!(integrator_chain_config.recv_threshold, 1);
+
+    let integrator_chain_config: IntegratorChainConfig = get_account(
+        &mut context.banks_client,
+        IntegratorChainConfig::pda(&integrator_program_id, other_chain_id).0,
+    )
+    .await;
+    assert_eq!(integrator_chain_config.chain_id, other_chain_id);
+    assert_eq!(integrator_chain_config.send_adapter_bitmap.as_value(), 1);
+
+    let adapter_info: AdapterInfo = get_account(
+        &mut context.banks_client,
+        AdapterInfo::pda(&integrator_program_id, &mock_adapter::id()).0,
+    )
+    .await;
+    assert_eq!(adapter_info.enabled_count, 2);
+
+    propose_and_wait(&mut context, &admin, &payer, 2, changes[2].clone()).await;
+    execute_config_change(&mut context, &payer, 2, changes[2].clone())
+        .await
+        .unwrap();
+
+    let integrator_chain_config: IntegratorChainConfig = get_account(
+        &mut context.banks_client,
+        IntegratorChainConfig::pda(&integrator_program_id, CHAIN_ID).0,
+    )
+    .await;
+    assert!(integrator_chain_config.recv_adapter_bitmap.is_empty());
+}
+
+#[tokio::test]
+async fn test_execute_lower_config_delay() {
+    let (mut context, payer, admin) = setup_test_environment().await;
+    let change = ConfigChange::SetConfigDelay { delay: 0 };
+
+    propose_and_wait(&mut context, &admin, &payer, 0, change.clone()).await;
+    execute_config_change(&mut context, &payer, 0, change)
+        .await
+        .unwrap();
+
+    let integrator_config: IntegratorConfig = get_account(
+        &mut context.banks_client,
+        IntegratorConfig::pda(&mock_integrator::id()).0,
+    )
+    .await;
+    assert_eq!(integrator_config.config_delay, 0);
+}
+
+#[tokio::test]
+async fn test_execute_lower_min_attestation_age() {
+    let (mut context, payer, admin) = setup_test_environment().await;
+    let integrator_chain_config_pda =
+        IntegratorChainConfig::pda(&mock_integrator::id(), CHAIN_ID).0;
+
+    // Raising the age is immediate, but lowering it must be proposed
+    set_min_attestation_age(&mut context, &admin, &payer, CHAIN_ID, 100)
+        .await
+        .unwrap();
+    assert_endpoint_error(
+        set_min_attestation_age(&mut context, &admin, &payer, CHAIN_ID, 0).await,
+        EndpointError::ConfigChangeTimelocked,
+    );
+
+    let change = ConfigChange::SetMinAttestationAge {
+        chain_id: CHAIN_ID,
+        min_attestation_age: 0,
+    };
+    propose_and_wait(&mut context, &admin, &payer, 0, change.clone()).await;
+    execute_config_change(&mut context, &payer, 0, change)
+        .await
+        .unwrap();
+
+    let integrator_chain_config: IntegratorChainConfig =
+        get_account(&mut context.banks_client, integrator_chain_config_pda).await;
+    assert_eq!(integrator_chain_config.min_attestation_age, 0);
+}
+
+#[tokio::test]
+async fn test_execute_set_chain_peers() {
+    let (mut context, payer, admin) = setup_test_environment().await;
+    let integrator_chain_config_pda =
+        IntegratorChainConfig::pda(&mock_integrator::id(), CHAIN_ID).0;
+    let peer = UniversalAddress::from_bytes([1; 32]);
+    let other = UniversalAddress::from_bytes([2; 32]);
+
+    // Restricting the source addresses is immediate
+    set_chain_peers(
+        &mut context,
+        &admin,
+        &payer,
+        mock_integrator::id(),
+        CHAIN_ID,
+        vec![peer, other],
+    )
+    .await
+    .unwrap();
+    set_chain_peers(
+        &mut context,
+        &admin,
+        &payer,
+        mock_integrator::id(),
+        CHAIN_ID,
+        vec![peer],
+    )
+    .await
+    .unwrap();
+
+    // Allowing a new source address or any source address must be proposed
+    for peers in [vec![peer, other], vec![]] {
+        assert_endpoint_error(
+            set_chain_peers(
+                &mut context,
+                &admin,
+                &payer,
+                mock_integrator::id(),
+                CHAIN_ID,
+                peers,
+            )
+            .await,
+            EndpointError::ConfigChangeTimelocked,
+        );
+    }
+
+    let change = ConfigChange::SetChainPeers {
+        chain_id: CHAIN_ID,
+        peers: vec![other],
+    };
+    propose_and_wait(&mut context, &admin, &payer, 0, change.clone()).await;
+    execute_config_change(&mut context, &payer, 0, change)
+        .await
+        .unwrap();
+
+    let integrator_chain_config: IntegratorChainConfig =
+        get_account(&mut context.banks_client, integrator_chain_config_pda).await;
+    assert_eq!(integrator_chain_config.peers, vec![other]);
+}
+
+#[tokio::test]
+async fn test_execute_replace_adapter() {
+    let (mut context, payer, admin) = setup_test_environment().await;
+    let integrator_program_id = mock_integrator::id();
+    let new_adapter_program_id = Keypair::new().pubkey();
+    let change = ConfigChange::ReplaceAdapter {
+        adapter_program_id: mock_adapter::id(),
+        new_adapter_program_id,
+    };
+
+    propose_and_wait(&mut context, &admin, &payer, 0, change.clone()).await;
+    execute_config_change(&mut context, &payer, 0, change)
+        .await
+        .unwrap();
+
+    let integrator_config: IntegratorConfig = get_account(
+        &mut context.banks_client,
+        IntegratorConfig::pda(&integrator_program_id).0,
+    )
+    .await;
+    assert_eq!(
+        integrator_config.adapter_infos,
+        vec![new_adapter_program_id]
+    );
+
+    // The new adapter takes over the slot and the chains of the replaced adapter
+    let adapter_info: AdapterInfo = get_account(
+        &mut context.banks_client,
+        AdapterInfo::pda(&integrator_program_id, &new_adapter_program_id).0,
+    )
+    .await;
+    assert_eq!(adapter_info.index, 0);
+    assert_eq!(adapter_info.adapter_program_id, new_adapter_program_id);
+    assert_eq!(adapter_info.enabled_count, 1);
+
+    // The adapter info of the replaced adapter is closed
+    let account = context
+        .banks_client
+        .get_account(AdapterInfo::pda(&integrator_program_id, &mock_adapter::id()).0)
+        .await
+        .unwrap();
+    assert!(account.is_none());
+}
+
+#[tokio::test]
+async fn test_execute_set_chain_adapters() {
+    let (mut context, payer, admin) = setup_test_environment().await;
+    let integrator_program_id = mock_integrator::id();
+    let adapter_info_pda = AdapterInfo::pda(&integrator_program_id, &mock_adapter::id()).0;
+    let change = ConfigChange::SetChainAdapters {
+        chain_id: CHAIN_ID,
+        send_adapter_bitmap: Bitmap::from_value(1),
+        recv_adapter_bitmap: Bitmap::from_value(1),
+    };
+
+    propose_and_wait(&mut context, &admin, &payer, 0, change.clone()).await;
+
+    // The adapter info of the changed adapter is required
+    let result = execute_config_change(&mut context, &payer, 0, change.clone()).await;
+    assert_endpoint_error(result, EndpointError::InvalidAdapterInfo);
+
+    execute_config_change_with_adapter_infos(&mut context, &payer, 0, change, &[adapter_info_pda])
+        .await
+        .unwrap();
+
+    let integrator_chain_config: IntegratorChainConfig = get_account(
+        &mut context.banks_client,
+        IntegratorChainConfig::pda(&integrator_program_id, CHAIN_ID).0,
+    )
+    .await;
+    assert_eq!(integrator_chain_config.send_adapter_bitmap.as_value(), 1);
+    assert_eq!(integrator_chain_config.recv_adapter_bitmap.as_value(), 1);
+
+    let adapter_info: AdapterInfo = get_account(&mut context.banks_client, adapter_info_pda).await;
+    assert_eq!(adapter_info.enabled_count, 2);
+}
+
+#[tokio::test]
+async fn test_execute_disable_outbound_rate_limit() {
+    let (mut context, payer, admin) = setup_test_environment().await;
+    let integrator_program_id = mock_integrator::id();
+    let integrator_chain_config_pda =
+        IntegratorChainConfig::pda(&integrator_program_id, CHAIN_ID).0;
+
+    // Enabling and tightening the rate limit are immediate
+    set_outbound_rate_limit(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_program_id,
+        CHAIN_ID,
+        2,
+        100,
+    )
+    .await
+    .unwrap();
+    set_outbound_rate_limit(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_program_id,
+        CHAIN_ID,
+        1,
+        200,
+    )
+    .await
+    .unwrap();
+
+    // Raising the limit, shortening the window and disabling the rate limit must be proposed
+    for (limit, window) in [(2, 200), (1, 100), (0, 0)] {
+        let result = set_outbound_rate_limit(
+            &mut context,
+            &admin,
+            &payer,
+            integrator_program_id,
+            CHAIN_ID,
+            limit,
+            window,
+        )
+        .await;
+        assert_endpoint_error(result, EndpointError::ConfigChangeTimelocked);
+    }
+
+    let change = ConfigChange::SetOutboundRateLimit {
+        chain_id: CHAIN_ID,
+        limit: 0,
+        window: 0,
+    };
+    propose_and_wait(&mut context, &admin, &payer, 0, change.clone()).await;
+    execute_config_change(&mut context, &payer, 0, change)
+        .await
+        .unwrap();
+
+    let integrator_chain_config: IntegratorChainConfig =
+        get_account(&mut context.banks_client, integrator_chain_config_pda).await;
+    assert!(!integrator_chain_config.outbound_rate_limit.is_enabled());
+}
+
+#[tokio::test]
+async fn test_execute_disable_inbound_rate_limit() {
+    let (mut context, payer, admin) = setup_test_environment().await;
+    let integrator_chain_config_pda =
+        IntegratorChainConfig::pda(&mock_integrator::id(), CHAIN_ID).0;
+
+    // Enabling and tightening the rate limit are immediate
+    set_inbound_rate_limit(&mut context, &admin, &payer, CHAIN_ID, 2, 100, 10)
+        .await
+        .unwrap();
+    set_inbound_rate_limit(&mut context, &admin, &payer, CHAIN_ID, 1, 200, 20)
+        .await
+        .unwrap();
+
+    // Raising the limit, shortening the window, lowering the queue delay and disabling the rate
+    // limit must be proposed
+    for (limit, window, queue_delay) in [(2, 200, 20), (1, 100, 20), (1, 200, 10), (0, 0, 20)] {
+        let result = set_inbound_rate_limit(
+            &mut context,
+            &admin,
+            &payer,
+            CHAIN_ID,
+            limit,
+            window,
+            queue_delay,
+        )
+        .await;
+        assert_endpoint_error(result, EndpointError::ConfigChangeTimelocked);
+    }
+
+    let change = ConfigChange::SetInboundRateLimit {
+        chain_id: CHAIN_ID,
+        limit: 0,
+        window: 0,
+        queue_delay: 0,
+    };
+    propose_and_wait(&mut context, &admin, &payer, 0, change.clone()).await;
+    execute_config_change(&mut context, &payer, 0, change)
+        .await
+        .unwrap();
+
+    let integrator_chain_config: IntegratorChainConfig =
+        get_account(&mut context.banks_client, integrator_chain_config_pda).await;
+    assert!(!integrator_chain_config.inbound_rate_limit.is_enabled());
+    assert_eq!(integrator_chain_config.inbound_queue_delay, 0);
+}
+
+#[tokio::test]
+async fn test_execute_invalid_accounts() {
+    let (mut context, payer, admin) = setup_test_environment().await;
+    let change = ConfigChange::EnableSendAdapter {
+        chain_id: CHAIN_ID,
+        adapter_program_id: mock_adapter::id(),
+    };
+
+    propose_and_wait(&mut context, &admin, &payer, 0, change.clone()).await;
+
+    // Passing the accounts of a threshold change leaves out the adapter info
+    let result = execute_config_change(
+        &mut context,
+        &payer,
+        0,
+        ConfigChange::SetRecvThreshold {
+            chain_id: CHAIN_ID,
+            threshold: 0,
+        },
+    )
+    .await;
+    assert_endpoint_error(result, EndpointError::InvalidConfigChangeAccounts);
+}
+
+#[tokio::test]
+async fn test_execute_after_discard_admin() {
+    let (mut context, payer, admin) = setup_test_environment().await;
+    let change = ConfigChange::AddAdapter {
+        adapter_program_id: Keypair::new().pubkey(),
+    };
+
+    propose_and_wait(&mut context, &admin, &payer, 0, change.clone()).await;
+
+    // The config is immutable once the admin is discarded, including through pending changes
+    discard_admin(
+        &mut context,
+        &admin,
+        &payer,
+        IntegratorConfig::pda(&mock_integrator::id()).0,
+    )
+    .await
+    .unwrap();
+
+    let result = execute_config_change(&mut context, &payer, 0, change).await;
+    assert_endpoint_error(result, EndpointError::CallerNotAuthorized);
+}
+
+#[tokio::test]
+async fn test_propose_config_change_invalid() {
+    let (mut context, payer, admin) = setup_test_environment().await;
+    let change = ConfigChange::SetRecvThreshold {
+        chain_id: 0,
+        threshold: 1,
+    };
+
+    let result = propose_config_change(&mut context, &admin, &payer, 0, change).await;
+    assert_endpoint_error(result, EndpointError::InvalidChainId);
+
+    let result = propose_config_change(
+        &mut context,
+        &Keypair::new(),
+        &payer,
+        0,
+        ConfigChange::SetConfigDelay { delay: 0 },
+    )
+    .await;
+    assert_endpoint_error(result, EndpointError::CallerNotAuthorized);
+}
+
+#[tokio::test]
+async fn test_cancel_config_change() {
+    let (mut context, payer, admin) = setup_test_environment().await;
+    let change = ConfigChange::AddAdapter {
+        adapter_program_id: Keypair::new().pubkey(),
+    };
+
+    for id in 0..2 {
+        propose_config_change(&mut context, &admin, &payer, id, change.clone())
+            .await
+            .unwrap();
+    }
+
+    let result = cancel_config_change(&mut context, &Keypair::new(), &payer, 0).await;
+    assert_endpoint_error(result, EndpointError::CallerNotAuthorized);
+
+    // The admin and the guardian, which is the payer, can cancel
+    cancel_config_change(&mut context, &admin, &payer, 0)
+        .await
+        .unwrap();
+    cancel_config_change(&mut context, &payer, &payer, 1)
+        .await
+        .unwrap();
+
+    for id in 0..2 {
+        let account = context
+            .banks_client
+            .get_account(PendingConfigChange::pda(&mock_integrator::id(), id).0)
+            .await
+            .unwrap();
+        assert!(account.is_none());
+    }
+}
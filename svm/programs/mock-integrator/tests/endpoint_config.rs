@@ -0,0 +1,116 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+mod instructions;
+
+use crate::instructions::initialize::initialize;
+use crate::instructions::set_paused::set_paused;
+
+use common::setup::{get_account, setup, setup_uninitialized, upgrade_authority};
+use endpoint::error::EndpointError;
+use endpoint::state::EndpointConfig;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer,
+    system_instruction::SystemError, transaction::TransactionError,
+};
+
+#[tokio::test]
+async fn test_initialize_success() {
+    let mut context = setup_uninitialized().await;
+    let payer = context.payer.insecure_clone();
+    let guardian = Keypair::new();
+
+    initialize(
+        &mut context,
+        &payer,
+        &upgrade_authority(),
+        guardian.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    let (endpoint_config_pda, bump) = EndpointConfig::pda();
+    let endpoint_config: EndpointConfig =
+        get_account(&mut context.banks_client, endpoint_config_pda).await;
+    assert_eq!(endpoint_config.bump, bump);
+    assert_eq!(endpoint_config.guardian, guardian.pubkey());
+    assert!(!endpoint_config.paused);
+}
+
+#[tokio::test]
+async fn test_initialize_not_upgrade_authority() {
+    let mut context = setup_uninitialized().await;
+    let payer = context.payer.insecure_clone();
+
+    let result = initialize(&mut context, &payer, &Keypair::new(), payer.pubkey()).await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::CallerNotAuthorized.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_initialize_reinitialization() {
+    // `setup` already initializes the endpoint
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+
+    let result = initialize(
+        &mut context,
+        &payer,
+        &upgrade_authority(),
+        Keypair::new().pubkey(),
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(SystemError::AccountAlreadyInUse as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_set_paused_success() {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+    let (endpoint_config_pda, _) = EndpointConfig::pda();
+
+    // The payer is the guardian set by `setup`
+    set_paused(&mut context, &payer, &payer, true)
+        .await
+        .unwrap();
+    let endpoint_config: EndpointConfig =
+        get_account(&mut context.banks_client, endpoint_config_pda).await;
+    assert!(endpoint_config.paused);
+
+    set_paused(&mut context, &payer, &payer, false)
+        .await
+        .unwrap();
+    let endpoint_config: EndpointConfig =
+        get_account(&mut context.banks_client, endpoint_config_pda).await;
+    assert!(!endpoint_config.paused);
+}
+
+#[tokio::test]
+async fn test_set_paused_not_guardian() {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+
+    let result = set_paused(&mut context, &payer, &Keypair::new(), true).await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::CallerNotAuthorized.into())
+        )
+    );
+}
@@ -8,11 +8,17 @@ use crate::instructions::attest_message::attest_message;
 use crate::instructions::enable_adapter::enable_recv_adapter;
 use crate::instructions::exec_message::exec_message;
 use crate::instructions::register::register;
+use crate::instructions::set_chain_paused::set_chain_paused;
+use crate::instructions::set_chain_peers::set_chain_peers;
+use crate::instructions::set_integrator_paused::set_integrator_paused;
+use crate::instructions::set_paused::set_paused;
 
 use anchor_lang::prelude::*;
 use common::setup::{get_account, setup};
 use endpoint::error::EndpointError;
-use endpoint::state::{AdapterInfo, AttestationInfo, IntegratorChainConfig, IntegratorConfig};
+use endpoint::state::{
+    AdapterInfo, AttestationInfo, IntegratorChainConfig, IntegratorConfig, ReplayBitmap,
+};
 use solana_program_test::*;
 use solana_sdk::{
     instruction::InstructionError, signature::Keypair, signer::Signer,
@@ -120,6 +126,17 @@ async fn test_exec_message_success() {
     assert_eq!(attestation_info.dst_addr, dst_addr);
     assert_eq!(attestation_info.payload_hash, payload_hash);
     assert!(attestation_info.executed);
+
+    // Verify the replay bitmap was created and marks the sequence as executed
+    let (replay_bitmap_pda, _) =
+        ReplayBitmap::pda(&mock_integrator::id(), src_chain, &src_addr, sequence);
+    let replay_bitmap: ReplayBitmap =
+        get_account(&mut context.banks_client, replay_bitmap_pda).await;
+    assert_eq!(replay_bitmap.integrator_program_id, mock_integrator::id());
+    assert_eq!(replay_bitmap.src_chain, src_chain);
+    assert_eq!(replay_bitmap.src_addr, src_addr);
+    assert_eq!(replay_bitmap.index, ReplayBitmap::index_of(sequence));
+    assert!(replay_bitmap.is_executed(sequence));
 }
 
 #[tokio::test]
@@ -203,3 +220,369 @@ async fn test_exec_message_zero_chain_id() {
         )
     );
 }
+
+#[tokio::test]
+async fn test_exec_message_shares_replay_bitmap() {
+    let (mut context, payer, _, _, chain_id) = setup_test_environment().await;
+
+    let src_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let dst_addr = UniversalAddress::from_pubkey(&mock_integrator::id());
+
+    for sequence in [1, 2] {
+        exec_message(
+            &mut context,
+            &payer,
+            chain_id,
+            src_addr,
+            sequence,
+            1,
+            dst_addr,
+            [3u8; 32],
+        )
+        .await
+        .unwrap();
+    }
+
+    // Both sequences are tracked by the same replay bitmap account
+    let (replay_bitmap_pda, _) = ReplayBitmap::pda(&mock_integrator::id(), chain_id, &src_addr, 1);
+    let replay_bitmap: ReplayBitmap =
+        get_account(&mut context.banks_client, replay_bitmap_pda).await;
+    assert!(replay_bitmap.is_executed(1));
+    assert!(replay_bitmap.is_executed(2));
+    assert!(!replay_bitmap.is_executed(3));
+}
+
+#[tokio::test]
+async fn test_exec_message_executed_sequence_different_payload() {
+    let (mut context, payer, _, _, chain_id) = setup_test_environment().await;
+
+    let src_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let dst_addr = UniversalAddress::from_pubkey(&mock_integrator::id());
+
+    exec_message(
+        &mut context,
+        &payer,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        dst_addr,
+        [3u8; 32],
+    )
+    .await
+    .unwrap();
+
+    // Replay protection is per sequence, so a different payload cannot reuse it
+    let result = exec_message(
+        &mut context,
+        &payer,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        dst_addr,
+        [4u8; 32],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::AlreadyExecuted.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_exec_message_invalid_destination_chain() {
+    let (mut context, payer, _, _, chain_id) = setup_test_environment().await;
+
+    let src_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let sequence: u64 = 1;
+    let dst_chain = 3; // Not this chain
+    let dst_addr = UniversalAddress::from_pubkey(&mock_integrator::id());
+    let payload_hash = [3u8; 32];
+
+    let result = exec_message(
+        &mut context,
+        &payer,
+        chain_id,
+        src_addr,
+        sequence,
+        dst_chain,
+        dst_addr,
+        payload_hash,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::InvalidDestinationChain.into())
+        )
+    );
+
+    // No attestation info nor replay bitmap is left behind
+    let (attestation_info_pda, _) = AttestationInfo::pda(AttestationInfo::compute_message_hash(
+        chain_id,
+        src_addr,
+        sequence,
+        dst_chain,
+        dst_addr,
+        payload_hash,
+    ));
+    let (replay_bitmap_pda, _) =
+        ReplayBitmap::pda(&mock_integrator::id(), chain_id, &src_addr, sequence);
+    for account in [attestation_info_pda, replay_bitmap_pda] {
+        assert!(context
+            .banks_client
+            .get_account(account)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
+
+#[tokio::test]
+async fn test_exec_message_executed_message_other_destination_chain() {
+    let (mut context, payer, _, _, chain_id) = setup_test_environment().await;
+
+    let src_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let sequence: u64 = 1;
+    let dst_addr = UniversalAddress::from_pubkey(&mock_integrator::id());
+    let payload_hash = [3u8; 32];
+
+    exec_message(
+        &mut context,
+        &payer,
+        chain_id,
+        src_addr,
+        sequence,
+        1,
+        dst_addr,
+        payload_hash,
+    )
+    .await
+    .unwrap();
+
+    // The same message destined to another chain is rejected for its destination
+    let result = exec_message(
+        &mut context,
+        &payer,
+        chain_id,
+        src_addr,
+        sequence,
+        2,
+        dst_addr,
+        payload_hash,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::InvalidDestinationChain.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_exec_message_paused() {
+    let (mut context, payer, _, _, chain_id) = setup_test_environment().await;
+
+    let src_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let dst_addr = UniversalAddress::from_pubkey(&mock_integrator::id());
+
+    // The payer is the guardian set by `setup`
+    set_paused(&mut context, &payer, &payer, true)
+        .await
+        .unwrap();
+
+    let result = exec_message(
+        &mut context,
+        &payer,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        dst_addr,
+        [3u8; 32],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::EndpointPaused.into())
+        )
+    );
+
+    // Executing works again once unpaused
+    set_paused(&mut context, &payer, &payer, false)
+        .await
+        .unwrap();
+
+    exec_message(
+        &mut context,
+        &payer,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        dst_addr,
+        [3u8; 32],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_exec_message_integrator_paused() {
+    let (mut context, payer, admin, _, chain_id) = setup_test_environment().await;
+
+    let src_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let dst_addr = UniversalAddress::from_pubkey(&mock_integrator::id());
+
+    set_integrator_paused(
+        &mut context,
+        &admin,
+        &payer,
+        mock_integrator::id(),
+        false,
+        true,
+    )
+    .await
+    .unwrap();
+
+    let result = exec_message(
+        &mut context,
+        &payer,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        dst_addr,
+        [3u8; 32],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::RecvPaused.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_exec_message_chain_paused() {
+    let (mut context, payer, admin, _, chain_id) = setup_test_environment().await;
+
+    let src_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let dst_addr = UniversalAddress::from_pubkey(&mock_integrator::id());
+
+    set_chain_paused(
+        &mut context,
+        &admin,
+        &payer,
+        mock_integrator::id(),
+        chain_id,
+        false,
+        true,
+    )
+    .await
+    .unwrap();
+
+    let result = exec_message(
+        &mut context,
+        &payer,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        dst_addr,
+        [3u8; 32],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::RecvPaused.into())
+        )
+    );
+
+    // Messages from other chains can still be executed, even without an integrator chain config
+    let other_chain_id = chain_id + 1;
+    exec_message(
+        &mut context,
+        &payer,
+        other_chain_id,
+        src_addr,
+        1,
+        1,
+        dst_addr,
+        [3u8; 32],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_exec_message_unknown_peer() {
+    let (mut context, payer, admin, _, chain_id) = setup_test_environment().await;
+
+    let src_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let peer = UniversalAddress::from_bytes([2u8; 32]);
+    let dst_addr = UniversalAddress::from_pubkey(&mock_integrator::id());
+
+    set_chain_peers(
+        &mut context,
+        &admin,
+        &payer,
+        mock_integrator::id(),
+        chain_id,
+        vec![peer],
+    )
+    .await
+    .unwrap();
+
+    let result = exec_message(
+        &mut context,
+        &payer,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        dst_addr,
+        [3u8; 32],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::UnknownPeer.into())
+        )
+    );
+
+    exec_message(
+        &mut context,
+        &payer,
+        chain_id,
+        peer,
+        1,
+        1,
+        dst_addr,
+        [3u8; 32],
+    )
+    .await
+    .unwrap();
+}
@@ -0,0 +1,276 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+mod instructions;
+
+use crate::instructions::add_adapter::add_adapter;
+use crate::instructions::attest_message::attest_message;
+use crate::instructions::enable_adapter::enable_recv_adapter;
+use crate::instructions::exec_message::exec_message;
+use crate::instructions::get_message_status::get_message_status;
+use crate::instructions::recv_message::recv_message;
+use crate::instructions::register::register;
+
+use anchor_lang::prelude::*;
+use common::setup::setup;
+use endpoint::instructions::MessageStatus;
+use endpoint::state::{AdapterInfo, AttestationInfo, IntegratorChainConfig, IntegratorConfig};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, transaction::TransactionError,
+};
+use universal_address::UniversalAddress;
+
+const SRC_CHAIN: u16 = 2;
+const SEQUENCE: u64 = 1;
+const DST_CHAIN: u16 = 1;
+const PAYLOAD_HASH: [u8; 32] = [3u8; 32];
+
+fn src_addr() -> UniversalAddress {
+    UniversalAddress::from_bytes([1u8; 32])
+}
+
+async fn setup_test_environment() -> (ProgramTestContext, Keypair, Pubkey, Pubkey, Pubkey) {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+    let admin = Keypair::new();
+    let integrator_program_id = mock_integrator::id();
+
+    let (integrator_config_pda, _) = IntegratorConfig::pda(&integrator_program_id);
+    let (integrator_chain_config_pda, _) =
+        IntegratorChainConfig::pda(&integrator_program_id, SRC_CHAIN);
+
+    register(
+        &mut context,
+        &payer,
+        &admin,
+        integrator_config_pda,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    let adapter_program_id = mock_adapter::id();
+    let (adapter_info_pda, _) = AdapterInfo::pda(&integrator_program_id, &adapter_program_id);
+    let (adapter_pda, _) = Pubkey::find_program_address(&[b"adapter_pda"], &adapter_program_id);
+
+    add_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        adapter_info_pda,
+        integrator_program_id,
+        adapter_program_id,
+    )
+    .await
+    .unwrap();
+
+    enable_recv_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        SRC_CHAIN,
+        adapter_program_id,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    (
+        context,
+        payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+    )
+}
+
+async fn message_status(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+) -> std::result::Result<MessageStatus, TransactionError> {
+    get_message_status(
+        context,
+        payer,
+        SRC_CHAIN,
+        src_addr(),
+        SEQUENCE,
+        DST_CHAIN,
+        mock_integrator::id(),
+        PAYLOAD_HASH,
+    )
+    .await
+}
+
+async fn attest(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    integrator_chain_config_pda: Pubkey,
+    adapter_info_pda: Pubkey,
+    adapter_pda: Pubkey,
+) {
+    attest_message(
+        context,
+        payer,
+        adapter_info_pda,
+        adapter_pda,
+        integrator_chain_config_pda,
+        SRC_CHAIN,
+        src_addr(),
+        SEQUENCE,
+        DST_CHAIN,
+        UniversalAddress::from_pubkey(&mock_integrator::id()),
+        PAYLOAD_HASH,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_get_message_status_not_attested() {
+    let (mut context, payer, _, _, _) = setup_test_environment().await;
+
+    let status = message_status(&mut context, &payer).await.unwrap();
+
+    assert_eq!(
+        status,
+        MessageStatus {
+            enabled_bitmap: 1,
+            attested_bitmap: 0,
+            executed: false,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_get_message_status_attested() {
+    let (mut context, payer, integrator_chain_config_pda, adapter_info_pda, adapter_pda) =
+        setup_test_environment().await;
+
+    attest(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+    )
+    .await;
+
+    let status = message_status(&mut context, &payer).await.unwrap();
+
+    assert_eq!(
+        status,
+        MessageStatus {
+            enabled_bitmap: 1,
+            attested_bitmap: 1,
+            executed: false,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_get_message_status_received() {
+    let (mut context, payer, integrator_chain_config_pda, adapter_info_pda, adapter_pda) =
+        setup_test_environment().await;
+
+    attest(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+    )
+    .await;
+
+    let (attestation_info_pda, _) = AttestationInfo::pda(AttestationInfo::compute_message_hash(
+        SRC_CHAIN,
+        src_addr(),
+        SEQUENCE,
+        DST_CHAIN,
+        UniversalAddress::from_pubkey(&mock_integrator::id()),
+        PAYLOAD_HASH,
+    ));
+
+    recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        SRC_CHAIN,
+        src_addr(),
+        SEQUENCE,
+        DST_CHAIN,
+        PAYLOAD_HASH,
+    )
+    .await
+    .unwrap();
+
+    let status = message_status(&mut context, &payer).await.unwrap();
+
+    assert_eq!(
+        status,
+        MessageStatus {
+            enabled_bitmap: 1,
+            attested_bitmap: 1,
+            executed: true,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_get_message_status_executed_without_attestation() {
+    let (mut context, payer, _, _, _) = setup_test_environment().await;
+
+    exec_message(
+        &mut context,
+        &payer,
+        SRC_CHAIN,
+        src_addr(),
+        SEQUENCE,
+        DST_CHAIN,
+        UniversalAddress::from_pubkey(&mock_integrator::id()),
+        PAYLOAD_HASH,
+    )
+    .await
+    .unwrap();
+
+    let status = message_status(&mut context, &payer).await.unwrap();
+
+    assert_eq!(
+        status,
+        MessageStatus {
+            enabled_bitmap: 1,
+            attested_bitmap: 0,
+            executed: true,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_get_message_status_unknown_chain() {
+    let (mut context, payer, _, _, _) = setup_test_environment().await;
+
+    let result = get_message_status(
+        &mut context,
+        &payer,
+        SRC_CHAIN + 1,
+        src_addr(),
+        SEQUENCE,
+        DST_CHAIN,
+        mock_integrator::id(),
+        PAYLOAD_HASH,
+    )
+    .await;
+
+    // No adapter was ever enabled for this chain
+    assert_eq!(
+        result.unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::AccountNotInitialized.into())
+        )
+    );
+}
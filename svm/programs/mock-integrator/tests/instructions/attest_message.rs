@@ -1,5 +1,5 @@
 use anchor_lang::{InstructionData, ToAccountMetas};
-use endpoint::state::AttestationInfo;
+use endpoint::state::{AttestationInfo, EndpointConfig, IntegratorConfig, ReplayBitmap};
 use mock_adapter::{accounts::InvokeAttestMessage, InvokeAttestMessageArgs};
 use solana_program_test::*;
 use solana_sdk::{
@@ -33,6 +33,8 @@ pub async fn attest_message(
         payload_hash,
     );
     let (attestation_info, _) = AttestationInfo::pda(message_hash);
+    let (replay_bitmap, _) =
+        ReplayBitmap::pda(&mock_integrator::id(), src_chain, &src_addr, sequence);
     let (event_authority, _) =
         Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
 
@@ -40,8 +42,11 @@ pub async fn attest_message(
         payer: payer.pubkey(),
         adapter_info,
         adapter_pda,
+        integrator_config: IntegratorConfig::pda(&mock_integrator::id()).0,
         integrator_chain_config,
         attestation_info,
+        replay_bitmap,
+        endpoint_config: EndpointConfig::pda().0,
         system_program: solana_sdk::system_program::id(),
         endpoint_program: endpoint::id(),
         program: endpoint::id(),
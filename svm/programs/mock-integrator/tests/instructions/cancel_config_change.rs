@@ -0,0 +1,43 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::{
+    accounts::CancelConfigChange,
+    state::{EndpointConfig, IntegratorConfig, PendingConfigChange},
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+};
+
+use crate::common::execute_transaction::execute_transaction;
+
+/// Cancels a config change proposed by `payer`
+pub async fn cancel_config_change(
+    context: &mut ProgramTestContext,
+    authority: &Keypair,
+    payer: &Keypair,
+    id: u64,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+    let integrator_program_id = mock_integrator::id();
+
+    let accounts = CancelConfigChange {
+        authority: authority.pubkey(),
+        endpoint_config: EndpointConfig::pda().0,
+        integrator_config: IntegratorConfig::pda(&integrator_program_id).0,
+        pending_config_change: PendingConfigChange::pda(&integrator_program_id, id).0,
+        rent_recipient: payer.pubkey(),
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::CancelConfigChange {}.data(),
+    };
+
+    execute_transaction(context, ix, &[authority, payer], payer).await
+}
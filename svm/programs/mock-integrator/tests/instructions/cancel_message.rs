@@ -0,0 +1,37 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use mock_integrator::{accounts::InvokeCancelMessage, InvokeCancelMessageArgs};
+use solana_program_test::*;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signer::keypair::Keypair};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn cancel_message(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    integrator_program_pda: Pubkey,
+    outbox_message: Pubkey,
+    refund_recipient: Pubkey,
+    sequence: u64,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+
+    let accounts = InvokeCancelMessage {
+        integrator_program_pda,
+        outbox_message,
+        refund_recipient,
+        event_authority,
+        program: endpoint::id(),
+        endpoint_program: endpoint::id(),
+    };
+
+    let args = InvokeCancelMessageArgs { sequence };
+
+    let ix = Instruction {
+        program_id: mock_integrator::id(),
+        accounts: accounts.to_account_metas(None),
+        data: mock_integrator::instruction::InvokeCancelMessage { args }.data(),
+    };
+
+    execute_transaction(context, ix, &[payer], payer).await
+}
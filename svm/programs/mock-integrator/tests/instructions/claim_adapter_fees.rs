@@ -0,0 +1,34 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::state::AdapterFeeVault;
+use mock_adapter::accounts::InvokeClaimAdapterFees;
+use solana_program_test::*;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signer::keypair::Keypair};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn claim_adapter_fees(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    adapter_pda: Pubkey,
+    recipient: Pubkey,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+
+    let accounts = InvokeClaimAdapterFees {
+        adapter_pda,
+        adapter_fee_vault: AdapterFeeVault::pda(&mock_adapter::id()).0,
+        recipient,
+        event_authority,
+        program: endpoint::id(),
+        endpoint_program: endpoint::id(),
+    };
+
+    let ix = Instruction {
+        program_id: mock_adapter::id(),
+        accounts: accounts.to_account_metas(None),
+        data: mock_adapter::instruction::InvokeClaimAdapterFees {}.data(),
+    };
+
+    execute_transaction(context, ix, &[payer], payer).await
+}
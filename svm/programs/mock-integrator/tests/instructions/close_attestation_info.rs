@@ -0,0 +1,33 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::accounts::CloseAttestationInfo;
+use solana_program_test::*;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signer::keypair::Keypair};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn close_attestation_info(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    attestation_info: Pubkey,
+    rent_recipient: Pubkey,
+    replay_bitmap: Pubkey,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+
+    let accounts = CloseAttestationInfo {
+        attestation_info,
+        rent_recipient,
+        replay_bitmap,
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::CloseAttestationInfo {}.data(),
+    };
+
+    execute_transaction(context, ix, &[payer], payer).await
+}
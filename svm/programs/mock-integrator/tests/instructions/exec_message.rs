@@ -1,5 +1,10 @@
 use anchor_lang::{InstructionData, ToAccountMetas};
-use endpoint::{instructions::ExecMessageArgs, state::AttestationInfo};
+use endpoint::{
+    instructions::ExecMessageArgs,
+    state::{
+        AttestationInfo, EndpointConfig, IntegratorChainConfig, IntegratorConfig, ReplayBitmap,
+    },
+};
 use mock_integrator::accounts::InvokeExecMessage;
 use solana_program_test::*;
 use solana_sdk::{
@@ -35,11 +40,20 @@ pub async fn exec_message(
         payload_hash,
     );
     let (attestation_info, _) = AttestationInfo::pda(message_hash);
+    let (replay_bitmap, _) =
+        ReplayBitmap::pda(&mock_integrator::id(), src_chain, &src_addr, sequence);
+    let (integrator_config, _) = IntegratorConfig::pda(&mock_integrator::id());
+    let (integrator_chain_config, _) =
+        IntegratorChainConfig::pda(&mock_integrator::id(), src_chain);
 
     let accounts = InvokeExecMessage {
         payer: payer.pubkey(),
         integrator_program_pda,
         attestation_info,
+        replay_bitmap,
+        integrator_config,
+        integrator_chain_config,
+        endpoint_config: EndpointConfig::pda().0,
         system_program: solana_sdk::system_program::id(),
         endpoint_program: endpoint::id(),
         program: endpoint::id(),
@@ -0,0 +1,77 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::{
+    accounts::ExecuteConfigChange,
+    state::{
+        AdapterInfo, ConfigChange, IntegratorChainConfig, IntegratorConfig, PendingConfigChange,
+    },
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+};
+
+use crate::common::execute_transaction::execute_transaction;
+
+/// Executes a config change proposed by `payer`, passing the accounts the change applies to
+pub async fn execute_config_change(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    id: u64,
+    change: ConfigChange,
+) -> Result<(), BanksClientError> {
+    execute_config_change_with_adapter_infos(context, payer, id, change, &[]).await
+}
+
+/// Executes a config change proposed by `payer`, passing the adapter infos of the adapters
+/// changed by a `SetChainAdapters` change as remaining accounts
+pub async fn execute_config_change_with_adapter_infos(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    id: u64,
+    change: ConfigChange,
+    adapter_infos: &[Pubkey],
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+    let integrator_program_id = mock_integrator::id();
+
+    let adapter_info = change
+        .adapter_program_id()
+        .map(|adapter_program_id| AdapterInfo::pda(&integrator_program_id, &adapter_program_id).0);
+    let new_adapter_info = change
+        .new_adapter_program_id()
+        .map(|adapter_program_id| AdapterInfo::pda(&integrator_program_id, &adapter_program_id).0);
+    let integrator_chain_config = change
+        .chain_id()
+        .map(|chain_id| IntegratorChainConfig::pda(&integrator_program_id, chain_id).0);
+
+    let accounts = ExecuteConfigChange {
+        payer: payer.pubkey(),
+        integrator_config: IntegratorConfig::pda(&integrator_program_id).0,
+        pending_config_change: PendingConfigChange::pda(&integrator_program_id, id).0,
+        rent_recipient: payer.pubkey(),
+        new_adapter_info,
+        adapter_info,
+        integrator_chain_config,
+        system_program: solana_sdk::system_program::id(),
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let mut accounts = accounts.to_account_metas(None);
+    accounts.extend(
+        adapter_infos
+            .iter()
+            .map(|adapter_info| AccountMeta::new(*adapter_info, false)),
+    );
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts,
+        data: endpoint::instruction::ExecuteConfigChange {}.data(),
+    };
+
+    execute_transaction(context, ix, &[payer], payer).await
+}
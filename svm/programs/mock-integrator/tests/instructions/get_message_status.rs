@@ -0,0 +1,61 @@
+use anchor_lang::{AnchorDeserialize, InstructionData, ToAccountMetas};
+use endpoint::accounts::GetMessageStatus;
+use endpoint::instructions::{GetMessageStatusArgs, MessageStatus};
+use endpoint::state::{AttestationInfo, IntegratorChainConfig, IntegratorConfig, ReplayBitmap};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signer::keypair::Keypair,
+    transaction::TransactionError,
+};
+use universal_address::UniversalAddress;
+
+use crate::common::execute_transaction::simulate_transaction;
+
+pub async fn get_message_status(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    src_chain: u16,
+    src_addr: UniversalAddress,
+    sequence: u64,
+    dst_chain: u16,
+    integrator_program_id: Pubkey,
+    payload_hash: [u8; 32],
+) -> Result<MessageStatus, TransactionError> {
+    let (integrator_chain_config, _) =
+        IntegratorChainConfig::pda(&integrator_program_id, src_chain);
+    let (attestation_info, _) = AttestationInfo::pda(AttestationInfo::compute_message_hash(
+        src_chain,
+        src_addr,
+        sequence,
+        dst_chain,
+        UniversalAddress::from_pubkey(&integrator_program_id),
+        payload_hash,
+    ));
+    let (replay_bitmap, _) =
+        ReplayBitmap::pda(&integrator_program_id, src_chain, &src_addr, sequence);
+
+    let accounts = GetMessageStatus {
+        integrator_config: IntegratorConfig::pda(&integrator_program_id).0,
+        integrator_chain_config,
+        attestation_info,
+        replay_bitmap,
+    };
+
+    let args = GetMessageStatusArgs {
+        src_chain,
+        src_addr,
+        sequence,
+        dst_chain,
+        integrator_program_id,
+        payload_hash,
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::GetMessageStatus { args }.data(),
+    };
+
+    let return_data = simulate_transaction(context, ix, &[payer], payer).await?;
+    Ok(MessageStatus::try_from_slice(&return_data).unwrap())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::{AnchorDeserialize, InstructionData, ToAccountMetas};
+use endpoint::accounts::GetOutboundCapacity;
+use endpoint::instructions::GetOutboundCapacityArgs;
+use endpoint::state::IntegratorChainConfig;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signer::keypair::Keypair,
+    transaction::TransactionError,
+};
+
+use crate::common::execute_transaction::simulate_transaction;
+
+pub async fn get_outbound_capacity(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    integrator_program_id: Pubkey,
+    dst_chain: u16,
+) -> Result<u64, TransactionError> {
+    let accounts = GetOutboundCapacity {
+        integrator_chain_config: IntegratorChainConfig::pda(&integrator_program_id, dst_chain).0,
+    };
+
+    let args = GetOutboundCapacityArgs {
+        integrator_program_id,
+        dst_chain,
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::GetOutboundCapacity { args }.data(),
+    };
+
+    let return_data = simulate_transaction(context, ix, &[payer], payer).await?;
+    Ok(u64::try_from_slice(&return_data).unwrap())
+}
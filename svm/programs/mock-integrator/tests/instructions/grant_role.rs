@@ -0,0 +1,47 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::{
+    accounts::GrantRole,
+    instructions::GrantRoleArgs,
+    state::{IntegratorConfig, Role},
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn grant_role(
+    context: &mut ProgramTestContext,
+    admin: &Keypair,
+    payer: &Keypair,
+    role: Role,
+    account: Pubkey,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+    let integrator_program_id = mock_integrator::id();
+
+    let accounts = GrantRole {
+        admin: admin.pubkey(),
+        integrator_config: IntegratorConfig::pda(&integrator_program_id).0,
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let args = GrantRoleArgs {
+        integrator_program_id,
+        role,
+        account,
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::GrantRole { args }.data(),
+    };
+
+    execute_transaction(context, ix, &[admin, payer], payer).await
+}
@@ -0,0 +1,35 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::{
+    accounts::InitAdapterFeeVault, instructions::InitAdapterFeeVaultArgs, state::AdapterFeeVault,
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn init_adapter_fee_vault(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    adapter_program_id: Pubkey,
+) -> Result<(), BanksClientError> {
+    let accounts = InitAdapterFeeVault {
+        payer: payer.pubkey(),
+        adapter_fee_vault: AdapterFeeVault::pda(&adapter_program_id).0,
+        system_program: solana_sdk::system_program::id(),
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::InitAdapterFeeVault {
+            args: InitAdapterFeeVaultArgs { adapter_program_id },
+        }
+        .data(),
+    };
+
+    execute_transaction(context, ix, &[payer], payer).await
+}
@@ -0,0 +1,44 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::{accounts::Initialize, instructions::InitializeArgs, state::EndpointConfig};
+use solana_program_test::*;
+use solana_sdk::{
+    bpf_loader_upgradeable,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn initialize(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    upgrade_authority: &Keypair,
+    guardian: Pubkey,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+    let (program_data, _) =
+        Pubkey::find_program_address(&[endpoint::id().as_ref()], &bpf_loader_upgradeable::id());
+
+    let accounts = Initialize {
+        payer: payer.pubkey(),
+        upgrade_authority: upgrade_authority.pubkey(),
+        program_data,
+        endpoint_config: EndpointConfig::pda().0,
+        system_program: solana_sdk::system_program::id(),
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::Initialize {
+            args: InitializeArgs { guardian },
+        }
+        .data(),
+    };
+
+    execute_transaction(context, ix, &[payer, upgrade_authority], payer).await
+}
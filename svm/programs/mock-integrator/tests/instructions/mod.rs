@@ -1,12 +1,43 @@
 pub mod add_adapter;
 pub mod attest_message;
+pub mod cancel_config_change;
+pub mod cancel_message;
+pub mod claim_adapter_fees;
+pub mod close_attestation_info;
 pub mod disable_adapter;
 pub mod discard_admin;
 pub mod enable_adapter;
 pub mod exec_message;
+pub mod execute_config_change;
+pub mod get_message_status;
+pub mod get_outbound_capacity;
+pub mod grant_role;
+pub mod init_adapter_fee_vault;
+pub mod initialize;
 pub mod pick_up_message;
+pub mod propose_config_change;
+pub mod quote_delivery_price;
+pub mod reclaim_outbox_message;
 pub mod recv_message;
 pub mod register;
+pub mod release_queued_message;
+pub mod remove_adapter;
+pub mod replace_adapter;
+pub mod revoke_role;
 pub mod send_message;
+pub mod set_admin_signers;
+pub mod set_chain_adapters;
+pub mod set_chain_paused;
+pub mod set_chain_peers;
+pub mod set_config_delay;
+pub mod set_enforce_peers;
+pub mod set_inbound_rate_limit;
+pub mod set_integrator_paused;
+pub mod set_min_attestation_age;
+pub mod set_outbound_rate_limit;
+pub mod set_outbox_message_timeout;
+pub mod set_paused;
+pub mod set_recv_threshold;
 pub mod transfer_admin;
 pub mod update_admin;
+pub mod update_threshold_reached_slot;
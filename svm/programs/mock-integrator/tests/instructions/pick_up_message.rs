@@ -1,4 +1,5 @@
 use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::state::{AdapterFeeVault, EndpointConfig, IntegratorConfig};
 use mock_adapter::accounts::InvokePickUpMessage;
 use solana_program_test::*;
 use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signer::keypair::Keypair};
@@ -19,7 +20,10 @@ pub async fn pick_up_message(
     let accounts = InvokePickUpMessage {
         outbox_message,
         adapter_info,
+        integrator_config: IntegratorConfig::pda(&mock_integrator::id()).0,
         adapter_pda,
+        adapter_fee_vault: AdapterFeeVault::pda(&mock_adapter::id()).0,
+        endpoint_config: EndpointConfig::pda().0,
         system_program: solana_sdk::system_program::id(),
         endpoint_program: endpoint::id(),
         refund_recipient,
@@ -0,0 +1,49 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::{
+    accounts::ProposeConfigChange,
+    instructions::ProposeConfigChangeArgs,
+    state::{ConfigChange, IntegratorConfig, PendingConfigChange},
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn propose_config_change(
+    context: &mut ProgramTestContext,
+    admin: &Keypair,
+    payer: &Keypair,
+    id: u64,
+    change: ConfigChange,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+    let integrator_program_id = mock_integrator::id();
+
+    let accounts = ProposeConfigChange {
+        payer: payer.pubkey(),
+        admin: admin.pubkey(),
+        integrator_config: IntegratorConfig::pda(&integrator_program_id).0,
+        pending_config_change: PendingConfigChange::pda(&integrator_program_id, id).0,
+        system_program: solana_sdk::system_program::id(),
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let args = ProposeConfigChangeArgs {
+        integrator_program_id,
+        change,
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::ProposeConfigChange { args }.data(),
+    };
+
+    execute_transaction(context, ix, &[admin, payer], payer).await
+}
@@ -0,0 +1,52 @@
+use anchor_lang::{AnchorDeserialize, InstructionData, ToAccountMetas};
+use endpoint::accounts::QuoteDeliveryPrice;
+use endpoint::instructions::QuoteDeliveryPriceArgs;
+use endpoint::state::{IntegratorChainConfig, IntegratorConfig};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signer::keypair::Keypair,
+    transaction::TransactionError,
+};
+
+use crate::common::execute_transaction::simulate_transaction;
+
+pub async fn quote_delivery_price(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    integrator_program_id: Pubkey,
+    dst_chain: u16,
+    adapter_programs: &[Pubkey],
+    adapter_instructions: Vec<u8>,
+) -> Result<u64, TransactionError> {
+    let (integrator_config, _) = IntegratorConfig::pda(&integrator_program_id);
+    let (integrator_chain_config, _) =
+        IntegratorChainConfig::pda(&integrator_program_id, dst_chain);
+
+    let mut accounts = QuoteDeliveryPrice {
+        integrator_config,
+        integrator_chain_config,
+    }
+    .to_account_metas(None);
+    accounts.extend(
+        adapter_programs
+            .iter()
+            .map(|adapter_program| AccountMeta::new_readonly(*adapter_program, false)),
+    );
+
+    let args = QuoteDeliveryPriceArgs {
+        integrator_program_id,
+        dst_chain,
+        adapter_instructions,
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts,
+        data: endpoint::instruction::QuoteDeliveryPrice { args }.data(),
+    };
+
+    let return_data = simulate_transaction(context, ix, &[payer], payer).await?;
+    Ok(u64::try_from_slice(&return_data).unwrap())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::accounts::ReclaimOutboxMessage;
+use solana_program_test::*;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signer::keypair::Keypair};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn reclaim_outbox_message(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    integrator_config: Pubkey,
+    outbox_message: Pubkey,
+    refund_recipient: Pubkey,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+
+    let accounts = ReclaimOutboxMessage {
+        integrator_config,
+        outbox_message,
+        refund_recipient,
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::ReclaimOutboxMessage {}.data(),
+    };
+
+    execute_transaction(context, ix, &[payer], payer).await
+}
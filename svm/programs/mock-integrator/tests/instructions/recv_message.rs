@@ -1,5 +1,8 @@
 use anchor_lang::{InstructionData, ToAccountMetas};
-use endpoint::{instructions::recv_message::RecvMessageArgs, state::IntegratorChainConfig};
+use endpoint::{
+    instructions::recv_message::RecvMessageArgs,
+    state::{EndpointConfig, IntegratorChainConfig, IntegratorConfig, ReplayBitmap},
+};
 use mock_integrator::accounts::InvokeRecvMessage;
 use solana_program_test::*;
 use solana_sdk::{
@@ -11,7 +14,7 @@ use universal_address::UniversalAddress;
 
 use crate::common::execute_transaction::execute_transaction;
 
-pub async fn recv_message(
+async fn execute_recv_message(
     context: &mut ProgramTestContext,
     payer: &Keypair,
     attestation_info: Pubkey,
@@ -20,20 +23,27 @@ pub async fn recv_message(
     sequence: u64,
     dst_chain: u16,
     payload_hash: [u8; 32],
+    queue_if_rate_limited: bool,
 ) -> Result<(), BanksClientError> {
     let (integrator_program_pda, integrator_program_pda_bump) =
         Pubkey::find_program_address(&[b"endpoint_integrator"], &mock_integrator::id());
     let (event_authority, _) =
         Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
     let integrator_program_id = mock_integrator::id();
+    let (integrator_config, _) = IntegratorConfig::pda(&integrator_program_id);
     let (integrator_chain_config, _) =
         IntegratorChainConfig::pda(&integrator_program_id, src_chain);
+    let (replay_bitmap, _) =
+        ReplayBitmap::pda(&integrator_program_id, src_chain, &src_addr, sequence);
 
     let accounts = InvokeRecvMessage {
         payer: payer.pubkey(),
         integrator_program_pda,
         attestation_info,
+        replay_bitmap,
+        endpoint_config: EndpointConfig::pda().0,
         system_program: solana_sdk::system_program::id(),
+        integrator_config,
         integrator_chain_config,
         endpoint_program: endpoint::id(),
         program: endpoint::id(),
@@ -48,6 +58,7 @@ pub async fn recv_message(
         dst_chain,
         integrator_program_id: mock_integrator::id(),
         payload_hash,
+        queue_if_rate_limited,
     };
 
     let ix = Instruction {
@@ -58,3 +69,52 @@ pub async fn recv_message(
 
     execute_transaction(context, ix, &[payer], payer).await
 }
+
+pub async fn recv_message(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    attestation_info: Pubkey,
+    src_chain: u16,
+    src_addr: UniversalAddress,
+    sequence: u64,
+    dst_chain: u16,
+    payload_hash: [u8; 32],
+) -> Result<(), BanksClientError> {
+    execute_recv_message(
+        context,
+        payer,
+        attestation_info,
+        src_chain,
+        src_addr,
+        sequence,
+        dst_chain,
+        payload_hash,
+        false,
+    )
+    .await
+}
+
+/// Receives a message, queueing it if it exceeds the inbound rate limit
+pub async fn recv_message_or_queue(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    attestation_info: Pubkey,
+    src_chain: u16,
+    src_addr: UniversalAddress,
+    sequence: u64,
+    dst_chain: u16,
+    payload_hash: [u8; 32],
+) -> Result<(), BanksClientError> {
+    execute_recv_message(
+        context,
+        payer,
+        attestation_info,
+        src_chain,
+        src_addr,
+        sequence,
+        dst_chain,
+        payload_hash,
+        true,
+    )
+    .await
+}
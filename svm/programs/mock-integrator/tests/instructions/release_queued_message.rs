@@ -0,0 +1,29 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::accounts::ReleaseQueuedMessage;
+use solana_program_test::*;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signer::keypair::Keypair};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn release_queued_message(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    attestation_info: Pubkey,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+
+    let accounts = ReleaseQueuedMessage {
+        attestation_info,
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::ReleaseQueuedMessage {}.data(),
+    };
+
+    execute_transaction(context, ix, &[payer], payer).await
+}
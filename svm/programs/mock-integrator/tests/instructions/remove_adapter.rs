@@ -0,0 +1,46 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::accounts::RemoveAdapter;
+use endpoint::instructions::RemoveAdapterArgs;
+use endpoint::state::{AdapterInfo, IntegratorConfig};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn remove_adapter(
+    context: &mut ProgramTestContext,
+    admin: &Keypair,
+    payer: &Keypair,
+    rent_recipient: Pubkey,
+    integrator_program_id: Pubkey,
+    adapter_program_id: Pubkey,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+
+    let accounts = RemoveAdapter {
+        admin: admin.pubkey(),
+        integrator_config: IntegratorConfig::pda(&integrator_program_id).0,
+        adapter_info: AdapterInfo::pda(&integrator_program_id, &adapter_program_id).0,
+        rent_recipient,
+        program: endpoint::id(),
+        event_authority,
+    };
+
+    let args = RemoveAdapterArgs {
+        integrator_program_id,
+        adapter_program_id,
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::RemoveAdapter { args }.data(),
+    };
+
+    execute_transaction(context, ix, &[admin, payer], payer).await
+}
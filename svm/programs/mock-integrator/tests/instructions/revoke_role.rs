@@ -0,0 +1,45 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::{
+    accounts::RevokeRole,
+    instructions::RevokeRoleArgs,
+    state::{IntegratorConfig, Role},
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn revoke_role(
+    context: &mut ProgramTestContext,
+    admin: &Keypair,
+    payer: &Keypair,
+    role: Role,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+    let integrator_program_id = mock_integrator::id();
+
+    let accounts = RevokeRole {
+        admin: admin.pubkey(),
+        integrator_config: IntegratorConfig::pda(&integrator_program_id).0,
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let args = RevokeRoleArgs {
+        integrator_program_id,
+        role,
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::RevokeRole { args }.data(),
+    };
+
+    execute_transaction(context, ix, &[admin, payer], payer).await
+}
@@ -1,46 +1,74 @@
 use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::event::MessageSent;
+use endpoint::state::{EndpointConfig, IntegratorConfig};
 use mock_integrator::{accounts::InvokeSendMessage, InvokeSendMessageArgs};
 use solana_program_test::*;
 use solana_sdk::{
-    instruction::Instruction,
+    instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     signer::{keypair::Keypair, Signer},
+    transaction::TransactionError,
 };
 use universal_address::UniversalAddress;
 
-use crate::common::execute_transaction::execute_transaction;
+use crate::common::execute_transaction::{execute_transaction, simulate_transaction_events};
 
-async fn execute_send_message(
-    context: &mut ProgramTestContext,
+fn send_message_instruction(
     payer: &Keypair,
     integrator_program_pda: Pubkey,
     integrator_chain_config: Pubkey,
-    outbox_message: &Keypair,
+    outbox_message: Pubkey,
     sequence_tracker: Pubkey,
+    adapter_programs: &[Pubkey],
     instruction_data: Vec<u8>,
-) -> Result<(), BanksClientError> {
+) -> Instruction {
     let (event_authority, _) =
         Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+    let (integrator_config, _) = IntegratorConfig::pda(&mock_integrator::id());
 
     let accounts = InvokeSendMessage {
         payer: payer.pubkey(),
         integrator_program_pda,
+        integrator_config,
         integrator_chain_config,
-        outbox_message: outbox_message.pubkey(),
+        outbox_message,
         sequence_tracker,
+        endpoint_config: EndpointConfig::pda().0,
         system_program: solana_sdk::system_program::id(),
         endpoint_program: endpoint::id(),
         event_authority,
         program: endpoint::id(),
     };
 
-    let ix = Instruction {
+    // The send-enabled adapter programs are passed as remaining accounts to quote delivery fees
+    let mut accounts = accounts.to_account_metas(None);
+    accounts.extend(
+        adapter_programs
+            .iter()
+            .map(|adapter_program| AccountMeta::new_readonly(*adapter_program, false)),
+    );
+
+    Instruction {
         program_id: mock_integrator::id(),
-        accounts: accounts.to_account_metas(None),
+        accounts,
         data: instruction_data,
+    }
+}
+
+fn send_message_instruction_data(
+    dst_chain: u16,
+    dst_addr: UniversalAddress,
+    payload_hash: [u8; 32],
+    adapter_instructions: Vec<u8>,
+) -> Vec<u8> {
+    let args = InvokeSendMessageArgs {
+        dst_chain,
+        dst_addr,
+        payload_hash,
+        adapter_instructions,
     };
 
-    execute_transaction(context, ix, &[payer, outbox_message], payer).await
+    mock_integrator::instruction::InvokeSendMessage { args }.data()
 }
 
 pub async fn send_message(
@@ -48,28 +76,50 @@ pub async fn send_message(
     payer: &Keypair,
     integrator_program_pda: Pubkey,
     integrator_chain_config: Pubkey,
-    outbox_message: &Keypair,
+    outbox_message: Pubkey,
     sequence_tracker: Pubkey,
     dst_chain: u16,
     dst_addr: UniversalAddress,
     payload_hash: [u8; 32],
+    adapter_programs: &[Pubkey],
+    adapter_instructions: Vec<u8>,
 ) -> Result<(), BanksClientError> {
-    let args = InvokeSendMessageArgs {
-        dst_chain,
-        dst_addr,
-        payload_hash,
-    };
+    let ix = send_message_instruction(
+        payer,
+        integrator_program_pda,
+        integrator_chain_config,
+        outbox_message,
+        sequence_tracker,
+        adapter_programs,
+        send_message_instruction_data(dst_chain, dst_addr, payload_hash, adapter_instructions),
+    );
 
-    let instruction_data = mock_integrator::instruction::InvokeSendMessage { args }.data();
+    execute_transaction(context, ix, &[payer], payer).await
+}
 
-    execute_send_message(
-        context,
+/// Simulates sending a message and returns the emitted `MessageSent` events
+pub async fn simulate_send_message(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    integrator_program_pda: Pubkey,
+    integrator_chain_config: Pubkey,
+    outbox_message: Pubkey,
+    sequence_tracker: Pubkey,
+    dst_chain: u16,
+    dst_addr: UniversalAddress,
+    payload_hash: [u8; 32],
+    adapter_programs: &[Pubkey],
+    adapter_instructions: Vec<u8>,
+) -> Result<Vec<MessageSent>, TransactionError> {
+    let ix = send_message_instruction(
         payer,
         integrator_program_pda,
         integrator_chain_config,
         outbox_message,
         sequence_tracker,
-        instruction_data,
-    )
-    .await
+        adapter_programs,
+        send_message_instruction_data(dst_chain, dst_addr, payload_hash, adapter_instructions),
+    );
+
+    simulate_transaction_events(context, ix, &[payer], payer).await
 }
@@ -0,0 +1,58 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::{
+    accounts::SetAdminSigners, instructions::SetAdminSignersArgs, state::IntegratorConfig,
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+};
+
+use crate::common::execute_transaction::execute_transaction;
+
+/// Appends the additional signers of a multisig admin to an admin instruction
+pub fn append_co_signers(instruction: &mut Instruction, co_signers: &[&Keypair]) {
+    instruction.accounts.extend(
+        co_signers
+            .iter()
+            .map(|co_signer| AccountMeta::new_readonly(co_signer.pubkey(), true)),
+    );
+}
+
+pub async fn set_admin_signers(
+    context: &mut ProgramTestContext,
+    admin: &Keypair,
+    payer: &Keypair,
+    co_signers: &[&Keypair],
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+    let integrator_program_id = mock_integrator::id();
+
+    let accounts = SetAdminSigners {
+        admin: admin.pubkey(),
+        integrator_config: IntegratorConfig::pda(&integrator_program_id).0,
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let args = SetAdminSignersArgs {
+        integrator_program_id,
+        signers,
+        threshold,
+    };
+
+    let mut ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::SetAdminSigners { args }.data(),
+    };
+    append_co_signers(&mut ix, co_signers);
+
+    let mut tx_signers = vec![admin, payer];
+    tx_signers.extend_from_slice(co_signers);
+    execute_transaction(context, ix, &tx_signers, payer).await
+}
@@ -0,0 +1,62 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::{
+    accounts::SetChainAdapters,
+    instructions::SetChainAdaptersArgs,
+    state::{IntegratorChainConfig, IntegratorConfig},
+    utils::bitmap::Bitmap,
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn set_chain_adapters(
+    context: &mut ProgramTestContext,
+    admin: &Keypair,
+    payer: &Keypair,
+    chain_id: u16,
+    send_adapter_bitmap: u128,
+    recv_adapter_bitmap: u128,
+    adapter_infos: &[Pubkey],
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+    let integrator_program_id = mock_integrator::id();
+
+    let accounts = SetChainAdapters {
+        payer: payer.pubkey(),
+        admin: admin.pubkey(),
+        integrator_config: IntegratorConfig::pda(&integrator_program_id).0,
+        integrator_chain_config: IntegratorChainConfig::pda(&integrator_program_id, chain_id).0,
+        system_program: solana_sdk::system_program::id(),
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    // The adapter infos of the changed adapters are passed as remaining accounts
+    let mut accounts = accounts.to_account_metas(None);
+    accounts.extend(
+        adapter_infos
+            .iter()
+            .map(|adapter_info| AccountMeta::new(*adapter_info, false)),
+    );
+
+    let args = SetChainAdaptersArgs {
+        integrator_program_id,
+        chain_id,
+        send_adapter_bitmap: Bitmap::from_value(send_adapter_bitmap),
+        recv_adapter_bitmap: Bitmap::from_value(recv_adapter_bitmap),
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts,
+        data: endpoint::instruction::SetChainAdapters { args }.data(),
+    };
+
+    execute_transaction(context, ix, &[admin, payer], payer).await
+}
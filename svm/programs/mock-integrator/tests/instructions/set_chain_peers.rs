@@ -0,0 +1,48 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::{
+    accounts::SetChainPeers,
+    state::{IntegratorChainConfig, IntegratorConfig},
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+};
+use universal_address::UniversalAddress;
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn set_chain_peers(
+    context: &mut ProgramTestContext,
+    admin: &Keypair,
+    payer: &Keypair,
+    integrator_program_id: Pubkey,
+    chain_id: u16,
+    peers: Vec<UniversalAddress>,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+
+    let accounts = SetChainPeers {
+        admin: admin.pubkey(),
+        integrator_config: IntegratorConfig::pda(&integrator_program_id).0,
+        integrator_chain_config: IntegratorChainConfig::pda(&integrator_program_id, chain_id).0,
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let args = endpoint::instructions::SetChainPeersArgs {
+        integrator_program_id,
+        chain_id,
+        peers,
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::SetChainPeers { args }.data(),
+    };
+
+    execute_transaction(context, ix, &[admin, payer], payer).await
+}
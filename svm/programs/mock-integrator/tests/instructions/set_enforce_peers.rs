@@ -0,0 +1,41 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::{accounts::SetEnforcePeers, state::IntegratorConfig};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn set_enforce_peers(
+    context: &mut ProgramTestContext,
+    admin: &Keypair,
+    payer: &Keypair,
+    integrator_program_id: Pubkey,
+    enforce_peers: bool,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+
+    let accounts = SetEnforcePeers {
+        admin: admin.pubkey(),
+        integrator_config: IntegratorConfig::pda(&integrator_program_id).0,
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let args = endpoint::instructions::SetEnforcePeersArgs {
+        integrator_program_id,
+        enforce_peers,
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::SetEnforcePeers { args }.data(),
+    };
+
+    execute_transaction(context, ix, &[admin, payer], payer).await
+}
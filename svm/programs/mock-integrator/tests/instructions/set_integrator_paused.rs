@@ -0,0 +1,43 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::{accounts::SetIntegratorPaused, state::IntegratorConfig};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn set_integrator_paused(
+    context: &mut ProgramTestContext,
+    admin: &Keypair,
+    payer: &Keypair,
+    integrator_program_id: Pubkey,
+    paused_send: bool,
+    paused_recv: bool,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+
+    let accounts = SetIntegratorPaused {
+        admin: admin.pubkey(),
+        integrator_config: IntegratorConfig::pda(&integrator_program_id).0,
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let args = endpoint::instructions::SetIntegratorPausedArgs {
+        integrator_program_id,
+        paused_send,
+        paused_recv,
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::SetIntegratorPaused { args }.data(),
+    };
+
+    execute_transaction(context, ix, &[admin, payer], payer).await
+}
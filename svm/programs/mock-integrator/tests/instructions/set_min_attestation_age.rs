@@ -0,0 +1,47 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::{
+    accounts::SetMinAttestationAge,
+    state::{IntegratorChainConfig, IntegratorConfig},
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn set_min_attestation_age(
+    context: &mut ProgramTestContext,
+    admin: &Keypair,
+    payer: &Keypair,
+    chain_id: u16,
+    min_attestation_age: u64,
+) -> Result<(), BanksClientError> {
+    let integrator_program_id = mock_integrator::id();
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+
+    let accounts = SetMinAttestationAge {
+        admin: admin.pubkey(),
+        integrator_config: IntegratorConfig::pda(&integrator_program_id).0,
+        integrator_chain_config: IntegratorChainConfig::pda(&integrator_program_id, chain_id).0,
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let args = endpoint::instructions::SetMinAttestationAgeArgs {
+        integrator_program_id,
+        chain_id,
+        min_attestation_age,
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::SetMinAttestationAge { args }.data(),
+    };
+
+    execute_transaction(context, ix, &[admin, payer], payer).await
+}
@@ -0,0 +1,42 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::accounts::SetOutboxMessageTimeout;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn set_outbox_message_timeout(
+    context: &mut ProgramTestContext,
+    admin: &Keypair,
+    payer: &Keypair,
+    integrator_config: Pubkey,
+    integrator_program_id: Pubkey,
+    timeout: u64,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+
+    let accounts = SetOutboxMessageTimeout {
+        admin: admin.pubkey(),
+        integrator_config,
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let args = endpoint::instructions::SetOutboxMessageTimeoutArgs {
+        integrator_program_id,
+        timeout,
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::SetOutboxMessageTimeout { args }.data(),
+    };
+
+    execute_transaction(context, ix, &[admin, payer], payer).await
+}
@@ -0,0 +1,38 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::{accounts::SetPaused, instructions::SetPausedArgs, state::EndpointConfig};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn set_paused(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    guardian: &Keypair,
+    paused: bool,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+
+    let accounts = SetPaused {
+        guardian: guardian.pubkey(),
+        endpoint_config: EndpointConfig::pda().0,
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::SetPaused {
+            args: SetPausedArgs { paused },
+        }
+        .data(),
+    };
+
+    execute_transaction(context, ix, &[payer, guardian], payer).await
+}
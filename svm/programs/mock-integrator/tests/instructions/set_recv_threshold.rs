@@ -0,0 +1,46 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::accounts::SetRecvThreshold;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn set_recv_threshold(
+    context: &mut ProgramTestContext,
+    admin: &Keypair,
+    payer: &Keypair,
+    integrator_config: Pubkey,
+    integrator_chain_config: Pubkey,
+    integrator_program_id: Pubkey,
+    chain_id: u16,
+    threshold: u8,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+
+    let accounts = SetRecvThreshold {
+        admin: admin.pubkey(),
+        integrator_config,
+        integrator_chain_config,
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let args = endpoint::instructions::SetRecvThresholdArgs {
+        integrator_program_id,
+        chain_id,
+        threshold,
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::SetRecvThreshold { args }.data(),
+    };
+
+    execute_transaction(context, ix, &[admin, payer], payer).await
+}
@@ -1,5 +1,5 @@
 use anchor_lang::{InstructionData, ToAccountMetas};
-use endpoint::accounts::{ClaimAdmin, TransferAdmin};
+use endpoint::accounts::{CancelAdminTransfer, ClaimAdmin, TransferAdmin};
 use solana_program_test::*;
 use solana_sdk::{
     instruction::Instruction,
@@ -8,6 +8,7 @@ use solana_sdk::{
 };
 
 use crate::common::execute_transaction::execute_transaction;
+use crate::instructions::set_admin_signers::append_co_signers;
 
 pub async fn transfer_admin(
     context: &mut ProgramTestContext,
@@ -65,3 +66,32 @@ pub async fn claim_admin(
 
     execute_transaction(context, ix, &[new_admin, payer], payer).await
 }
+
+pub async fn cancel_admin_transfer(
+    context: &mut ProgramTestContext,
+    admin: &Keypair,
+    payer: &Keypair,
+    co_signers: &[&Keypair],
+    integrator_config: Pubkey,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+
+    let accounts = CancelAdminTransfer {
+        admin: admin.pubkey(),
+        integrator_config,
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let mut ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::CancelAdminTransfer {}.data(),
+    };
+    append_co_signers(&mut ix, co_signers);
+
+    let mut signers = vec![admin, payer];
+    signers.extend_from_slice(co_signers);
+    execute_transaction(context, ix, &signers, payer).await
+}
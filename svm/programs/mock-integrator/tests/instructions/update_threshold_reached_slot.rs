@@ -0,0 +1,34 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use endpoint::accounts::UpdateThresholdReachedSlot;
+use endpoint::state::{IntegratorChainConfig, IntegratorConfig};
+use solana_program_test::*;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signer::keypair::Keypair};
+
+use crate::common::execute_transaction::execute_transaction;
+
+pub async fn update_threshold_reached_slot(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    integrator_program_id: Pubkey,
+    src_chain: u16,
+    attestation_info: Pubkey,
+) -> Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+
+    let accounts = UpdateThresholdReachedSlot {
+        integrator_config: IntegratorConfig::pda(&integrator_program_id).0,
+        integrator_chain_config: IntegratorChainConfig::pda(&integrator_program_id, src_chain).0,
+        attestation_info,
+        event_authority,
+        program: endpoint::id(),
+    };
+
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::UpdateThresholdReachedSlot {}.data(),
+    };
+
+    execute_transaction(context, ix, &[payer], payer).await
+}
@@ -4,21 +4,26 @@ mod common;
 mod instructions;
 
 use crate::instructions::add_adapter::add_adapter;
+use crate::instructions::claim_adapter_fees::claim_adapter_fees;
 use crate::instructions::disable_adapter::disable_send_adapter;
 use crate::instructions::enable_adapter::enable_send_adapter;
 use crate::instructions::pick_up_message::pick_up_message;
 use crate::instructions::register::register;
 use crate::instructions::send_message::send_message;
+use crate::instructions::set_paused::set_paused;
 
 use anchor_lang::prelude::*;
+use common::execute_transaction::execute_transaction;
 use common::setup::{get_account, setup};
 use endpoint::error::EndpointError;
 use endpoint::state::{
-    AdapterInfo, IntegratorChainConfig, IntegratorConfig, OutboxMessage, SequenceTracker,
+    AdapterFeeVault, AdapterInfo, IntegratorChainConfig, IntegratorConfig, OutboxMessage,
+    SequenceTracker,
 };
+use mock_adapter::DELIVERY_PRICE;
 use solana_program_test::*;
 use solana_sdk::{
-    instruction::InstructionError, signature::Keypair, signer::Signer,
+    instruction::InstructionError, signature::Keypair, signer::Signer, system_instruction,
     transaction::TransactionError,
 };
 use universal_address::UniversalAddress;
@@ -110,9 +115,11 @@ async fn create_and_send_message(
     integrator_program_id: Pubkey,
     bump: u8,
     chain_id: u16,
-) -> Keypair {
-    let outbox_message = Keypair::new();
+) -> Pubkey {
     let (sequence_tracker_pda, _) = SequenceTracker::pda(&integrator_program_id);
+    let sequence_tracker: SequenceTracker =
+        get_account(&mut context.banks_client, sequence_tracker_pda).await;
+    let (outbox_message, _) = OutboxMessage::pda(&integrator_program_id, sequence_tracker.sequence);
     let dst_addr = UniversalAddress::from_bytes([1u8; 32]);
     let payload_hash = [2u8; 32];
 
@@ -121,11 +128,13 @@ async fn create_and_send_message(
         payer,
         integrator_program_pda,
         integrator_chain_config_pda,
-        &outbox_message,
+        outbox_message,
         sequence_tracker_pda,
         chain_id,
         dst_addr,
         payload_hash,
+        &[mock_adapter::id()],
+        vec![],
     )
     .await
     .unwrap();
@@ -162,7 +171,7 @@ async fn test_pick_up_message_success() {
     let result = pick_up_message(
         &mut context,
         &payer,
-        outbox_message.pubkey(),
+        outbox_message,
         adapter_info_pda,
         adapter_pda,
         payer.pubkey(),
@@ -174,7 +183,7 @@ async fn test_pick_up_message_success() {
     // Check if the outbox message account is closed
     let account = context
         .banks_client
-        .get_account(outbox_message.pubkey())
+        .get_account(outbox_message)
         .await
         .expect("Failed to get account info");
 
@@ -219,7 +228,7 @@ async fn test_pick_up_message_all_already_picked_up() {
     pick_up_message(
         &mut context,
         &payer,
-        outbox_message.pubkey(),
+        outbox_message,
         adapter_info_pda,
         adapter_pda,
         payer.pubkey(),
@@ -231,7 +240,7 @@ async fn test_pick_up_message_all_already_picked_up() {
     let result = pick_up_message(
         &mut context,
         &payer,
-        outbox_message.pubkey(),
+        outbox_message,
         adapter_info_pda,
         adapter_pda,
         payer.pubkey(),
@@ -244,3 +253,187 @@ async fn test_pick_up_message_all_already_picked_up() {
         TransactionError::InstructionError(0, InstructionError::Custom(3012)) // AccountNotInitialized
     );
 }
+
+#[tokio::test]
+async fn test_pick_up_message_releases_fee() {
+    let (
+        mut context,
+        payer,
+        _,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        bump,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    let outbox_message = create_and_send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        mock_integrator::id(),
+        bump,
+        chain_id,
+    )
+    .await;
+
+    // The delivery fee is escrowed in the outbox message
+    let outbox_msg: OutboxMessage = get_account(&mut context.banks_client, outbox_message).await;
+    assert_eq!(outbox_msg.adapter_fee(0), DELIVERY_PRICE);
+
+    let (adapter_fee_vault, _) = AdapterFeeVault::pda(&mock_adapter::id());
+    let vault_balance_before = context
+        .banks_client
+        .get_balance(adapter_fee_vault)
+        .await
+        .unwrap();
+
+    // The adapter PDA is not funded, the fee is released to the rent exempt fee vault instead
+    pick_up_message(
+        &mut context,
+        &payer,
+        outbox_message,
+        adapter_info_pda,
+        adapter_pda,
+        payer.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    let vault_balance_after = context
+        .banks_client
+        .get_balance(adapter_fee_vault)
+        .await
+        .unwrap();
+    assert_eq!(vault_balance_after - vault_balance_before, DELIVERY_PRICE);
+    assert_eq!(
+        context.banks_client.get_balance(adapter_pda).await.unwrap(),
+        0
+    );
+}
+
+#[tokio::test]
+async fn test_claim_adapter_fees() {
+    let (
+        mut context,
+        payer,
+        _,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        bump,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    let outbox_message = create_and_send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        mock_integrator::id(),
+        bump,
+        chain_id,
+    )
+    .await;
+
+    pick_up_message(
+        &mut context,
+        &payer,
+        outbox_message,
+        adapter_info_pda,
+        adapter_pda,
+        payer.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    // Fund the recipient so that it is rent exempt
+    let recipient = Keypair::new();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    execute_transaction(
+        &mut context,
+        system_instruction::transfer(
+            &payer.pubkey(),
+            &recipient.pubkey(),
+            rent.minimum_balance(0),
+        ),
+        &[&payer],
+        &payer,
+    )
+    .await
+    .unwrap();
+
+    claim_adapter_fees(&mut context, &payer, adapter_pda, recipient.pubkey())
+        .await
+        .unwrap();
+
+    // The recipient received the fee and the vault is left with its rent
+    let (adapter_fee_vault, _) = AdapterFeeVault::pda(&mock_adapter::id());
+    let vault = context
+        .banks_client
+        .get_account(adapter_fee_vault)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(vault.lamports, rent.minimum_balance(vault.data.len()));
+    assert_eq!(
+        context
+            .banks_client
+            .get_balance(recipient.pubkey())
+            .await
+            .unwrap(),
+        rent.minimum_balance(0) + DELIVERY_PRICE
+    );
+}
+
+#[tokio::test]
+async fn test_pick_up_message_paused() {
+    let (
+        mut context,
+        payer,
+        _,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        bump,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    let outbox_message = create_and_send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        mock_integrator::id(),
+        bump,
+        chain_id,
+    )
+    .await;
+
+    // The payer is the guardian set by `setup`
+    set_paused(&mut context, &payer, &payer, true)
+        .await
+        .unwrap();
+
+    let result = pick_up_message(
+        &mut context,
+        &payer,
+        outbox_message,
+        adapter_info_pda,
+        adapter_pda,
+        payer.pubkey(),
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::EndpointPaused.into())
+        )
+    );
+}
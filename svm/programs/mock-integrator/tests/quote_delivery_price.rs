@@ -0,0 +1,161 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+mod instructions;
+
+use crate::instructions::add_adapter::add_adapter;
+use crate::instructions::enable_adapter::enable_send_adapter;
+use crate::instructions::quote_delivery_price::quote_delivery_price;
+use crate::instructions::register::register;
+
+use common::setup::setup;
+use endpoint::error::EndpointError;
+use endpoint::state::{AdapterInfo, IntegratorChainConfig, IntegratorConfig};
+use endpoint::utils::adapter_instructions::{encode_instructions, AdapterInstruction};
+use mock_adapter::DELIVERY_PRICE;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, transaction::TransactionError,
+};
+
+async fn setup_test_environment() -> (ProgramTestContext, Keypair, Keypair, u16) {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+    let admin = Keypair::new();
+    let integrator_program_id = mock_integrator::id();
+    let chain_id: u16 = 2;
+
+    let (integrator_config_pda, _) = IntegratorConfig::pda(&integrator_program_id);
+    let (integrator_chain_config_pda, _) =
+        IntegratorChainConfig::pda(&integrator_program_id, chain_id);
+
+    register(
+        &mut context,
+        &payer,
+        &admin,
+        integrator_config_pda,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    let adapter_program_id = mock_adapter::id();
+    let (adapter_info_pda, _) = AdapterInfo::pda(&integrator_program_id, &adapter_program_id);
+
+    add_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        adapter_info_pda,
+        integrator_program_id,
+        adapter_program_id,
+    )
+    .await
+    .unwrap();
+
+    enable_send_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        chain_id,
+        adapter_program_id,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    (context, payer, admin, chain_id)
+}
+
+#[tokio::test]
+async fn test_quote_delivery_price_success() {
+    let (mut context, payer, _, chain_id) = setup_test_environment().await;
+
+    let price = quote_delivery_price(
+        &mut context,
+        &payer,
+        mock_integrator::id(),
+        chain_id,
+        &[mock_adapter::id()],
+        vec![],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(price, DELIVERY_PRICE);
+}
+
+#[tokio::test]
+async fn test_quote_delivery_price_with_adapter_instructions() {
+    let (mut context, payer, _, chain_id) = setup_test_environment().await;
+
+    let adapter_instructions = encode_instructions(&[AdapterInstruction {
+        index: 0,
+        payload: vec![1, 2, 3],
+    }])
+    .unwrap();
+
+    let price = quote_delivery_price(
+        &mut context,
+        &payer,
+        mock_integrator::id(),
+        chain_id,
+        &[mock_adapter::id()],
+        adapter_instructions,
+    )
+    .await
+    .unwrap();
+
+    // The mock adapter charges one extra lamport per instruction byte
+    assert_eq!(price, DELIVERY_PRICE + 3);
+}
+
+#[tokio::test]
+async fn test_quote_delivery_price_missing_adapter_program() {
+    let (mut context, payer, _, chain_id) = setup_test_environment().await;
+
+    let result = quote_delivery_price(
+        &mut context,
+        &payer,
+        mock_integrator::id(),
+        chain_id,
+        &[],
+        vec![],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::InvalidAdapterProgram.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_quote_delivery_price_wrong_adapter_program() {
+    let (mut context, payer, _, chain_id) = setup_test_environment().await;
+
+    let result = quote_delivery_price(
+        &mut context,
+        &payer,
+        mock_integrator::id(),
+        chain_id,
+        &[mock_integrator::id()],
+        vec![],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::InvalidAdapterProgram.into())
+        )
+    );
+}
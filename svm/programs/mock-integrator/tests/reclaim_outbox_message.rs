@@ -0,0 +1,331 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+mod instructions;
+
+use crate::instructions::add_adapter::add_adapter;
+use crate::instructions::enable_adapter::enable_send_adapter;
+use crate::instructions::reclaim_outbox_message::reclaim_outbox_message;
+use crate::instructions::register::register;
+use crate::instructions::send_message::send_message;
+use crate::instructions::set_outbox_message_timeout::set_outbox_message_timeout;
+
+use anchor_lang::prelude::*;
+use common::execute_transaction::execute_transaction;
+use common::setup::{get_account, setup};
+use endpoint::error::EndpointError;
+use endpoint::state::{
+    AdapterInfo, IntegratorChainConfig, IntegratorConfig, OutboxMessage, SequenceTracker,
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError, native_token::LAMPORTS_PER_SOL, signature::Keypair,
+    signer::Signer, system_instruction, transaction::TransactionError,
+};
+use universal_address::UniversalAddress;
+
+const TIMEOUT: u64 = 100;
+
+async fn setup_test_environment() -> (ProgramTestContext, Keypair, Keypair, Pubkey, Pubkey) {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+    let admin = Keypair::new();
+    let integrator_program_id = mock_integrator::id();
+    let chain_id: u16 = 1;
+
+    let (integrator_config_pda, _) = IntegratorConfig::pda(&integrator_program_id);
+    let (integrator_chain_config_pda, _) =
+        IntegratorChainConfig::pda(&integrator_program_id, chain_id);
+    let (integrator_program_pda, _) =
+        Pubkey::find_program_address(&[b"endpoint_integrator"], &integrator_program_id);
+
+    register(
+        &mut context,
+        &payer,
+        &admin,
+        integrator_config_pda,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    let adapter_program_id = mock_adapter::id();
+    let (adapter_info_pda, _) = AdapterInfo::pda(&integrator_program_id, &adapter_program_id);
+
+    add_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        adapter_info_pda,
+        integrator_program_id,
+        adapter_program_id,
+    )
+    .await
+    .unwrap();
+
+    enable_send_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        chain_id,
+        adapter_program_id,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    // Send a message that the adapter never picks up
+    let (sequence_tracker_pda, _) = SequenceTracker::pda(&integrator_program_id);
+    let (outbox_message, _) = OutboxMessage::pda(&integrator_program_id, 0);
+    send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        outbox_message,
+        sequence_tracker_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        [2u8; 32],
+        &[adapter_program_id],
+        vec![],
+    )
+    .await
+    .unwrap();
+
+    // Reclaiming is permissionless, so it is done by an unrelated caller
+    let caller = Keypair::new();
+    execute_transaction(
+        &mut context,
+        system_instruction::transfer(&payer.pubkey(), &caller.pubkey(), LAMPORTS_PER_SOL),
+        &[&payer],
+        &payer,
+    )
+    .await
+    .unwrap();
+
+    (
+        context,
+        admin,
+        caller,
+        integrator_config_pda,
+        outbox_message,
+    )
+}
+
+#[tokio::test]
+async fn test_set_outbox_message_timeout_success() {
+    let (mut context, admin, _, integrator_config_pda, _) = setup_test_environment().await;
+    let payer = context.payer.insecure_clone();
+
+    set_outbox_message_timeout(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        mock_integrator::id(),
+        TIMEOUT,
+    )
+    .await
+    .unwrap();
+
+    let integrator_config: IntegratorConfig =
+        get_account(&mut context.banks_client, integrator_config_pda).await;
+    assert_eq!(integrator_config.outbox_message_timeout, TIMEOUT);
+}
+
+#[tokio::test]
+async fn test_set_outbox_message_timeout_non_admin() {
+    let (mut context, _, caller, integrator_config_pda, _) = setup_test_environment().await;
+    let payer = context.payer.insecure_clone();
+
+    let result = set_outbox_message_timeout(
+        &mut context,
+        &caller,
+        &payer,
+        integrator_config_pda,
+        mock_integrator::id(),
+        TIMEOUT,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::CallerNotAuthorized.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_reclaim_outbox_message_success() {
+    let (mut context, admin, caller, integrator_config_pda, outbox_message) =
+        setup_test_environment().await;
+    let payer = context.payer.insecure_clone();
+
+    set_outbox_message_timeout(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        mock_integrator::id(),
+        TIMEOUT,
+    )
+    .await
+    .unwrap();
+
+    let outbox_msg: OutboxMessage = get_account(&mut context.banks_client, outbox_message).await;
+    context
+        .warp_to_slot(outbox_msg.created_at + TIMEOUT)
+        .unwrap();
+
+    let outbox_lamports = context
+        .banks_client
+        .get_balance(outbox_message)
+        .await
+        .unwrap();
+    let refund_balance_before = context
+        .banks_client
+        .get_balance(outbox_msg.refund_recipient)
+        .await
+        .unwrap();
+
+    reclaim_outbox_message(
+        &mut context,
+        &caller,
+        integrator_config_pda,
+        outbox_message,
+        outbox_msg.refund_recipient,
+    )
+    .await
+    .unwrap();
+
+    // The outbox message is closed
+    let account = context
+        .banks_client
+        .get_account(outbox_message)
+        .await
+        .unwrap();
+    assert!(account.is_none());
+
+    // The rent and the unclaimed delivery fee are returned to the refund recipient
+    let refund_balance_after = context
+        .banks_client
+        .get_balance(outbox_msg.refund_recipient)
+        .await
+        .unwrap();
+    assert_eq!(
+        refund_balance_after,
+        refund_balance_before + outbox_lamports
+    );
+}
+
+#[tokio::test]
+async fn test_reclaim_outbox_message_not_expired() {
+    let (mut context, admin, caller, integrator_config_pda, outbox_message) =
+        setup_test_environment().await;
+    let payer = context.payer.insecure_clone();
+
+    set_outbox_message_timeout(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        mock_integrator::id(),
+        TIMEOUT,
+    )
+    .await
+    .unwrap();
+
+    let result = reclaim_outbox_message(
+        &mut context,
+        &caller,
+        integrator_config_pda,
+        outbox_message,
+        payer.pubkey(),
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::OutboxMessageNotExpired.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_reclaim_outbox_message_timeout_disabled() {
+    let (mut context, _, caller, integrator_config_pda, outbox_message) =
+        setup_test_environment().await;
+    let payer = context.payer.insecure_clone();
+
+    // Outbox messages never expire when the timeout is not set
+    let outbox_msg: OutboxMessage = get_account(&mut context.banks_client, outbox_message).await;
+    context
+        .warp_to_slot(outbox_msg.created_at + TIMEOUT)
+        .unwrap();
+
+    let result = reclaim_outbox_message(
+        &mut context,
+        &caller,
+        integrator_config_pda,
+        outbox_message,
+        payer.pubkey(),
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::OutboxMessageNotExpired.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_reclaim_outbox_message_wrong_refund_recipient() {
+    let (mut context, admin, caller, integrator_config_pda, outbox_message) =
+        setup_test_environment().await;
+    let payer = context.payer.insecure_clone();
+
+    set_outbox_message_timeout(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        mock_integrator::id(),
+        TIMEOUT,
+    )
+    .await
+    .unwrap();
+
+    let outbox_msg: OutboxMessage = get_account(&mut context.banks_client, outbox_message).await;
+    context
+        .warp_to_slot(outbox_msg.created_at + TIMEOUT)
+        .unwrap();
+
+    let result = reclaim_outbox_message(
+        &mut context,
+        &caller,
+        integrator_config_pda,
+        outbox_message,
+        caller.pubkey(),
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintHasOne.into())
+        )
+    );
+}
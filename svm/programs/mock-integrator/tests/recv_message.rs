@@ -5,14 +5,28 @@ mod instructions;
 
 use crate::instructions::add_adapter::add_adapter;
 use crate::instructions::attest_message::attest_message;
+use crate::instructions::disable_adapter::disable_recv_adapter;
 use crate::instructions::enable_adapter::enable_recv_adapter;
-use crate::instructions::recv_message::recv_message;
+use crate::instructions::recv_message::{recv_message, recv_message_or_queue};
 use crate::instructions::register::register;
+use crate::instructions::release_queued_message::release_queued_message;
+use crate::instructions::remove_adapter::remove_adapter;
+use crate::instructions::set_chain_paused::set_chain_paused;
+use crate::instructions::set_chain_peers::set_chain_peers;
+use crate::instructions::set_inbound_rate_limit::set_inbound_rate_limit;
+use crate::instructions::set_integrator_paused::set_integrator_paused;
+use crate::instructions::set_min_attestation_age::set_min_attestation_age;
+use crate::instructions::set_paused::set_paused;
+use crate::instructions::set_recv_threshold::set_recv_threshold;
+use crate::instructions::update_threshold_reached_slot::update_threshold_reached_slot;
 
 use anchor_lang::prelude::*;
 use common::setup::{get_account, setup};
 use endpoint::error::EndpointError;
-use endpoint::state::{AdapterInfo, AttestationInfo, IntegratorChainConfig, IntegratorConfig};
+use endpoint::state::{
+    AdapterInfo, AttestationInfo, IntegratorChainConfig, IntegratorConfig, QueueStatus,
+    ReplayBitmap,
+};
 use solana_program_test::*;
 use solana_sdk::{
     instruction::InstructionError, signature::Keypair, signer::Signer,
@@ -165,6 +179,13 @@ async fn test_recv_message_success() {
     // Verify that the message is marked as executed
     assert!(attestation_info.executed);
 
+    let (replay_bitmap_pda, _) =
+        ReplayBitmap::pda(&mock_integrator::id(), src_chain, &src_addr, sequence);
+    let replay_bitmap: ReplayBitmap =
+        get_account(&mut context.banks_client, replay_bitmap_pda).await;
+    assert!(replay_bitmap.is_executed(sequence));
+    assert!(!replay_bitmap.is_executed(sequence + 1));
+
     // Verify that the adapter is still enabled
     assert!(integrator_chain_config.recv_adapter_bitmap.get(0).unwrap());
 
@@ -299,3 +320,1534 @@ async fn test_recv_message_no_attestation() {
         TransactionError::InstructionError(_, InstructionError::Custom(3012))
     ));
 }
+
+/// Attests to a message with the mock adapter and returns the attestation info PDA
+async fn attest(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    integrator_chain_config_pda: Pubkey,
+    adapter_info_pda: Pubkey,
+    adapter_pda: Pubkey,
+    src_chain: u16,
+) -> Pubkey {
+    let src_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let dst_addr = UniversalAddress::from_pubkey(&mock_integrator::id());
+
+    attest_message(
+        context,
+        payer,
+        adapter_info_pda,
+        adapter_pda,
+        integrator_chain_config_pda,
+        src_chain,
+        src_addr,
+        1,
+        1,
+        dst_addr,
+        [3u8; 32],
+    )
+    .await
+    .unwrap();
+
+    AttestationInfo::pda(AttestationInfo::compute_message_hash(
+        src_chain, src_addr, 1, 1, dst_addr, [3u8; 32],
+    ))
+    .0
+}
+
+#[tokio::test]
+async fn test_recv_message_threshold_met() {
+    let (
+        mut context,
+        payer,
+        admin,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    set_recv_threshold(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        mock_integrator::id(),
+        chain_id,
+        1,
+    )
+    .await
+    .unwrap();
+
+    let integrator_chain_config: IntegratorChainConfig =
+        get_account(&mut context.banks_client, integrator_chain_config_pda).await;
+    assert_eq!(integrator_chain_config.recv_threshold, 1);
+
+    let attestation_info_pda = attest(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    )
+    .await;
+
+    recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        1,
+        1,
+        [3u8; 32],
+    )
+    .await
+    .unwrap();
+
+    let attestation_info: AttestationInfo =
+        get_account(&mut context.banks_client, attestation_info_pda).await;
+    assert!(attestation_info.executed);
+}
+
+#[tokio::test]
+async fn test_recv_message_threshold_not_met() {
+    let (
+        mut context,
+        payer,
+        admin,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    // Enable a second receive adapter that never attests
+    let second_adapter_program_id = Keypair::new().pubkey();
+    let (second_adapter_info_pda, _) =
+        AdapterInfo::pda(&mock_integrator::id(), &second_adapter_program_id);
+    add_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        second_adapter_info_pda,
+        mock_integrator::id(),
+        second_adapter_program_id,
+    )
+    .await
+    .unwrap();
+    enable_recv_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        second_adapter_info_pda,
+        chain_id,
+        second_adapter_program_id,
+        mock_integrator::id(),
+    )
+    .await
+    .unwrap();
+
+    set_recv_threshold(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        mock_integrator::id(),
+        chain_id,
+        2,
+    )
+    .await
+    .unwrap();
+
+    let attestation_info_pda = attest(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    )
+    .await;
+
+    let result = recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        1,
+        1,
+        [3u8; 32],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::ThresholdNotMet.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_recv_message_threshold_ignores_disabled_adapters() {
+    let (
+        mut context,
+        payer,
+        admin,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    set_recv_threshold(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        mock_integrator::id(),
+        chain_id,
+        1,
+    )
+    .await
+    .unwrap();
+
+    let attestation_info_pda = attest(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    )
+    .await;
+
+    // The attestation of an adapter disabled afterwards no longer counts
+    disable_recv_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        chain_id,
+        mock_adapter::id(),
+        mock_integrator::id(),
+    )
+    .await
+    .unwrap();
+
+    let result = recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        1,
+        1,
+        [3u8; 32],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::ThresholdNotMet.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_recv_message_threshold_ignores_removed_adapters() {
+    let (
+        mut context,
+        payer,
+        admin,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    set_recv_threshold(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        mock_integrator::id(),
+        chain_id,
+        1,
+    )
+    .await
+    .unwrap();
+
+    let attestation_info_pda = attest(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    )
+    .await;
+
+    // Remove the adapter that attested
+    disable_recv_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        chain_id,
+        mock_adapter::id(),
+        mock_integrator::id(),
+    )
+    .await
+    .unwrap();
+    remove_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        payer.pubkey(),
+        mock_integrator::id(),
+        mock_adapter::id(),
+    )
+    .await
+    .unwrap();
+
+    // A new adapter takes over the freed slot and is enabled on the chain
+    let new_adapter_program_id = Keypair::new().pubkey();
+    let (new_adapter_info_pda, _) =
+        AdapterInfo::pda(&mock_integrator::id(), &new_adapter_program_id);
+    add_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        new_adapter_info_pda,
+        mock_integrator::id(),
+        new_adapter_program_id,
+    )
+    .await
+    .unwrap();
+    enable_recv_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        new_adapter_info_pda,
+        chain_id,
+        new_adapter_program_id,
+        mock_integrator::id(),
+    )
+    .await
+    .unwrap();
+
+    let new_adapter_info: AdapterInfo =
+        get_account(&mut context.banks_client, new_adapter_info_pda).await;
+    assert_eq!(new_adapter_info.index, 0);
+
+    // The attestation of the removed adapter is not counted for the new adapter of the slot
+    let result = recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        1,
+        1,
+        [3u8; 32],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::ThresholdNotMet.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_set_recv_threshold_above_enabled_adapters() {
+    let (
+        mut context,
+        payer,
+        admin,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        _,
+        _,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    let result = set_recv_threshold(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        mock_integrator::id(),
+        chain_id,
+        2,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::InvalidThreshold.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_set_recv_threshold_non_admin() {
+    let (mut context, payer, _, integrator_config_pda, integrator_chain_config_pda, _, _, chain_id) =
+        setup_test_environment().await;
+
+    let result = set_recv_threshold(
+        &mut context,
+        &Keypair::new(),
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        mock_integrator::id(),
+        chain_id,
+        1,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::CallerNotAuthorized.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_recv_message_paused() {
+    let (
+        mut context,
+        payer,
+        _,
+        _,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    let src_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let dst_addr = UniversalAddress::from_pubkey(&mock_integrator::id());
+    let payload_hash = [3u8; 32];
+
+    attest_message(
+        &mut context,
+        &payer,
+        adapter_info_pda,
+        adapter_pda,
+        integrator_chain_config_pda,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        dst_addr,
+        payload_hash,
+    )
+    .await
+    .unwrap();
+
+    // The payer is the guardian set by `setup`
+    set_paused(&mut context, &payer, &payer, true)
+        .await
+        .unwrap();
+
+    let (attestation_info_pda, _) = AttestationInfo::pda(AttestationInfo::compute_message_hash(
+        chain_id,
+        src_addr,
+        1,
+        1,
+        dst_addr,
+        payload_hash,
+    ));
+    let result = recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        payload_hash,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::EndpointPaused.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_recv_message_integrator_paused() {
+    let (
+        mut context,
+        payer,
+        admin,
+        _,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    let src_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let dst_addr = UniversalAddress::from_pubkey(&mock_integrator::id());
+    let payload_hash = [3u8; 32];
+
+    attest_message(
+        &mut context,
+        &payer,
+        adapter_info_pda,
+        adapter_pda,
+        integrator_chain_config_pda,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        dst_addr,
+        payload_hash,
+    )
+    .await
+    .unwrap();
+
+    set_integrator_paused(
+        &mut context,
+        &admin,
+        &payer,
+        mock_integrator::id(),
+        false,
+        true,
+    )
+    .await
+    .unwrap();
+
+    let (attestation_info_pda, _) = AttestationInfo::pda(AttestationInfo::compute_message_hash(
+        chain_id,
+        src_addr,
+        1,
+        1,
+        dst_addr,
+        payload_hash,
+    ));
+    let result = recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        payload_hash,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::RecvPaused.into())
+        )
+    );
+
+    // Receiving works again once unpaused
+    set_integrator_paused(
+        &mut context,
+        &admin,
+        &payer,
+        mock_integrator::id(),
+        false,
+        false,
+    )
+    .await
+    .unwrap();
+
+    recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        payload_hash,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_recv_message_chain_paused() {
+    let (
+        mut context,
+        payer,
+        admin,
+        _,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    let src_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let dst_addr = UniversalAddress::from_pubkey(&mock_integrator::id());
+    let payload_hash = [3u8; 32];
+
+    // Attestations are still accepted while receiving is paused
+    set_chain_paused(
+        &mut context,
+        &admin,
+        &payer,
+        mock_integrator::id(),
+        chain_id,
+        false,
+        true,
+    )
+    .await
+    .unwrap();
+
+    attest_message(
+        &mut context,
+        &payer,
+        adapter_info_pda,
+        adapter_pda,
+        integrator_chain_config_pda,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        dst_addr,
+        payload_hash,
+    )
+    .await
+    .unwrap();
+
+    let integrator_chain_config: IntegratorChainConfig =
+        get_account(&mut context.banks_client, integrator_chain_config_pda).await;
+    assert!(!integrator_chain_config.paused_send);
+    assert!(integrator_chain_config.paused_recv);
+
+    let (attestation_info_pda, _) = AttestationInfo::pda(AttestationInfo::compute_message_hash(
+        chain_id,
+        src_addr,
+        1,
+        1,
+        dst_addr,
+        payload_hash,
+    ));
+    let result = recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        payload_hash,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::RecvPaused.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_set_chain_paused_non_admin() {
+    let (mut context, payer, _, _, _, _, _, chain_id) = setup_test_environment().await;
+
+    let result = set_chain_paused(
+        &mut context,
+        &Keypair::new(),
+        &payer,
+        mock_integrator::id(),
+        chain_id,
+        true,
+        true,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::CallerNotAuthorized.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_recv_message_unknown_peer() {
+    let (
+        mut context,
+        payer,
+        admin,
+        _,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    let src_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let peer = UniversalAddress::from_bytes([2u8; 32]);
+    let dst_addr = UniversalAddress::from_pubkey(&mock_integrator::id());
+    let payload_hash = [3u8; 32];
+
+    set_chain_peers(
+        &mut context,
+        &admin,
+        &payer,
+        mock_integrator::id(),
+        chain_id,
+        vec![peer],
+    )
+    .await
+    .unwrap();
+
+    let integrator_chain_config: IntegratorChainConfig =
+        get_account(&mut context.banks_client, integrator_chain_config_pda).await;
+    assert_eq!(integrator_chain_config.peers, vec![peer]);
+
+    attest_message(
+        &mut context,
+        &payer,
+        adapter_info_pda,
+        adapter_pda,
+        integrator_chain_config_pda,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        dst_addr,
+        payload_hash,
+    )
+    .await
+    .unwrap();
+
+    let (attestation_info_pda, _) = AttestationInfo::pda(AttestationInfo::compute_message_hash(
+        chain_id,
+        src_addr,
+        1,
+        1,
+        dst_addr,
+        payload_hash,
+    ));
+    let result = recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        payload_hash,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::UnknownPeer.into())
+        )
+    );
+
+    // The message is accepted once its source address is a peer
+    set_chain_peers(
+        &mut context,
+        &admin,
+        &payer,
+        mock_integrator::id(),
+        chain_id,
+        vec![peer, src_addr],
+    )
+    .await
+    .unwrap();
+
+    recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        payload_hash,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_set_chain_peers_invalid() {
+    let (mut context, payer, admin, _, _, _, _, chain_id) = setup_test_environment().await;
+
+    let peer = UniversalAddress::from_bytes([1u8; 32]);
+    let too_many = (0..=IntegratorChainConfig::MAX_PEERS)
+        .map(|i| UniversalAddress::from_bytes([i as u8; 32]))
+        .collect();
+
+    for peers in [vec![peer, peer], too_many] {
+        let result = set_chain_peers(
+            &mut context,
+            &admin,
+            &payer,
+            mock_integrator::id(),
+            chain_id,
+            peers,
+        )
+        .await;
+
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(EndpointError::InvalidPeers.into())
+            )
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_set_chain_peers_non_admin() {
+    let (mut context, payer, _, _, _, _, _, chain_id) = setup_test_environment().await;
+
+    let result = set_chain_peers(
+        &mut context,
+        &Keypair::new(),
+        &payer,
+        mock_integrator::id(),
+        chain_id,
+        vec![UniversalAddress::from_bytes([1u8; 32])],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::CallerNotAuthorized.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_recv_message_rate_limited() {
+    let (
+        mut context,
+        payer,
+        admin,
+        _,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    const QUEUE_DELAY: u64 = 100;
+    let src_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let dst_addr = UniversalAddress::from_pubkey(&mock_integrator::id());
+
+    set_inbound_rate_limit(
+        &mut context,
+        &admin,
+        &payer,
+        chain_id,
+        1,
+        1_000,
+        QUEUE_DELAY,
+    )
+    .await
+    .unwrap();
+
+    // The first message is within the limit
+    let attestation_info_pda = attest(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    )
+    .await;
+    recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        src_addr,
+        1,
+        1,
+        [3u8; 32],
+    )
+    .await
+    .unwrap();
+
+    attest_message(
+        &mut context,
+        &payer,
+        adapter_info_pda,
+        adapter_pda,
+        integrator_chain_config_pda,
+        chain_id,
+        src_addr,
+        2,
+        1,
+        dst_addr,
+        [3u8; 32],
+    )
+    .await
+    .unwrap();
+    let (attestation_info_pda, _) = AttestationInfo::pda(AttestationInfo::compute_message_hash(
+        chain_id, src_addr, 2, 1, dst_addr, [3u8; 32],
+    ));
+
+    // The second message fails unless the integrator opts in to queueing
+    let result = recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        src_addr,
+        2,
+        1,
+        [3u8; 32],
+    )
+    .await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::RateLimitExceeded.into())
+        )
+    );
+    let attestation_info: AttestationInfo =
+        get_account(&mut context.banks_client, attestation_info_pda).await;
+    assert_eq!(attestation_info.queue_status, QueueStatus::NotQueued);
+
+    recv_message_or_queue(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        src_addr,
+        2,
+        1,
+        [3u8; 32],
+    )
+    .await
+    .unwrap();
+
+    let attestation_info: AttestationInfo =
+        get_account(&mut context.banks_client, attestation_info_pda).await;
+    assert!(!attestation_info.executed);
+    let QueueStatus::Queued { release_slot } = attestation_info.queue_status else {
+        panic!("message is not queued");
+    };
+
+    let result = recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        src_addr,
+        2,
+        1,
+        [3u8; 32],
+    )
+    .await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::MessageQueued.into())
+        )
+    );
+
+    let result = release_queued_message(&mut context, &payer, attestation_info_pda).await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::QueueDelayNotElapsed.into())
+        )
+    );
+
+    // Once released, the message is received without consuming the rate limit
+    context.warp_to_slot(release_slot).unwrap();
+    release_queued_message(&mut context, &payer, attestation_info_pda)
+        .await
+        .unwrap();
+
+    let attestation_info: AttestationInfo =
+        get_account(&mut context.banks_client, attestation_info_pda).await;
+    assert_eq!(attestation_info.queue_status, QueueStatus::Released);
+
+    recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        src_addr,
+        2,
+        1,
+        [3u8; 32],
+    )
+    .await
+    .unwrap();
+
+    let attestation_info: AttestationInfo =
+        get_account(&mut context.banks_client, attestation_info_pda).await;
+    assert!(attestation_info.executed);
+}
+
+#[tokio::test]
+async fn test_release_queued_message_not_queued() {
+    let (
+        mut context,
+        payer,
+        _,
+        _,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    let attestation_info_pda = attest(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    )
+    .await;
+
+    let result = release_queued_message(&mut context, &payer, attestation_info_pda).await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::MessageNotQueued.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_set_inbound_rate_limit_non_admin() {
+    let (mut context, payer, _, _, _, _, _, chain_id) = setup_test_environment().await;
+
+    let result =
+        set_inbound_rate_limit(&mut context, &Keypair::new(), &payer, chain_id, 1, 1_000, 0).await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::CallerNotAuthorized.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_recv_message_min_attestation_age() {
+    let (
+        mut context,
+        payer,
+        admin,
+        _,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    const MIN_ATTESTATION_AGE: u64 = 100;
+
+    set_min_attestation_age(&mut context, &admin, &payer, chain_id, MIN_ATTESTATION_AGE)
+        .await
+        .unwrap();
+
+    let integrator_chain_config: IntegratorChainConfig =
+        get_account(&mut context.banks_client, integrator_chain_config_pda).await;
+    assert_eq!(
+        integrator_chain_config.min_attestation_age,
+        MIN_ATTESTATION_AGE
+    );
+
+    let attestation_info_pda = attest(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    )
+    .await;
+
+    let attestation_info: AttestationInfo =
+        get_account(&mut context.banks_client, attestation_info_pda).await;
+    let threshold_reached_slot = attestation_info.threshold_reached_slot.unwrap();
+
+    let result = recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        1,
+        1,
+        [3u8; 32],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::AttestationTooRecent.into())
+        )
+    );
+
+    context
+        .warp_to_slot(threshold_reached_slot + MIN_ATTESTATION_AGE)
+        .unwrap();
+
+    recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        1,
+        1,
+        [3u8; 32],
+    )
+    .await
+    .unwrap();
+
+    let attestation_info: AttestationInfo =
+        get_account(&mut context.banks_client, attestation_info_pda).await;
+    assert!(attestation_info.executed);
+}
+
+#[tokio::test]
+async fn test_recv_message_threshold_met_by_lowering_threshold() {
+    let (
+        mut context,
+        payer,
+        admin,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    const MIN_ATTESTATION_AGE: u64 = 100;
+
+    // Enable a second receive adapter that never attests
+    let second_adapter_program_id = Keypair::new().pubkey();
+    let (second_adapter_info_pda, _) =
+        AdapterInfo::pda(&mock_integrator::id(), &second_adapter_program_id);
+    add_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        second_adapter_info_pda,
+        mock_integrator::id(),
+        second_adapter_program_id,
+    )
+    .await
+    .unwrap();
+    enable_recv_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        second_adapter_info_pda,
+        chain_id,
+        second_adapter_program_id,
+        mock_integrator::id(),
+    )
+    .await
+    .unwrap();
+
+    set_recv_threshold(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        mock_integrator::id(),
+        chain_id,
+        2,
+    )
+    .await
+    .unwrap();
+    set_min_attestation_age(&mut context, &admin, &payer, chain_id, MIN_ATTESTATION_AGE)
+        .await
+        .unwrap();
+
+    let attestation_info_pda = attest(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    )
+    .await;
+
+    let attestation_info: AttestationInfo =
+        get_account(&mut context.banks_client, attestation_info_pda).await;
+    assert_eq!(attestation_info.threshold_reached_slot, None);
+
+    // The threshold is met without any further attestation
+    set_recv_threshold(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        mock_integrator::id(),
+        chain_id,
+        1,
+    )
+    .await
+    .unwrap();
+
+    // The slot recorded by recv_message is reverted along with the failing instruction
+    let result = recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        1,
+        1,
+        [3u8; 32],
+    )
+    .await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::AttestationTooRecent.into())
+        )
+    );
+
+    update_threshold_reached_slot(
+        &mut context,
+        &payer,
+        mock_integrator::id(),
+        chain_id,
+        attestation_info_pda,
+    )
+    .await
+    .unwrap();
+
+    let attestation_info: AttestationInfo =
+        get_account(&mut context.banks_client, attestation_info_pda).await;
+    let threshold_reached_slot = attestation_info.threshold_reached_slot.unwrap();
+
+    context
+        .warp_to_slot(threshold_reached_slot + MIN_ATTESTATION_AGE)
+        .unwrap();
+
+    recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        1,
+        1,
+        [3u8; 32],
+    )
+    .await
+    .unwrap();
+
+    let attestation_info: AttestationInfo =
+        get_account(&mut context.banks_client, attestation_info_pda).await;
+    assert!(attestation_info.executed);
+}
+
+#[tokio::test]
+async fn test_recv_message_threshold_reached_slot_reset() {
+    let (
+        mut context,
+        payer,
+        admin,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    const MIN_ATTESTATION_AGE: u64 = 100;
+
+    set_recv_threshold(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        mock_integrator::id(),
+        chain_id,
+        1,
+    )
+    .await
+    .unwrap();
+    set_min_attestation_age(&mut context, &admin, &payer, chain_id, MIN_ATTESTATION_AGE)
+        .await
+        .unwrap();
+
+    let attestation_info_pda = attest(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    )
+    .await;
+
+    let attestation_info: AttestationInfo =
+        get_account(&mut context.banks_client, attestation_info_pda).await;
+    let first_reached_slot = attestation_info.threshold_reached_slot.unwrap();
+
+    // The threshold stops being met once the attesting adapter is disabled
+    disable_recv_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        chain_id,
+        mock_adapter::id(),
+        mock_integrator::id(),
+    )
+    .await
+    .unwrap();
+
+    update_threshold_reached_slot(
+        &mut context,
+        &payer,
+        mock_integrator::id(),
+        chain_id,
+        attestation_info_pda,
+    )
+    .await
+    .unwrap();
+
+    let attestation_info: AttestationInfo =
+        get_account(&mut context.banks_client, attestation_info_pda).await;
+    assert_eq!(attestation_info.threshold_reached_slot, None);
+
+    context
+        .warp_to_slot(first_reached_slot + MIN_ATTESTATION_AGE)
+        .unwrap();
+
+    enable_recv_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        chain_id,
+        mock_adapter::id(),
+        mock_integrator::id(),
+    )
+    .await
+    .unwrap();
+
+    // The minimum attestation age restarts when the threshold is met again
+    let result = recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        1,
+        1,
+        [3u8; 32],
+    )
+    .await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::AttestationTooRecent.into())
+        )
+    );
+
+    update_threshold_reached_slot(
+        &mut context,
+        &payer,
+        mock_integrator::id(),
+        chain_id,
+        attestation_info_pda,
+    )
+    .await
+    .unwrap();
+
+    let attestation_info: AttestationInfo =
+        get_account(&mut context.banks_client, attestation_info_pda).await;
+    let threshold_reached_slot = attestation_info.threshold_reached_slot.unwrap();
+    assert!(threshold_reached_slot >= first_reached_slot + MIN_ATTESTATION_AGE);
+
+    context
+        .warp_to_slot(threshold_reached_slot + MIN_ATTESTATION_AGE)
+        .unwrap();
+
+    recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        1,
+        1,
+        [3u8; 32],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_recv_message_min_attestation_age_zero_threshold() {
+    let (
+        mut context,
+        payer,
+        admin,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    ) = setup_test_environment().await;
+
+    const MIN_ATTESTATION_AGE: u64 = 100;
+
+    // The receive threshold is left at 0
+    set_min_attestation_age(&mut context, &admin, &payer, chain_id, MIN_ATTESTATION_AGE)
+        .await
+        .unwrap();
+
+    let attestation_info_pda = attest(
+        &mut context,
+        &payer,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        adapter_pda,
+        chain_id,
+    )
+    .await;
+
+    let attestation_info: AttestationInfo =
+        get_account(&mut context.banks_client, attestation_info_pda).await;
+    let threshold_reached_slot = attestation_info.threshold_reached_slot.unwrap();
+
+    // Disabling the attesting adapter during the age stops the message
+    disable_recv_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        chain_id,
+        mock_adapter::id(),
+        mock_integrator::id(),
+    )
+    .await
+    .unwrap();
+
+    context
+        .warp_to_slot(threshold_reached_slot + MIN_ATTESTATION_AGE)
+        .unwrap();
+
+    let result = recv_message(
+        &mut context,
+        &payer,
+        attestation_info_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        1,
+        1,
+        [3u8; 32],
+    )
+    .await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::ThresholdNotMet.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_set_min_attestation_age_non_admin() {
+    let (mut context, payer, _, _, _, _, _, chain_id) = setup_test_environment().await;
+
+    let result =
+        set_min_attestation_age(&mut context, &Keypair::new(), &payer, chain_id, 100).await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::CallerNotAuthorized.into())
+        )
+    );
+}
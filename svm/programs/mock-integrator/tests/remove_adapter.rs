@@ -0,0 +1,353 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+mod instructions;
+
+use crate::instructions::add_adapter::add_adapter;
+use crate::instructions::disable_adapter::{disable_recv_adapter, disable_send_adapter};
+use crate::instructions::enable_adapter::{enable_recv_adapter, enable_send_adapter};
+use crate::instructions::register::register;
+use crate::instructions::remove_adapter::remove_adapter;
+use anchor_lang::prelude::*;
+use common::setup::{get_account, setup};
+use endpoint::error::EndpointError;
+use endpoint::state::{AdapterInfo, IntegratorChainConfig, IntegratorConfig};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer,
+    transaction::TransactionError,
+};
+
+const CHAIN_ID: u16 = 2;
+
+async fn setup_test_environment() -> (ProgramTestContext, Keypair, Keypair, Pubkey, Pubkey) {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+    let admin = Keypair::new();
+    let integrator_program_id = mock_integrator::id();
+
+    let (integrator_config_pda, _) = IntegratorConfig::pda(&integrator_program_id);
+
+    register(
+        &mut context,
+        &payer,
+        &admin,
+        integrator_config_pda,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    // Add an adapter and enable it in both directions
+    let adapter_program_id = Keypair::new().pubkey();
+    let (adapter_info_pda, _) = AdapterInfo::pda(&integrator_program_id, &adapter_program_id);
+    let (integrator_chain_config_pda, _) =
+        IntegratorChainConfig::pda(&integrator_program_id, CHAIN_ID);
+
+    add_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        adapter_info_pda,
+        integrator_program_id,
+        adapter_program_id,
+    )
+    .await
+    .unwrap();
+
+    enable_recv_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        CHAIN_ID,
+        adapter_program_id,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    enable_send_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        CHAIN_ID,
+        adapter_program_id,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    (
+        context,
+        payer,
+        admin,
+        integrator_program_id,
+        adapter_program_id,
+    )
+}
+
+async fn disable_test_adapter(
+    context: &mut ProgramTestContext,
+    admin: &Keypair,
+    payer: &Keypair,
+    integrator_program_id: Pubkey,
+    adapter_program_id: Pubkey,
+) {
+    let (integrator_config_pda, _) = IntegratorConfig::pda(&integrator_program_id);
+    let (integrator_chain_config_pda, _) =
+        IntegratorChainConfig::pda(&integrator_program_id, CHAIN_ID);
+    let (adapter_info_pda, _) = AdapterInfo::pda(&integrator_program_id, &adapter_program_id);
+
+    disable_recv_adapter(
+        context,
+        admin,
+        payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        CHAIN_ID,
+        adapter_program_id,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    disable_send_adapter(
+        context,
+        admin,
+        payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        CHAIN_ID,
+        adapter_program_id,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_remove_adapter_success() {
+    let (mut context, payer, admin, integrator_program_id, adapter_program_id) =
+        setup_test_environment().await;
+    let (adapter_info_pda, _) = AdapterInfo::pda(&integrator_program_id, &adapter_program_id);
+
+    // Enabling and disabling the adapter is tracked in the adapter info
+    let adapter_info: AdapterInfo = get_account(&mut context.banks_client, adapter_info_pda).await;
+    assert_eq!(adapter_info.enabled_count, 2);
+
+    disable_test_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_program_id,
+        adapter_program_id,
+    )
+    .await;
+
+    let adapter_info: AdapterInfo = get_account(&mut context.banks_client, adapter_info_pda).await;
+    assert_eq!(adapter_info.enabled_count, 0);
+
+    let rent_recipient = Keypair::new().pubkey();
+    let adapter_info_lamports = context
+        .banks_client
+        .get_balance(adapter_info_pda)
+        .await
+        .unwrap();
+
+    remove_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        rent_recipient,
+        integrator_program_id,
+        adapter_program_id,
+    )
+    .await
+    .unwrap();
+
+    // The adapter info is closed and its rent refunded to the rent recipient
+    let account = context
+        .banks_client
+        .get_account(adapter_info_pda)
+        .await
+        .unwrap();
+    assert!(account.is_none());
+    assert_eq!(
+        context
+            .banks_client
+            .get_balance(rent_recipient)
+            .await
+            .unwrap(),
+        adapter_info_lamports
+    );
+
+    // The slot of the adapter is freed
+    let integrator_config: IntegratorConfig = get_account(
+        &mut context.banks_client,
+        IntegratorConfig::pda(&integrator_program_id).0,
+    )
+    .await;
+    assert_eq!(integrator_config.adapter_infos, vec![Pubkey::default()]);
+}
+
+#[tokio::test]
+async fn test_remove_adapter_slot_reused() {
+    let (mut context, payer, admin, integrator_program_id, adapter_program_id) =
+        setup_test_environment().await;
+    let (integrator_config_pda, _) = IntegratorConfig::pda(&integrator_program_id);
+
+    // Add a second adapter so the removed slot is not the last one
+    let second_adapter_program_id = Keypair::new().pubkey();
+    add_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        AdapterInfo::pda(&integrator_program_id, &second_adapter_program_id).0,
+        integrator_program_id,
+        second_adapter_program_id,
+    )
+    .await
+    .unwrap();
+
+    disable_test_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_program_id,
+        adapter_program_id,
+    )
+    .await;
+    remove_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        payer.pubkey(),
+        integrator_program_id,
+        adapter_program_id,
+    )
+    .await
+    .unwrap();
+
+    // A new adapter takes the slot of the removed adapter
+    let new_adapter_program_id = Keypair::new().pubkey();
+    let (new_adapter_info_pda, _) =
+        AdapterInfo::pda(&integrator_program_id, &new_adapter_program_id);
+    add_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        new_adapter_info_pda,
+        integrator_program_id,
+        new_adapter_program_id,
+    )
+    .await
+    .unwrap();
+
+    let new_adapter_info: AdapterInfo =
+        get_account(&mut context.banks_client, new_adapter_info_pda).await;
+    assert_eq!(new_adapter_info.index, 0);
+    assert_eq!(new_adapter_info.enabled_count, 0);
+
+    let integrator_config: IntegratorConfig =
+        get_account(&mut context.banks_client, integrator_config_pda).await;
+    assert_eq!(
+        integrator_config.adapter_infos,
+        vec![new_adapter_program_id, second_adapter_program_id]
+    );
+
+    // The removed adapter is no longer enabled on the chain
+    let integrator_chain_config: IntegratorChainConfig = get_account(
+        &mut context.banks_client,
+        IntegratorChainConfig::pda(&integrator_program_id, CHAIN_ID).0,
+    )
+    .await;
+    assert!(integrator_chain_config.send_adapter_bitmap.is_empty());
+    assert!(integrator_chain_config.recv_adapter_bitmap.is_empty());
+}
+
+#[tokio::test]
+async fn test_remove_adapter_still_enabled() {
+    let (mut context, payer, admin, integrator_program_id, adapter_program_id) =
+        setup_test_environment().await;
+    let (integrator_config_pda, _) = IntegratorConfig::pda(&integrator_program_id);
+    let (integrator_chain_config_pda, _) =
+        IntegratorChainConfig::pda(&integrator_program_id, CHAIN_ID);
+    let (adapter_info_pda, _) = AdapterInfo::pda(&integrator_program_id, &adapter_program_id);
+
+    // The adapter is still enabled as a send adapter
+    disable_recv_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        adapter_info_pda,
+        CHAIN_ID,
+        adapter_program_id,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    let result = remove_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        payer.pubkey(),
+        integrator_program_id,
+        adapter_program_id,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::AdapterStillEnabled.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_remove_adapter_non_admin() {
+    let (mut context, payer, admin, integrator_program_id, adapter_program_id) =
+        setup_test_environment().await;
+
+    disable_test_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_program_id,
+        adapter_program_id,
+    )
+    .await;
+
+    let result = remove_adapter(
+        &mut context,
+        &Keypair::new(),
+        &payer,
+        payer.pubkey(),
+        integrator_program_id,
+        adapter_program_id,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::CallerNotAuthorized.into())
+        )
+    );
+}
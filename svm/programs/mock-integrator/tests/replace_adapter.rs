@@ -0,0 +1,213 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+mod instructions;
+
+use crate::instructions::add_adapter::add_adapter;
+use crate::instructions::disable_adapter::disable_recv_adapter;
+use crate::instructions::enable_adapter::enable_recv_adapter;
+use crate::instructions::register::register;
+use crate::instructions::replace_adapter::replace_adapter;
+use anchor_lang::prelude::*;
+use common::setup::{get_account, setup};
+use endpoint::error::EndpointError;
+use endpoint::state::{AdapterInfo, IntegratorChainConfig, IntegratorConfig};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer,
+    system_instruction::SystemError, transaction::TransactionError,
+};
+
+const CHAIN_ID: u16 = 2;
+
+async fn setup_test_environment() -> (ProgramTestContext, Keypair, Keypair, Pubkey, Pubkey) {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+    let admin = Keypair::new();
+    let integrator_program_id = mock_integrator::id();
+
+    let (integrator_config_pda, _) = IntegratorConfig::pda(&integrator_program_id);
+
+    register(
+        &mut context,
+        &payer,
+        &admin,
+        integrator_config_pda,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    // Add an adapter and enable it as a receive adapter
+    let adapter_program_id = Keypair::new().pubkey();
+    let (adapter_info_pda, _) = AdapterInfo::pda(&integrator_program_id, &adapter_program_id);
+
+    add_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        adapter_info_pda,
+        integrator_program_id,
+        adapter_program_id,
+    )
+    .await
+    .unwrap();
+
+    enable_recv_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        IntegratorChainConfig::pda(&integrator_program_id, CHAIN_ID).0,
+        adapter_info_pda,
+        CHAIN_ID,
+        adapter_program_id,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    (
+        context,
+        payer,
+        admin,
+        integrator_program_id,
+        adapter_program_id,
+    )
+}
+
+#[tokio::test]
+async fn test_replace_adapter_success() {
+    let (mut context, payer, admin, integrator_program_id, adapter_program_id) =
+        setup_test_environment().await;
+    let (integrator_config_pda, _) = IntegratorConfig::pda(&integrator_program_id);
+    let (integrator_chain_config_pda, _) =
+        IntegratorChainConfig::pda(&integrator_program_id, CHAIN_ID);
+    let (adapter_info_pda, _) = AdapterInfo::pda(&integrator_program_id, &adapter_program_id);
+
+    let new_adapter_program_id = Keypair::new().pubkey();
+    let (new_adapter_info_pda, _) =
+        AdapterInfo::pda(&integrator_program_id, &new_adapter_program_id);
+
+    replace_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_program_id,
+        adapter_program_id,
+        new_adapter_program_id,
+    )
+    .await
+    .unwrap();
+
+    // The adapter info of the replaced adapter is closed
+    let account = context
+        .banks_client
+        .get_account(adapter_info_pda)
+        .await
+        .unwrap();
+    assert!(account.is_none());
+
+    // The new adapter takes over the index and the enabled chains of the replaced adapter
+    let new_adapter_info: AdapterInfo =
+        get_account(&mut context.banks_client, new_adapter_info_pda).await;
+    assert_eq!(new_adapter_info.index, 0);
+    assert_eq!(
+        new_adapter_info.integrator_program_id,
+        integrator_program_id
+    );
+    assert_eq!(new_adapter_info.adapter_program_id, new_adapter_program_id);
+    assert_eq!(new_adapter_info.enabled_count, 1);
+
+    let integrator_config: IntegratorConfig =
+        get_account(&mut context.banks_client, integrator_config_pda).await;
+    assert_eq!(
+        integrator_config.adapter_infos,
+        vec![new_adapter_program_id]
+    );
+
+    let integrator_chain_config: IntegratorChainConfig =
+        get_account(&mut context.banks_client, integrator_chain_config_pda).await;
+    assert_eq!(integrator_chain_config.recv_adapter_bitmap.as_value(), 1);
+
+    // The new adapter can be managed like any other adapter
+    disable_recv_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        new_adapter_info_pda,
+        CHAIN_ID,
+        new_adapter_program_id,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    let new_adapter_info: AdapterInfo =
+        get_account(&mut context.banks_client, new_adapter_info_pda).await;
+    assert_eq!(new_adapter_info.enabled_count, 0);
+}
+
+#[tokio::test]
+async fn test_replace_adapter_already_registered() {
+    let (mut context, payer, admin, integrator_program_id, adapter_program_id) =
+        setup_test_environment().await;
+
+    let other_adapter_program_id = Keypair::new().pubkey();
+    add_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        IntegratorConfig::pda(&integrator_program_id).0,
+        AdapterInfo::pda(&integrator_program_id, &other_adapter_program_id).0,
+        integrator_program_id,
+        other_adapter_program_id,
+    )
+    .await
+    .unwrap();
+
+    let result = replace_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_program_id,
+        adapter_program_id,
+        other_adapter_program_id,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(SystemError::AccountAlreadyInUse as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_replace_adapter_non_admin() {
+    let (mut context, payer, _, integrator_program_id, adapter_program_id) =
+        setup_test_environment().await;
+
+    let result = replace_adapter(
+        &mut context,
+        &Keypair::new(),
+        &payer,
+        integrator_program_id,
+        adapter_program_id,
+        Keypair::new().pubkey(),
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::CallerNotAuthorized.into())
+        )
+    );
+}
@@ -0,0 +1,361 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+mod instructions;
+
+use crate::instructions::add_adapter::add_adapter;
+use crate::instructions::disable_adapter::disable_recv_adapter;
+use crate::instructions::discard_admin::discard_admin;
+use crate::instructions::enable_adapter::enable_recv_adapter;
+use crate::instructions::grant_role::grant_role;
+use crate::instructions::propose_config_change::propose_config_change;
+use crate::instructions::register::register;
+use crate::instructions::revoke_role::revoke_role;
+use crate::instructions::set_config_delay::set_config_delay;
+use crate::instructions::set_integrator_paused::set_integrator_paused;
+use crate::instructions::transfer_admin::transfer_admin;
+
+use anchor_lang::prelude::*;
+use common::setup::{get_account, setup};
+use endpoint::error::EndpointError;
+use endpoint::state::{AdapterInfo, ConfigChange, IntegratorChainConfig, IntegratorConfig, Role};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer,
+    transaction::TransactionError,
+};
+
+const CHAIN_ID: u16 = 2;
+
+/// Registers the mock integrator and grants the configurator and pauser roles
+async fn setup_test_environment() -> (ProgramTestContext, Keypair, Keypair, Keypair, Keypair) {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+    let admin = Keypair::new();
+    let configurator = Keypair::new();
+    let pauser = Keypair::new();
+
+    register(
+        &mut context,
+        &payer,
+        &admin,
+        IntegratorConfig::pda(&mock_integrator::id()).0,
+        mock_integrator::id(),
+    )
+    .await
+    .unwrap();
+
+    grant_role(
+        &mut context,
+        &admin,
+        &payer,
+        Role::Configurator,
+        configurator.pubkey(),
+    )
+    .await
+    .unwrap();
+    grant_role(&mut context, &admin, &payer, Role::Pauser, pauser.pubkey())
+        .await
+        .unwrap();
+
+    (context, payer, admin, configurator, pauser)
+}
+
+async fn add_test_adapter(
+    context: &mut ProgramTestContext,
+    signer: &Keypair,
+    payer: &Keypair,
+) -> std::result::Result<(), BanksClientError> {
+    add_adapter(
+        context,
+        signer,
+        payer,
+        IntegratorConfig::pda(&mock_integrator::id()).0,
+        AdapterInfo::pda(&mock_integrator::id(), &mock_adapter::id()).0,
+        mock_integrator::id(),
+        mock_adapter::id(),
+    )
+    .await
+}
+
+async fn enable_test_adapter(
+    context: &mut ProgramTestContext,
+    signer: &Keypair,
+    payer: &Keypair,
+) -> std::result::Result<(), BanksClientError> {
+    enable_recv_adapter(
+        context,
+        signer,
+        payer,
+        IntegratorConfig::pda(&mock_integrator::id()).0,
+        IntegratorChainConfig::pda(&mock_integrator::id(), CHAIN_ID).0,
+        AdapterInfo::pda(&mock_integrator::id(), &mock_adapter::id()).0,
+        CHAIN_ID,
+        mock_adapter::id(),
+        mock_integrator::id(),
+    )
+    .await
+}
+
+async fn disable_test_adapter(
+    context: &mut ProgramTestContext,
+    signer: &Keypair,
+    payer: &Keypair,
+) -> std::result::Result<(), BanksClientError> {
+    disable_recv_adapter(
+        context,
+        signer,
+        payer,
+        IntegratorConfig::pda(&mock_integrator::id()).0,
+        IntegratorChainConfig::pda(&mock_integrator::id(), CHAIN_ID).0,
+        AdapterInfo::pda(&mock_integrator::id(), &mock_adapter::id()).0,
+        CHAIN_ID,
+        mock_adapter::id(),
+        mock_integrator::id(),
+    )
+    .await
+}
+
+fn assert_endpoint_error(result: std::result::Result<(), BanksClientError>, error: EndpointError) {
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(error.into()))
+    );
+}
+
+#[tokio::test]
+async fn test_grant_role_success() {
+    let (mut context, _, _, configurator, pauser) = setup_test_environment().await;
+
+    let integrator_config: IntegratorConfig = get_account(
+        &mut context.banks_client,
+        IntegratorConfig::pda(&mock_integrator::id()).0,
+    )
+    .await;
+    assert_eq!(integrator_config.configurator, Some(configurator.pubkey()));
+    assert_eq!(integrator_config.pauser, Some(pauser.pubkey()));
+}
+
+#[tokio::test]
+async fn test_grant_role_non_admin() {
+    let (mut context, payer, _, configurator, pauser) = setup_test_environment().await;
+
+    // Role holders cannot grant roles
+    for signer in [&configurator, &pauser] {
+        let result = grant_role(
+            &mut context,
+            signer,
+            &payer,
+            Role::Pauser,
+            Keypair::new().pubkey(),
+        )
+        .await;
+        assert_endpoint_error(result, EndpointError::CallerNotAuthorized);
+    }
+}
+
+#[tokio::test]
+async fn test_configurator_manages_adapters() {
+    let (mut context, payer, _, configurator, _) = setup_test_environment().await;
+
+    add_test_adapter(&mut context, &configurator, &payer)
+        .await
+        .unwrap();
+    enable_test_adapter(&mut context, &configurator, &payer)
+        .await
+        .unwrap();
+    disable_test_adapter(&mut context, &configurator, &payer)
+        .await
+        .unwrap();
+
+    // The configurator cannot act as the admin
+    let result = transfer_admin(
+        &mut context,
+        &configurator,
+        &configurator.pubkey(),
+        &payer,
+        IntegratorConfig::pda(&mock_integrator::id()).0,
+        mock_integrator::id(),
+    )
+    .await;
+    assert_endpoint_error(result, EndpointError::CallerNotAuthorized);
+
+    let result = set_integrator_paused(
+        &mut context,
+        &configurator,
+        &payer,
+        mock_integrator::id(),
+        true,
+        true,
+    )
+    .await;
+    assert_endpoint_error(result, EndpointError::CallerNotAuthorized);
+}
+
+#[tokio::test]
+async fn test_configurator_proposes_config_changes() {
+    let (mut context, payer, admin, configurator, _) = setup_test_environment().await;
+
+    set_config_delay(&mut context, &admin, &payer, 100)
+        .await
+        .unwrap();
+
+    propose_config_change(
+        &mut context,
+        &configurator,
+        &payer,
+        0,
+        ConfigChange::SetRecvThreshold {
+            chain_id: CHAIN_ID,
+            threshold: 1,
+        },
+    )
+    .await
+    .unwrap();
+
+    // Only the admin can change the config delay
+    let result = propose_config_change(
+        &mut context,
+        &configurator,
+        &payer,
+        1,
+        ConfigChange::SetConfigDelay { delay: 0 },
+    )
+    .await;
+    assert_endpoint_error(result, EndpointError::CallerNotAuthorized);
+
+    propose_config_change(
+        &mut context,
+        &admin,
+        &payer,
+        1,
+        ConfigChange::SetConfigDelay { delay: 0 },
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_pauser_responds_to_incidents() {
+    let (mut context, payer, admin, _, pauser) = setup_test_environment().await;
+
+    add_test_adapter(&mut context, &admin, &payer)
+        .await
+        .unwrap();
+
+    // The pauser cannot enable adapters
+    let result = enable_test_adapter(&mut context, &pauser, &payer).await;
+    assert_endpoint_error(result, EndpointError::CallerNotAuthorized);
+
+    enable_test_adapter(&mut context, &admin, &payer)
+        .await
+        .unwrap();
+    disable_test_adapter(&mut context, &pauser, &payer)
+        .await
+        .unwrap();
+
+    set_integrator_paused(
+        &mut context,
+        &pauser,
+        &payer,
+        mock_integrator::id(),
+        true,
+        false,
+    )
+    .await
+    .unwrap();
+
+    // The pauser can only pause
+    let result = set_integrator_paused(
+        &mut context,
+        &pauser,
+        &payer,
+        mock_integrator::id(),
+        false,
+        true,
+    )
+    .await;
+    assert_endpoint_error(result, EndpointError::CallerNotAuthorized);
+
+    set_integrator_paused(
+        &mut context,
+        &admin,
+        &payer,
+        mock_integrator::id(),
+        false,
+        false,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_revoke_role() {
+    let (mut context, payer, admin, _, pauser) = setup_test_environment().await;
+
+    revoke_role(&mut context, &admin, &payer, Role::Pauser)
+        .await
+        .unwrap();
+
+    let integrator_config: IntegratorConfig = get_account(
+        &mut context.banks_client,
+        IntegratorConfig::pda(&mock_integrator::id()).0,
+    )
+    .await;
+    assert_eq!(integrator_config.pauser, None);
+
+    let result = set_integrator_paused(
+        &mut context,
+        &pauser,
+        &payer,
+        mock_integrator::id(),
+        true,
+        true,
+    )
+    .await;
+    assert_endpoint_error(result, EndpointError::CallerNotAuthorized);
+
+    let result = revoke_role(&mut context, &admin, &payer, Role::Pauser).await;
+    assert_endpoint_error(result, EndpointError::RoleNotGranted);
+}
+
+#[tokio::test]
+async fn test_grant_role_replaces_holder() {
+    let (mut context, payer, admin, configurator, _) = setup_test_environment().await;
+    let new_configurator = Keypair::new();
+
+    grant_role(
+        &mut context,
+        &admin,
+        &payer,
+        Role::Configurator,
+        new_configurator.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    let result = add_test_adapter(&mut context, &configurator, &payer).await;
+    assert_endpoint_error(result, EndpointError::CallerNotAuthorized);
+
+    add_test_adapter(&mut context, &new_configurator, &payer)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_discard_admin_revokes_roles() {
+    let (mut context, payer, admin, configurator, _) = setup_test_environment().await;
+    let (integrator_config_pda, _) = IntegratorConfig::pda(&mock_integrator::id());
+
+    discard_admin(&mut context, &admin, &payer, integrator_config_pda)
+        .await
+        .unwrap();
+
+    let integrator_config: IntegratorConfig =
+        get_account(&mut context.banks_client, integrator_config_pda).await;
+    assert_eq!(integrator_config.configurator, None);
+    assert_eq!(integrator_config.pauser, None);
+
+    let result = add_test_adapter(&mut context, &configurator, &payer).await;
+    assert_endpoint_error(result, EndpointError::CallerNotAuthorized);
+}
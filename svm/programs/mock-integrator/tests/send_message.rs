@@ -5,8 +5,15 @@ mod instructions;
 
 use crate::instructions::add_adapter::add_adapter;
 use crate::instructions::enable_adapter::enable_send_adapter;
+use crate::instructions::get_outbound_capacity::get_outbound_capacity;
 use crate::instructions::register::register;
-use crate::instructions::send_message::send_message;
+use crate::instructions::send_message::{send_message, simulate_send_message};
+use crate::instructions::set_chain_paused::set_chain_paused;
+use crate::instructions::set_chain_peers::set_chain_peers;
+use crate::instructions::set_enforce_peers::set_enforce_peers;
+use crate::instructions::set_integrator_paused::set_integrator_paused;
+use crate::instructions::set_outbound_rate_limit::set_outbound_rate_limit;
+use crate::instructions::set_paused::set_paused;
 
 use anchor_lang::prelude::*;
 use common::setup::{get_account, setup};
@@ -14,6 +21,8 @@ use endpoint::error::EndpointError;
 use endpoint::state::{
     AdapterInfo, IntegratorChainConfig, IntegratorConfig, OutboxMessage, SequenceTracker,
 };
+use endpoint::utils::adapter_instructions::{encode_instructions, AdapterInstruction};
+use mock_adapter::DELIVERY_PRICE;
 use solana_program_test::*;
 use solana_sdk::{
     instruction::InstructionError, signature::Keypair, signer::Signer,
@@ -48,7 +57,7 @@ async fn initialize_test_environment(
     let (integrator_chain_config_pda, _) =
         IntegratorChainConfig::pda(&integrator_program_id, chain_id);
 
-    let adapter_program_id = Keypair::new().pubkey();
+    let adapter_program_id = mock_adapter::id();
     let (adapter_info_pda, _) = AdapterInfo::pda(&integrator_program_id, &adapter_program_id);
 
     // Add and enable adapter
@@ -110,35 +119,51 @@ async fn test_send_message_success() {
     let payload_hash = [2u8; 32];
 
     let (sequence_tracker_pda, _) = SequenceTracker::pda(&integrator_program_id);
-    let outbox_message = Keypair::new();
+    let (outbox_message, outbox_message_bump) = OutboxMessage::pda(&integrator_program_id, 0);
 
     let result = send_message(
         &mut context,
         &payer,
         integrator_program_pda,
         integrator_chain_config_pda,
-        &outbox_message,
+        outbox_message,
         sequence_tracker_pda,
         chain_id,
         dst_addr,
         payload_hash,
+        &[mock_adapter::id()],
+        vec![],
     )
     .await;
 
     assert!(result.is_ok());
 
     // Verify outbox message was created correctly
-    let outbox_msg: OutboxMessage =
-        get_account(&mut context.banks_client, outbox_message.pubkey()).await;
+    let outbox_msg: OutboxMessage = get_account(&mut context.banks_client, outbox_message).await;
     assert_eq!(
         outbox_msg.src_addr,
         UniversalAddress::from(mock_integrator::id())
     );
+    assert_eq!(outbox_msg.bump, outbox_message_bump);
     assert_eq!(outbox_msg.sequence, 0);
     assert_eq!(outbox_msg.dst_chain, chain_id);
     assert_eq!(outbox_msg.dst_addr, dst_addr);
     assert_eq!(outbox_msg.payload_hash, payload_hash);
     assert_eq!(outbox_msg.outstanding_adapters.as_value(), 1);
+
+    // Verify the delivery fee of the adapter was escrowed on top of the rent
+    assert_eq!(outbox_msg.adapter_fee(0), DELIVERY_PRICE);
+    let outbox_account = context
+        .banks_client
+        .get_account(outbox_message)
+        .await
+        .unwrap()
+        .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        outbox_account.lamports,
+        rent.minimum_balance(outbox_account.data.len()) + DELIVERY_PRICE
+    );
 }
 
 #[tokio::test]
@@ -162,45 +187,72 @@ async fn test_send_message_increments_sequence() {
     let (sequence_tracker_pda, _) = SequenceTracker::pda(&integrator_program_id);
 
     // Send first message
-    let outbox_message_1 = Keypair::new();
+    let (outbox_message_1, _) = OutboxMessage::pda(&integrator_program_id, 0);
     send_message(
         &mut context,
         &payer,
         integrator_program_pda,
         integrator_chain_config_pda,
-        &outbox_message_1,
+        outbox_message_1,
         sequence_tracker_pda,
         chain_id,
         dst_addr,
         payload_hash,
+        &[mock_adapter::id()],
+        vec![],
     )
     .await
     .unwrap();
 
     // Verify first message sequence is 0
     let outbox_msg_1: OutboxMessage =
-        get_account(&mut context.banks_client, outbox_message_1.pubkey()).await;
+        get_account(&mut context.banks_client, outbox_message_1).await;
     assert_eq!(outbox_msg_1.sequence, 0);
 
+    // The event reports the sequence the outbox message is derived from
+    let (outbox_message_2, _) = OutboxMessage::pda(&integrator_program_id, 1);
+    let events = simulate_send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        outbox_message_2,
+        sequence_tracker_pda,
+        chain_id,
+        dst_addr,
+        payload_hash,
+        &[mock_adapter::id()],
+        vec![],
+    )
+    .await
+    .unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].sequence, 1);
+    assert_eq!(
+        OutboxMessage::pda(&integrator_program_id, events[0].sequence).0,
+        outbox_message_2
+    );
+
     // Send second message
-    let outbox_message_2 = Keypair::new();
     send_message(
         &mut context,
         &payer,
         integrator_program_pda,
         integrator_chain_config_pda,
-        &outbox_message_2,
+        outbox_message_2,
         sequence_tracker_pda,
         chain_id,
         dst_addr,
         payload_hash,
+        &[mock_adapter::id()],
+        vec![],
     )
     .await
     .unwrap();
 
     // Verify second message sequence is 1
     let outbox_msg_2: OutboxMessage =
-        get_account(&mut context.banks_client, outbox_message_2.pubkey()).await;
+        get_account(&mut context.banks_client, outbox_message_2).await;
     assert_eq!(outbox_msg_2.sequence, 1);
 
     // Verify the sequence key was incremented
@@ -209,6 +261,50 @@ async fn test_send_message_increments_sequence() {
     assert_eq!(sequence_tracker.sequence, 2); // Next available sequence
 }
 
+#[tokio::test]
+async fn test_send_message_wrong_sequence_outbox_message() {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+
+    let (
+        _admin,
+        integrator_program_id,
+        _integrator_config_pda,
+        integrator_chain_config_pda,
+        integrator_program_pda,
+        _adapter_info_pda,
+        bump,
+        chain_id,
+    ) = initialize_test_environment(&mut context).await;
+
+    let (sequence_tracker_pda, _) = SequenceTracker::pda(&integrator_program_id);
+    // The next sequence is 0, so the outbox message for sequence 1 must be rejected
+    let (outbox_message, _) = OutboxMessage::pda(&integrator_program_id, 1);
+
+    let result = send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        outbox_message,
+        sequence_tracker_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        [2u8; 32],
+        &[mock_adapter::id()],
+        vec![],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintSeeds.into())
+        )
+    );
+}
+
 #[tokio::test]
 async fn test_send_message_no_enabled_adapters() {
     let mut context = setup().await;
@@ -239,18 +335,20 @@ async fn test_send_message_no_enabled_adapters() {
     let dst_addr = UniversalAddress::from_bytes([1u8; 32]);
     let payload_hash = [2u8; 32];
     let (sequence_tracker_pda, _) = SequenceTracker::pda(&integrator_program_id);
-    let outbox_message = Keypair::new();
+    let (outbox_message, _) = OutboxMessage::pda(&integrator_program_id, 0);
 
     let result = send_message(
         &mut context,
         &payer,
         integrator_program_pda,
         integrator_chain_config_pda,
-        &outbox_message,
+        outbox_message,
         sequence_tracker_pda,
         chain_id,
         dst_addr,
         payload_hash,
+        &[],
+        vec![],
     )
     .await;
 
@@ -284,18 +382,20 @@ async fn test_send_message_unregistered_chain() {
     let dst_addr = UniversalAddress::from_bytes([1u8; 32]);
     let payload_hash = [2u8; 32];
     let (sequence_tracker_pda, _) = SequenceTracker::pda(&integrator_program_id);
-    let outbox_message = Keypair::new();
+    let (outbox_message, _) = OutboxMessage::pda(&integrator_program_id, 0);
 
     let result = send_message(
         &mut context,
         &payer,
         integrator_program_pda,
         unregistered_chain_config_pda,
-        &outbox_message,
+        outbox_message,
         sequence_tracker_pda,
         unregistered_chain_id,
         dst_addr,
         payload_hash,
+        &[mock_adapter::id()],
+        vec![],
     )
     .await;
 
@@ -304,3 +404,706 @@ async fn test_send_message_unregistered_chain() {
         TransactionError::InstructionError(0, InstructionError::Custom(3012)) // AccountNotInitialized
     );
 }
+
+#[tokio::test]
+async fn test_send_message_with_adapter_instructions() {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+
+    let (
+        _admin,
+        integrator_program_id,
+        _integrator_config_pda,
+        integrator_chain_config_pda,
+        integrator_program_pda,
+        _adapter_info_pda,
+        _bump,
+        chain_id,
+    ) = initialize_test_environment(&mut context).await;
+
+    let dst_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let payload_hash = [2u8; 32];
+    let (sequence_tracker_pda, _) = SequenceTracker::pda(&integrator_program_id);
+    let (outbox_message, _) = OutboxMessage::pda(&integrator_program_id, 0);
+
+    let instruction = AdapterInstruction {
+        index: 0,
+        payload: vec![0xaa, 0xbb, 0xcc],
+    };
+    let adapter_instructions = encode_instructions(&[instruction.clone()]).unwrap();
+
+    send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        outbox_message,
+        sequence_tracker_pda,
+        chain_id,
+        dst_addr,
+        payload_hash,
+        &[mock_adapter::id()],
+        adapter_instructions,
+    )
+    .await
+    .unwrap();
+
+    // Verify the instruction was stored for the adapter
+    let outbox_msg: OutboxMessage = get_account(&mut context.banks_client, outbox_message).await;
+    assert_eq!(outbox_msg.adapter_instructions, vec![instruction]);
+}
+
+#[tokio::test]
+async fn test_send_message_invalid_instruction_index() {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+
+    let (
+        _admin,
+        integrator_program_id,
+        _integrator_config_pda,
+        integrator_chain_config_pda,
+        integrator_program_pda,
+        _adapter_info_pda,
+        _bump,
+        chain_id,
+    ) = initialize_test_environment(&mut context).await;
+
+    let dst_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let payload_hash = [2u8; 32];
+    let (sequence_tracker_pda, _) = SequenceTracker::pda(&integrator_program_id);
+    let (outbox_message, _) = OutboxMessage::pda(&integrator_program_id, 0);
+
+    // Only one adapter is registered, so index 1 is out of bounds
+    let adapter_instructions = encode_instructions(&[AdapterInstruction {
+        index: 1,
+        payload: vec![0xaa],
+    }])
+    .unwrap();
+
+    let result = send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        outbox_message,
+        sequence_tracker_pda,
+        chain_id,
+        dst_addr,
+        payload_hash,
+        &[mock_adapter::id()],
+        adapter_instructions,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::InvalidInstructionIndex.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_send_message_paused() {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+
+    let (
+        _admin,
+        integrator_program_id,
+        _integrator_config_pda,
+        integrator_chain_config_pda,
+        integrator_program_pda,
+        _adapter_info_pda,
+        _bump,
+        chain_id,
+    ) = initialize_test_environment(&mut context).await;
+
+    // The payer is the guardian set by `setup`
+    set_paused(&mut context, &payer, &payer, true)
+        .await
+        .unwrap();
+
+    let (sequence_tracker_pda, _) = SequenceTracker::pda(&integrator_program_id);
+    let (outbox_message, _) = OutboxMessage::pda(&integrator_program_id, 0);
+
+    let result = send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        outbox_message,
+        sequence_tracker_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        [2u8; 32],
+        &[mock_adapter::id()],
+        vec![],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::EndpointPaused.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_send_message_integrator_paused() {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+
+    let (
+        admin,
+        integrator_program_id,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        integrator_program_pda,
+        _adapter_info_pda,
+        _bump,
+        chain_id,
+    ) = initialize_test_environment(&mut context).await;
+
+    set_integrator_paused(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_program_id,
+        true,
+        false,
+    )
+    .await
+    .unwrap();
+
+    let integrator_config: IntegratorConfig =
+        get_account(&mut context.banks_client, integrator_config_pda).await;
+    assert!(integrator_config.paused_send);
+    assert!(!integrator_config.paused_recv);
+
+    let (sequence_tracker_pda, _) = SequenceTracker::pda(&integrator_program_id);
+    let (outbox_message, _) = OutboxMessage::pda(&integrator_program_id, 0);
+
+    let result = send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        outbox_message,
+        sequence_tracker_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        [2u8; 32],
+        &[mock_adapter::id()],
+        vec![],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::SendPaused.into())
+        )
+    );
+
+    // Sending works again once unpaused, with the adapter configuration intact
+    set_integrator_paused(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_program_id,
+        false,
+        false,
+    )
+    .await
+    .unwrap();
+
+    send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        outbox_message,
+        sequence_tracker_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        [2u8; 32],
+        &[mock_adapter::id()],
+        vec![],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_send_message_chain_paused() {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+
+    let (
+        admin,
+        integrator_program_id,
+        _integrator_config_pda,
+        integrator_chain_config_pda,
+        integrator_program_pda,
+        _adapter_info_pda,
+        _bump,
+        chain_id,
+    ) = initialize_test_environment(&mut context).await;
+
+    set_chain_paused(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_program_id,
+        chain_id,
+        true,
+        false,
+    )
+    .await
+    .unwrap();
+
+    let integrator_chain_config: IntegratorChainConfig =
+        get_account(&mut context.banks_client, integrator_chain_config_pda).await;
+    assert!(integrator_chain_config.paused_send);
+    assert!(!integrator_chain_config.paused_recv);
+    assert_eq!(integrator_chain_config.send_adapter_bitmap.as_value(), 1);
+
+    let (sequence_tracker_pda, _) = SequenceTracker::pda(&integrator_program_id);
+    let (outbox_message, _) = OutboxMessage::pda(&integrator_program_id, 0);
+
+    let result = send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        outbox_message,
+        sequence_tracker_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        [2u8; 32],
+        &[mock_adapter::id()],
+        vec![],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::SendPaused.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_send_message_recv_paused() {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+
+    let (
+        admin,
+        integrator_program_id,
+        _integrator_config_pda,
+        integrator_chain_config_pda,
+        integrator_program_pda,
+        _adapter_info_pda,
+        _bump,
+        chain_id,
+    ) = initialize_test_environment(&mut context).await;
+
+    // Pausing the receive direction does not affect sending
+    set_integrator_paused(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_program_id,
+        false,
+        true,
+    )
+    .await
+    .unwrap();
+    set_chain_paused(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_program_id,
+        chain_id,
+        false,
+        true,
+    )
+    .await
+    .unwrap();
+
+    let (sequence_tracker_pda, _) = SequenceTracker::pda(&integrator_program_id);
+    let (outbox_message, _) = OutboxMessage::pda(&integrator_program_id, 0);
+
+    send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        outbox_message,
+        sequence_tracker_pda,
+        chain_id,
+        UniversalAddress::from_bytes([1u8; 32]),
+        [2u8; 32],
+        &[mock_adapter::id()],
+        vec![],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_set_integrator_paused_non_admin() {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+
+    let (_, integrator_program_id, _, _, _, _, _, _) =
+        initialize_test_environment(&mut context).await;
+
+    let result = set_integrator_paused(
+        &mut context,
+        &Keypair::new(),
+        &payer,
+        integrator_program_id,
+        true,
+        true,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::CallerNotAuthorized.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_send_message_enforce_peers() {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+
+    let (
+        admin,
+        integrator_program_id,
+        integrator_config_pda,
+        integrator_chain_config_pda,
+        integrator_program_pda,
+        _adapter_info_pda,
+        _bump,
+        chain_id,
+    ) = initialize_test_environment(&mut context).await;
+
+    let dst_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let (sequence_tracker_pda, _) = SequenceTracker::pda(&integrator_program_id);
+    let (outbox_message, _) = OutboxMessage::pda(&integrator_program_id, 0);
+
+    set_enforce_peers(&mut context, &admin, &payer, integrator_program_id, true)
+        .await
+        .unwrap();
+
+    let integrator_config: IntegratorConfig =
+        get_account(&mut context.banks_client, integrator_config_pda).await;
+    assert!(integrator_config.enforce_peers);
+
+    // A chain without peers cannot be sent to while peers are enforced
+    let result = send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        outbox_message,
+        sequence_tracker_pda,
+        chain_id,
+        dst_addr,
+        [2u8; 32],
+        &[mock_adapter::id()],
+        vec![],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::UnknownDestination.into())
+        )
+    );
+
+    set_chain_peers(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_program_id,
+        chain_id,
+        vec![UniversalAddress::from_bytes([3u8; 32])],
+    )
+    .await
+    .unwrap();
+
+    let result = send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        outbox_message,
+        sequence_tracker_pda,
+        chain_id,
+        dst_addr,
+        [2u8; 32],
+        &[mock_adapter::id()],
+        vec![],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::UnknownDestination.into())
+        )
+    );
+
+    // The destination is accepted once it is a peer
+    set_chain_peers(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_program_id,
+        chain_id,
+        vec![UniversalAddress::from_bytes([3u8; 32]), dst_addr],
+    )
+    .await
+    .unwrap();
+
+    send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        outbox_message,
+        sequence_tracker_pda,
+        chain_id,
+        dst_addr,
+        [2u8; 32],
+        &[mock_adapter::id()],
+        vec![],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_set_enforce_peers_non_admin() {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+
+    let (_, integrator_program_id, _, _, _, _, _, _) =
+        initialize_test_environment(&mut context).await;
+
+    let result = set_enforce_peers(
+        &mut context,
+        &Keypair::new(),
+        &payer,
+        integrator_program_id,
+        true,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::CallerNotAuthorized.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_send_message_rate_limited() {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+
+    let (
+        admin,
+        integrator_program_id,
+        _integrator_config_pda,
+        integrator_chain_config_pda,
+        integrator_program_pda,
+        _adapter_info_pda,
+        _bump,
+        chain_id,
+    ) = initialize_test_environment(&mut context).await;
+
+    const WINDOW: u64 = 1_000;
+    let dst_addr = UniversalAddress::from_bytes([1u8; 32]);
+    let (sequence_tracker_pda, _) = SequenceTracker::pda(&integrator_program_id);
+
+    // The capacity is unlimited until a rate limit is set
+    assert_eq!(
+        get_outbound_capacity(&mut context, &payer, integrator_program_id, chain_id)
+            .await
+            .unwrap(),
+        u64::MAX
+    );
+
+    set_outbound_rate_limit(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_program_id,
+        chain_id,
+        1,
+        WINDOW,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        get_outbound_capacity(&mut context, &payer, integrator_program_id, chain_id)
+            .await
+            .unwrap(),
+        1
+    );
+
+    send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        OutboxMessage::pda(&integrator_program_id, 0).0,
+        sequence_tracker_pda,
+        chain_id,
+        dst_addr,
+        [2u8; 32],
+        &[mock_adapter::id()],
+        vec![],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        get_outbound_capacity(&mut context, &payer, integrator_program_id, chain_id)
+            .await
+            .unwrap(),
+        0
+    );
+
+    let result = send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        OutboxMessage::pda(&integrator_program_id, 1).0,
+        sequence_tracker_pda,
+        chain_id,
+        dst_addr,
+        [2u8; 32],
+        &[mock_adapter::id()],
+        vec![],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::RateLimitExceeded.into())
+        )
+    );
+
+    // The capacity refills over the window
+    let integrator_chain_config: IntegratorChainConfig =
+        get_account(&mut context.banks_client, integrator_chain_config_pda).await;
+    context
+        .warp_to_slot(integrator_chain_config.outbound_rate_limit.last_refill_slot + WINDOW)
+        .unwrap();
+
+    assert_eq!(
+        get_outbound_capacity(&mut context, &payer, integrator_program_id, chain_id)
+            .await
+            .unwrap(),
+        1
+    );
+
+    send_message(
+        &mut context,
+        &payer,
+        integrator_program_pda,
+        integrator_chain_config_pda,
+        OutboxMessage::pda(&integrator_program_id, 1).0,
+        sequence_tracker_pda,
+        chain_id,
+        dst_addr,
+        [2u8; 32],
+        &[mock_adapter::id()],
+        vec![],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_set_outbound_rate_limit_invalid() {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+
+    let (admin, integrator_program_id, _, _, _, _, _, chain_id) =
+        initialize_test_environment(&mut context).await;
+
+    let result = set_outbound_rate_limit(
+        &mut context,
+        &admin,
+        &payer,
+        integrator_program_id,
+        chain_id,
+        1,
+        0,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::InvalidRateLimit.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_set_outbound_rate_limit_non_admin() {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+
+    let (_, integrator_program_id, _, _, _, _, _, chain_id) =
+        initialize_test_environment(&mut context).await;
+
+    let result = set_outbound_rate_limit(
+        &mut context,
+        &Keypair::new(),
+        &payer,
+        integrator_program_id,
+        chain_id,
+        1,
+        1_000,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::CallerNotAuthorized.into())
+        )
+    );
+}
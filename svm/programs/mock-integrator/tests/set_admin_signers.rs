@@ -0,0 +1,377 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+mod instructions;
+
+use crate::common::execute_transaction::execute_transaction;
+use crate::instructions::add_adapter::add_adapter;
+use crate::instructions::register::register;
+use crate::instructions::set_admin_signers::{append_co_signers, set_admin_signers};
+use crate::instructions::transfer_admin::{claim_admin, transfer_admin};
+use crate::instructions::update_admin::update_admin;
+use anchor_lang::{prelude::*, InstructionData, ToAccountMetas};
+use common::setup::{get_account, setup};
+use endpoint::error::EndpointError;
+use endpoint::state::{AdapterInfo, IntegratorConfig};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    signature::Keypair,
+    signer::Signer,
+    transaction::TransactionError,
+};
+
+async fn setup_test_environment() -> (ProgramTestContext, Keypair, Keypair, [Keypair; 3]) {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+    let admin = Keypair::new();
+
+    register(
+        &mut context,
+        &payer,
+        &admin,
+        IntegratorConfig::pda(&mock_integrator::id()).0,
+        mock_integrator::id(),
+    )
+    .await
+    .unwrap();
+
+    // Switch to a 2-of-3 multisig admin
+    let signers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    set_admin_signers(
+        &mut context,
+        &admin,
+        &payer,
+        &[],
+        signers.iter().map(|signer| signer.pubkey()).collect(),
+        2,
+    )
+    .await
+    .unwrap();
+
+    (context, payer, admin, signers)
+}
+
+/// Adds an adapter with an instruction signed by `admin` and the `co_signers`
+async fn add_adapter_with_co_signers(
+    context: &mut ProgramTestContext,
+    admin: &Keypair,
+    payer: &Keypair,
+    co_signers: &[&Keypair],
+    adapter_program_id: Pubkey,
+) -> std::result::Result<(), BanksClientError> {
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+    let integrator_program_id = mock_integrator::id();
+
+    let accounts = endpoint::accounts::AddAdapter {
+        payer: payer.pubkey(),
+        admin: admin.pubkey(),
+        integrator_config: IntegratorConfig::pda(&integrator_program_id).0,
+        adapter_info: AdapterInfo::pda(&integrator_program_id, &adapter_program_id).0,
+        system_program: solana_sdk::system_program::id(),
+        program: endpoint::id(),
+        event_authority,
+    };
+
+    let args = endpoint::instructions::AddAdapterArgs {
+        integrator_program_id,
+        adapter_program_id,
+    };
+
+    let mut ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::AddAdapter { args }.data(),
+    };
+    append_co_signers(&mut ix, co_signers);
+
+    let mut tx_signers = vec![admin, payer];
+    tx_signers.extend_from_slice(co_signers);
+    execute_transaction(context, ix, &tx_signers, payer).await
+}
+
+fn assert_not_authorized(result: std::result::Result<(), BanksClientError>) {
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::CallerNotAuthorized.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_set_admin_signers_success() {
+    let (mut context, _, admin, signers) = setup_test_environment().await;
+
+    let integrator_config: IntegratorConfig = get_account(
+        &mut context.banks_client,
+        IntegratorConfig::pda(&mock_integrator::id()).0,
+    )
+    .await;
+    assert_eq!(integrator_config.admin, Some(admin.pubkey()));
+    assert_eq!(
+        integrator_config.admin_signers,
+        signers
+            .iter()
+            .map(|signer| signer.pubkey())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(integrator_config.admin_threshold, 2);
+}
+
+#[tokio::test]
+async fn test_multisig_admin_threshold_met() {
+    let (mut context, payer, _, signers) = setup_test_environment().await;
+
+    let adapter_program_id = Keypair::new().pubkey();
+    add_adapter_with_co_signers(
+        &mut context,
+        &signers[2],
+        &payer,
+        &[&signers[0]],
+        adapter_program_id,
+    )
+    .await
+    .unwrap();
+
+    let integrator_config: IntegratorConfig = get_account(
+        &mut context.banks_client,
+        IntegratorConfig::pda(&mock_integrator::id()).0,
+    )
+    .await;
+    assert_eq!(integrator_config.adapter_infos, vec![adapter_program_id]);
+}
+
+#[tokio::test]
+async fn test_multisig_admin_threshold_not_met() {
+    let (mut context, payer, _, signers) = setup_test_environment().await;
+
+    let result = add_adapter_with_co_signers(
+        &mut context,
+        &signers[0],
+        &payer,
+        &[],
+        Keypair::new().pubkey(),
+    )
+    .await;
+    assert_not_authorized(result);
+
+    // Signing twice with the same admin signer only counts once
+    let result = add_adapter_with_co_signers(
+        &mut context,
+        &signers[0],
+        &payer,
+        &[&signers[0]],
+        Keypair::new().pubkey(),
+    )
+    .await;
+    assert_not_authorized(result);
+
+    // Signers that are not admin signers are not counted
+    let result = add_adapter_with_co_signers(
+        &mut context,
+        &signers[0],
+        &payer,
+        &[&Keypair::new()],
+        Keypair::new().pubkey(),
+    )
+    .await;
+    assert_not_authorized(result);
+}
+
+#[tokio::test]
+async fn test_multisig_admin_non_signer_not_counted() {
+    let (mut context, payer, _, signers) = setup_test_environment().await;
+
+    let adapter_program_id = Keypair::new().pubkey();
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+
+    let accounts = endpoint::accounts::AddAdapter {
+        payer: payer.pubkey(),
+        admin: signers[0].pubkey(),
+        integrator_config: IntegratorConfig::pda(&mock_integrator::id()).0,
+        adapter_info: AdapterInfo::pda(&mock_integrator::id(), &adapter_program_id).0,
+        system_program: solana_sdk::system_program::id(),
+        program: endpoint::id(),
+        event_authority,
+    };
+    let args = endpoint::instructions::AddAdapterArgs {
+        integrator_program_id: mock_integrator::id(),
+        adapter_program_id,
+    };
+
+    // The second admin signer is passed without signing
+    let mut accounts = accounts.to_account_metas(None);
+    accounts.push(AccountMeta::new_readonly(signers[1].pubkey(), false));
+    let ix = Instruction {
+        program_id: endpoint::id(),
+        accounts,
+        data: endpoint::instruction::AddAdapter { args }.data(),
+    };
+
+    let result = execute_transaction(&mut context, ix, &[&signers[0], &payer], &payer).await;
+    assert_not_authorized(result);
+}
+
+#[tokio::test]
+async fn test_multisig_admin_single_admin_not_authorized() {
+    let (mut context, payer, admin, _) = setup_test_environment().await;
+
+    let result = add_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        IntegratorConfig::pda(&mock_integrator::id()).0,
+        AdapterInfo::pda(&mock_integrator::id(), &mock_adapter::id()).0,
+        mock_integrator::id(),
+        mock_adapter::id(),
+    )
+    .await;
+    assert_not_authorized(result);
+
+    // The single admin key cannot cancel an admin transfer either
+    let result = transfer_admin(
+        &mut context,
+        &admin,
+        &Keypair::new().pubkey(),
+        &payer,
+        IntegratorConfig::pda(&mock_integrator::id()).0,
+        mock_integrator::id(),
+    )
+    .await;
+    assert_not_authorized(result);
+}
+
+#[tokio::test]
+async fn test_set_admin_signers_back_to_single_admin() {
+    let (mut context, payer, admin, signers) = setup_test_environment().await;
+
+    set_admin_signers(&mut context, &signers[0], &payer, &[&signers[1]], vec![], 0)
+        .await
+        .unwrap();
+
+    // The single admin key is authorized again
+    add_adapter(
+        &mut context,
+        &admin,
+        &payer,
+        IntegratorConfig::pda(&mock_integrator::id()).0,
+        AdapterInfo::pda(&mock_integrator::id(), &mock_adapter::id()).0,
+        mock_integrator::id(),
+        mock_adapter::id(),
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_set_admin_signers_invalid() {
+    let (mut context, payer, _, signers) = setup_test_environment().await;
+    let signer_keys: Vec<Pubkey> = signers.iter().map(|signer| signer.pubkey()).collect();
+
+    for (new_signers, threshold) in [
+        // Threshold above the number of signers
+        (signer_keys.clone(), 4),
+        // Zero threshold with signers
+        (signer_keys.clone(), 0),
+        // Duplicate signers
+        (vec![signer_keys[0], signer_keys[0]], 1),
+        // Too many signers
+        (
+            (0..=IntegratorConfig::MAX_ADMIN_SIGNERS)
+                .map(|_| Keypair::new().pubkey())
+                .collect(),
+            1,
+        ),
+    ] {
+        let result = set_admin_signers(
+            &mut context,
+            &signers[0],
+            &payer,
+            &[&signers[1]],
+            new_signers,
+            threshold,
+        )
+        .await;
+
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(EndpointError::InvalidAdminSigners.into())
+            )
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_multisig_admin_update_admin() {
+    let (mut context, payer, _, signers) = setup_test_environment().await;
+    let (integrator_config_pda, _) = IntegratorConfig::pda(&mock_integrator::id());
+    let new_admin = Keypair::new();
+
+    // Hand the config over to a single admin key with the approval of the multisig
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+    let accounts = endpoint::accounts::UpdateAdmin {
+        admin: signers[0].pubkey(),
+        integrator_config: integrator_config_pda,
+        event_authority,
+        program: endpoint::id(),
+    };
+    let args = endpoint::instructions::UpdateAdminArgs {
+        integrator_program_id: mock_integrator::id(),
+        new_admin: new_admin.pubkey(),
+    };
+    let mut ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: accounts.to_account_metas(None),
+        data: endpoint::instruction::UpdateAdmin { args }.data(),
+    };
+    append_co_signers(&mut ix, &[&signers[1]]);
+    execute_transaction(
+        &mut context,
+        ix,
+        &[&signers[0], &signers[1], &payer],
+        &payer,
+    )
+    .await
+    .unwrap();
+
+    let integrator_config: IntegratorConfig =
+        get_account(&mut context.banks_client, integrator_config_pda).await;
+    assert_eq!(integrator_config.admin, Some(new_admin.pubkey()));
+    assert!(integrator_config.admin_signers.is_empty());
+    assert_eq!(integrator_config.admin_threshold, 0);
+
+    // The new admin key is authorized on its own
+    update_admin(
+        &mut context,
+        &new_admin,
+        &Keypair::new().pubkey(),
+        &payer,
+        integrator_config_pda,
+        mock_integrator::id(),
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_multisig_admin_claim_admin() {
+    let (mut context, payer, admin, _) = setup_test_environment().await;
+    let (integrator_config_pda, _) = IntegratorConfig::pda(&mock_integrator::id());
+
+    // The single admin key cannot claim while the multisig is active
+    let result = claim_admin(&mut context, &admin, &payer, integrator_config_pda).await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::NoAdminTransferInProgress.into())
+        )
+    );
+}
@@ -0,0 +1,329 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+mod instructions;
+
+use crate::instructions::add_adapter::add_adapter;
+use crate::instructions::register::register;
+use crate::instructions::set_chain_adapters::set_chain_adapters;
+use crate::instructions::set_config_delay::set_config_delay;
+use anchor_lang::prelude::*;
+use common::setup::{get_account, setup};
+use endpoint::error::EndpointError;
+use endpoint::state::{AdapterInfo, IntegratorChainConfig, IntegratorConfig};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer,
+    transaction::TransactionError,
+};
+
+const CHAIN_ID: u16 = 2;
+
+async fn setup_test_environment() -> (ProgramTestContext, Keypair, Keypair, Vec<Pubkey>) {
+    let mut context = setup().await;
+    let payer = context.payer.insecure_clone();
+    let admin = Keypair::new();
+    let integrator_program_id = mock_integrator::id();
+
+    let (integrator_config_pda, _) = IntegratorConfig::pda(&integrator_program_id);
+
+    register(
+        &mut context,
+        &payer,
+        &admin,
+        integrator_config_pda,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    // Add three adapters
+    let mut adapter_infos = Vec::new();
+    for _ in 0..3 {
+        let adapter_program_id = Keypair::new().pubkey();
+        let (adapter_info_pda, _) = AdapterInfo::pda(&integrator_program_id, &adapter_program_id);
+
+        add_adapter(
+            &mut context,
+            &admin,
+            &payer,
+            integrator_config_pda,
+            adapter_info_pda,
+            integrator_program_id,
+            adapter_program_id,
+        )
+        .await
+        .unwrap();
+
+        adapter_infos.push(adapter_info_pda);
+    }
+
+    (context, payer, admin, adapter_infos)
+}
+
+async fn enabled_counts(context: &mut ProgramTestContext, adapter_infos: &[Pubkey]) -> Vec<u32> {
+    let mut enabled_counts = Vec::new();
+    for adapter_info in adapter_infos {
+        let adapter_info: AdapterInfo = get_account(&mut context.banks_client, *adapter_info).await;
+        enabled_counts.push(adapter_info.enabled_count);
+    }
+    enabled_counts
+}
+
+#[tokio::test]
+async fn test_set_chain_adapters_success() {
+    let (mut context, payer, admin, adapter_infos) = setup_test_environment().await;
+    let (integrator_chain_config_pda, _) =
+        IntegratorChainConfig::pda(&mock_integrator::id(), CHAIN_ID);
+
+    set_chain_adapters(
+        &mut context,
+        &admin,
+        &payer,
+        CHAIN_ID,
+        0b011,
+        0b110,
+        &adapter_infos,
+    )
+    .await
+    .unwrap();
+
+    let integrator_chain_config: IntegratorChainConfig =
+        get_account(&mut context.banks_client, integrator_chain_config_pda).await;
+    assert_eq!(integrator_chain_config.chain_id, CHAIN_ID);
+    assert_eq!(
+        integrator_chain_config.integrator_program_id,
+        mock_integrator::id()
+    );
+    assert_eq!(
+        integrator_chain_config.send_adapter_bitmap.as_value(),
+        0b011
+    );
+    assert_eq!(
+        integrator_chain_config.recv_adapter_bitmap.as_value(),
+        0b110
+    );
+    assert_eq!(
+        enabled_counts(&mut context, &adapter_infos).await,
+        vec![1, 2, 1]
+    );
+
+    // Only the adapter infos of the changed adapters are passed
+    set_chain_adapters(
+        &mut context,
+        &admin,
+        &payer,
+        CHAIN_ID,
+        0b001,
+        0b100,
+        &adapter_infos[1..2],
+    )
+    .await
+    .unwrap();
+
+    let integrator_chain_config: IntegratorChainConfig =
+        get_account(&mut context.banks_client, integrator_chain_config_pda).await;
+    assert_eq!(
+        integrator_chain_config.send_adapter_bitmap.as_value(),
+        0b001
+    );
+    assert_eq!(
+        integrator_chain_config.recv_adapter_bitmap.as_value(),
+        0b100
+    );
+    assert_eq!(
+        enabled_counts(&mut context, &adapter_infos).await,
+        vec![1, 0, 1]
+    );
+}
+
+#[tokio::test]
+async fn test_set_chain_adapters_unregistered_adapter() {
+    let (mut context, payer, admin, adapter_infos) = setup_test_environment().await;
+
+    let result = set_chain_adapters(
+        &mut context,
+        &admin,
+        &payer,
+        CHAIN_ID,
+        0b1001,
+        0,
+        &adapter_infos[0..1],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::InvalidAdapterBitmap.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_set_chain_adapters_missing_adapter_info() {
+    let (mut context, payer, admin, adapter_infos) = setup_test_environment().await;
+
+    let result = set_chain_adapters(
+        &mut context,
+        &admin,
+        &payer,
+        CHAIN_ID,
+        0b011,
+        0,
+        &adapter_infos[0..1],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::InvalidAdapterInfo.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_set_chain_adapters_wrong_adapter_info_order() {
+    let (mut context, payer, admin, adapter_infos) = setup_test_environment().await;
+
+    let result = set_chain_adapters(
+        &mut context,
+        &admin,
+        &payer,
+        CHAIN_ID,
+        0b011,
+        0,
+        &[adapter_infos[1], adapter_infos[0]],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::InvalidAdapterInfo.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_set_chain_adapters_zero_chain_id() {
+    let (mut context, payer, admin, adapter_infos) = setup_test_environment().await;
+
+    let result = set_chain_adapters(
+        &mut context,
+        &admin,
+        &payer,
+        0,
+        0b001,
+        0,
+        &adapter_infos[0..1],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::InvalidChainId.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_set_chain_adapters_non_admin() {
+    let (mut context, payer, _, adapter_infos) = setup_test_environment().await;
+
+    let result = set_chain_adapters(
+        &mut context,
+        &Keypair::new(),
+        &payer,
+        CHAIN_ID,
+        0b001,
+        0,
+        &adapter_infos[0..1],
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::CallerNotAuthorized.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_set_chain_adapters_with_config_delay() {
+    let (mut context, payer, admin, adapter_infos) = setup_test_environment().await;
+    let (integrator_chain_config_pda, _) =
+        IntegratorChainConfig::pda(&mock_integrator::id(), CHAIN_ID);
+
+    set_chain_adapters(
+        &mut context,
+        &admin,
+        &payer,
+        CHAIN_ID,
+        0b011,
+        0b011,
+        &adapter_infos[0..2],
+    )
+    .await
+    .unwrap();
+
+    set_config_delay(&mut context, &admin, &payer, 100)
+        .await
+        .unwrap();
+
+    // Enabling an adapter must be proposed, even when others are disabled at the same time
+    let result = set_chain_adapters(
+        &mut context,
+        &admin,
+        &payer,
+        CHAIN_ID,
+        0b110,
+        0b011,
+        &adapter_infos,
+    )
+    .await;
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::ConfigChangeTimelocked.into())
+        )
+    );
+
+    // Disabling adapters stays immediate
+    set_chain_adapters(
+        &mut context,
+        &admin,
+        &payer,
+        CHAIN_ID,
+        0b001,
+        0b010,
+        &adapter_infos[0..2],
+    )
+    .await
+    .unwrap();
+
+    let integrator_chain_config: IntegratorChainConfig =
+        get_account(&mut context.banks_client, integrator_chain_config_pda).await;
+    assert_eq!(
+        integrator_chain_config.send_adapter_bitmap.as_value(),
+        0b001
+    );
+    assert_eq!(
+        integrator_chain_config.recv_adapter_bitmap.as_value(),
+        0b010
+    );
+    assert_eq!(
+        enabled_counts(&mut context, &adapter_infos).await,
+        vec![1, 1, 0]
+    );
+}
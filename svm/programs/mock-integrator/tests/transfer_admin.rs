@@ -3,15 +3,20 @@
 mod common;
 mod instructions;
 
+use crate::common::execute_transaction::execute_transaction;
 use crate::instructions::discard_admin::discard_admin;
 use crate::instructions::register::register;
-use crate::instructions::transfer_admin::{claim_admin, transfer_admin};
-use anchor_lang::prelude::*;
+use crate::instructions::set_admin_signers::{append_co_signers, set_admin_signers};
+use crate::instructions::transfer_admin::{cancel_admin_transfer, claim_admin, transfer_admin};
+use crate::instructions::update_admin::update_admin;
+use anchor_lang::{prelude::*, InstructionData};
 use common::setup::{get_account, setup};
 use endpoint::{error::EndpointError, state::IntegratorConfig};
 use solana_program_test::*;
 use solana_sdk::{
-    instruction::InstructionError, signature::Keypair, signer::Signer,
+    instruction::{Instruction, InstructionError},
+    signature::Keypair,
+    signer::Signer,
     transaction::TransactionError,
 };
 
@@ -466,3 +471,159 @@ async fn test_claim_admin_unauthorized() {
         )
     );
 }
+
+#[tokio::test]
+async fn test_cancel_admin_transfer_instruction() {
+    let (mut context, payer, admin, integrator_program_id, integrator_config_pda) =
+        setup_test_environment().await;
+
+    let new_admin = Keypair::new();
+
+    transfer_admin(
+        &mut context,
+        &admin,
+        &new_admin.pubkey(),
+        &payer,
+        integrator_config_pda,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+
+    // The pending admin cannot cancel the transfer
+    let result =
+        cancel_admin_transfer(&mut context, &new_admin, &payer, &[], integrator_config_pda).await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::CallerNotAuthorized.into())
+        )
+    );
+
+    cancel_admin_transfer(&mut context, &admin, &payer, &[], integrator_config_pda)
+        .await
+        .unwrap();
+
+    let integrator_config: IntegratorConfig =
+        get_account(&mut context.banks_client, integrator_config_pda).await;
+    assert_eq!(integrator_config.pending_admin, None);
+    assert_eq!(integrator_config.admin, Some(admin.pubkey()));
+
+    // The transfer can no longer be claimed
+    let result = claim_admin(&mut context, &new_admin, &payer, integrator_config_pda).await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::NoAdminTransferInProgress.into())
+        )
+    );
+
+    // Admin instructions are unblocked
+    update_admin(
+        &mut context,
+        &admin,
+        &new_admin.pubkey(),
+        &payer,
+        integrator_config_pda,
+        integrator_program_id,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_cancel_admin_transfer_no_pending_admin() {
+    let (mut context, payer, admin, _, integrator_config_pda) = setup_test_environment().await;
+
+    let result =
+        cancel_admin_transfer(&mut context, &admin, &payer, &[], integrator_config_pda).await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::NoAdminTransferInProgress.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_admin_transfer_multisig() {
+    let (mut context, payer, admin, integrator_program_id, integrator_config_pda) =
+        setup_test_environment().await;
+
+    let signers = [Keypair::new(), Keypair::new()];
+    set_admin_signers(
+        &mut context,
+        &admin,
+        &payer,
+        &[],
+        signers.iter().map(|signer| signer.pubkey()).collect(),
+        2,
+    )
+    .await
+    .unwrap();
+
+    // Initiate transfer, approved by both admin signers
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &endpoint::id());
+    let mut ix = Instruction {
+        program_id: endpoint::id(),
+        accounts: endpoint::accounts::TransferAdmin {
+            admin: signers[0].pubkey(),
+            integrator_config: integrator_config_pda,
+            event_authority,
+            program: endpoint::id(),
+        }
+        .to_account_metas(None),
+        data: endpoint::instruction::TransferAdmin {
+            args: endpoint::instructions::TransferAdminArgs {
+                new_admin: Keypair::new().pubkey(),
+                integrator_program_id,
+            },
+        }
+        .data(),
+    };
+    append_co_signers(&mut ix, &[&signers[1]]);
+    execute_transaction(
+        &mut context,
+        ix,
+        &[&signers[0], &signers[1], &payer],
+        &payer,
+    )
+    .await
+    .unwrap();
+
+    // A single admin signer cannot cancel the transfer
+    let result = cancel_admin_transfer(
+        &mut context,
+        &signers[0],
+        &payer,
+        &[],
+        integrator_config_pda,
+    )
+    .await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EndpointError::CallerNotAuthorized.into())
+        )
+    );
+
+    cancel_admin_transfer(
+        &mut context,
+        &signers[0],
+        &payer,
+        &[&signers[1]],
+        integrator_config_pda,
+    )
+    .await
+    .unwrap();
+
+    let integrator_config: IntegratorConfig =
+        get_account(&mut context.banks_client, integrator_config_pda).await;
+    assert_eq!(integrator_config.pending_admin, None);
+    assert_eq!(integrator_config.admin_threshold, 2);
+}